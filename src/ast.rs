@@ -10,27 +10,102 @@ pub struct InnerBlock {
     pub return_exp: Option<Box<Expression>>
 }
 
+/// Whether `statements` start with a "use strict" directive
+///
+/// Directives are the string literals at the start of a script or function body.
+pub fn is_strict(statements: &[Statement]) -> bool {
+    for statement in statements {
+        match statement.unlocated() {
+            &Statement::Expression(ref e) => match e.unlocated() {
+                &Expression::Literal(Value::String(ref directive)) if directive == "use strict" => return true,
+                &Expression::Literal(Value::String(_)) => (),
+                _ => return false
+            },
+            _ => return false
+        }
+    }
+
+    false
+}
+
 #[derive(Debug, Clone)]
 pub enum Statement {
     Expression(Expression),
     Declaration(Declaration),
     Throw(Expression),
     If(Expression, InnerBlock, Option<InnerBlock>),
-    Empty
+    While(Expression, InnerBlock),
+    DoWhile(InnerBlock, Expression),
+    For(Option<Box<Statement>>, Option<Expression>, Option<Expression>, InnerBlock),
+    Break(Option<Identifier>),
+    Continue(Option<Identifier>),
+    Labelled(Identifier, Box<Statement>),
+    Block(InnerBlock),
+    Try(InnerBlock, Option<(Identifier, InnerBlock)>, Option<InnerBlock>),
+    Empty,
+    /// A statement along with where it starts in the source
+    Located(Position, Box<Statement>)
+}
+
+impl Statement {
+    /// Records that this statement starts at `position`, unless it already has a position
+    pub fn located(self, position: Position) -> Statement {
+        match self {
+            s@Statement::Located(_, _) => s,
+            s => Statement::Located(position, Box::new(s))
+        }
+    }
+
+    /// The statement without any position information around it
+    pub fn unlocated(&self) -> &Statement {
+        match self {
+            &Statement::Located(_, ref s) => s.unlocated(),
+            s => s
+        }
+    }
+
+    /// Whether this declares a variable with `let` or `const`
+    pub fn is_lexical_declaration(&self) -> bool {
+        match self.unlocated() {
+            &Statement::Declaration(ref decl) => decl.is_lexical(),
+            _ => false
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub enum Declaration {
     Variable(Identifier, Option<Expression>),
+    /// A variable that is only visible in its block
+    Let(Identifier, Option<Expression>),
+    /// A variable in its block that can't be assigned to
+    Const(Identifier, Expression),
     Function(Identifier, Function)
 }
 
+impl Declaration {
+    /// Whether the declaration uses `let` or `const`, which are scoped to their block
+    pub fn is_lexical(&self) -> bool {
+        matches!(self, &Declaration::Let(..) | &Declaration::Const(..))
+    }
+
+    /// The name being declared
+    pub fn id(&self) -> &Identifier {
+        match self {
+            &Declaration::Variable(ref id, _) | &Declaration::Let(ref id, _) |
+            &Declaration::Const(ref id, _) | &Declaration::Function(ref id, _) => id
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum UnaryOp {
     Positive,
     Negative,
 
-    LogicalNot
+    LogicalNot,
+
+    BitwiseNot
 }
 
 #[derive(Debug, Clone)]
@@ -40,16 +115,43 @@ pub enum BinaryOp {
 
     Multiply,
     Divide,
+    Modulo,
 
     LogicalAnd,
     LogicalOr,
 
-    StrictEquals
+    Equals,
+    NotEquals,
+    StrictEquals,
+    StrictNotEquals,
+
+    LessThan,
+    GreaterThan,
+    LessThanOrEqual,
+    GreaterThanOrEqual,
+    InstanceOf,
+
+    BitwiseAnd,
+    BitwiseOr,
+    BitwiseXor,
+
+    LeftShift,
+    SignedRightShift,
+    UnsignedRightShift
+}
+
+#[derive(Debug, Clone)]
+pub enum UpdateOp {
+    Increment,
+    Decrement
 }
 
 #[derive(Debug, Clone)]
 pub enum Expression {
     Assignment(Access, Box<Expression>),
+    CompoundAssignment(BinaryOp, Access, Box<Expression>),
+    PrefixUpdate(UpdateOp, Access),
+    PostfixUpdate(UpdateOp, Access),
     Access(Access),
     Call(Box<Expression>, ExpressionList),
     New(Box<Expression>, ExpressionList),
@@ -58,8 +160,37 @@ pub enum Expression {
     Unary(UnaryOp, Box<Expression>),
     Binary(BinaryOp, Box<Expression>, Box<Expression>),
     Ternary(Box<Expression>, Box<Expression>, Box<Expression>),
-    Object(Vec<(String, Expression)>),
-    This
+    Object(Vec<Property>),
+    Array(Vec<Option<Expression>>),
+    This,
+    /// An expression along with where it starts in the source
+    Located(Position, Box<Expression>)
+}
+
+impl Expression {
+    /// Records that this expression starts at `position`, unless it already has a position
+    pub fn located(self, position: Position) -> Expression {
+        match self {
+            e@Expression::Located(_, _) => e,
+            e => Expression::Located(position, Box::new(e))
+        }
+    }
+
+    /// The expression without any position information around it
+    pub fn unlocated(&self) -> &Expression {
+        match self {
+            &Expression::Located(_, ref e) => e.unlocated(),
+            e => e
+        }
+    }
+}
+
+/// A place in the source, with lines and columns counted from 1
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub offset: usize,
+    pub line: usize,
+    pub column: usize
 }
 
 #[derive(Debug, Clone)]
@@ -74,12 +205,30 @@ pub enum Accessor {
     Expression(Box<Expression>)
 }
 
+/// A property in an object literal
+#[derive(Debug, Clone)]
+pub enum Property {
+    Value(Identifier, Expression),
+    Getter(Identifier, Function),
+    Setter(Identifier, Function)
+}
+
 #[derive(Debug, Clone)]
 pub struct Function {
     pub id: Option<Identifier>,
-    pub parameters: Vec<String>,
+    pub parameters: Vec<Parameter>,
+    /// The parameter that collects any arguments past the others into an array, written `...rest`
+    pub rest: Option<Identifier>,
     pub body: InnerBlock,
     pub source: String
 }
 
+/// A named function parameter
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    pub id: Identifier,
+    /// The value used when the argument is missing or `undefined`
+    pub default: Option<Expression>
+}
+
 pub type Identifier = String;
@@ -0,0 +1,309 @@
+//! Conversions between Rust and JavaScript values, and native functions built from plain Rust functions.
+
+use std::collections::HashMap;
+use std::fmt;
+
+use super::interpret::{self, Value, Object, Context, Function, JSResult, ToValue, ErrorKind, throw_error};
+
+/// Converts JavaScript values into Rust values, throwing a TypeError when the value has the wrong type
+pub trait FromValue: Sized {
+    fn from_value(value: Value, context: &Context) -> Result<Self, Value>;
+}
+
+fn type_error<T>(value: &Value, expected: &str) -> Result<T, Value> {
+    throw_error(ErrorKind::TypeError, format!("{} is not {}", value.debug_string(), expected))
+}
+
+impl FromValue for Value {
+    fn from_value(value: Value, _context: &Context) -> Result<Value, Value> {
+        Ok(value)
+    }
+}
+
+impl FromValue for Object {
+    fn from_value(value: Value, _context: &Context) -> Result<Object, Value> {
+        match value {
+            Value::Object(o) => Ok(o),
+            v => type_error(&v, "an object")
+        }
+    }
+}
+
+impl FromValue for f64 {
+    fn from_value(value: Value, _context: &Context) -> Result<f64, Value> {
+        match value {
+            Value::Number(n) => Ok(n),
+            v => type_error(&v, "a number")
+        }
+    }
+}
+
+impl FromValue for f32 {
+    fn from_value(value: Value, context: &Context) -> Result<f32, Value> {
+        f64::from_value(value, context).map(|n| n as f32)
+    }
+}
+
+impl FromValue for bool {
+    fn from_value(value: Value, _context: &Context) -> Result<bool, Value> {
+        match value {
+            Value::Boolean(b) => Ok(b),
+            v => type_error(&v, "a boolean")
+        }
+    }
+}
+
+impl FromValue for String {
+    fn from_value(value: Value, _context: &Context) -> Result<String, Value> {
+        match value {
+            Value::String(s) => Ok(s),
+            v => type_error(&v, "a string")
+        }
+    }
+}
+
+/// `undefined` and `null` become `None`
+impl<T: FromValue> FromValue for Option<T> {
+    fn from_value(value: Value, context: &Context) -> Result<Option<T>, Value> {
+        match value {
+            Value::Undefined | Value::Object(Object::Null) => Ok(None),
+            v => T::from_value(v, context).map(Some)
+        }
+    }
+}
+
+/// Only arrays can be converted, with holes read as `undefined`
+impl<T: FromValue> FromValue for Vec<T> {
+    fn from_value(value: Value, context: &Context) -> Result<Vec<T>, Value> {
+        match value {
+            Value::Object(ref o) if o.is_array() => (),
+            v => return type_error(&v, "an array")
+        }
+
+        let length = try!(value.get_in("length", context)).to_uint32();
+        if length > interpret::MAX_ELEMENTS {
+            return throw_error(ErrorKind::RangeError, format!("array of length {} is too long to convert", length));
+        }
+
+        let mut elements = vec![];
+        for index in 0..length {
+            let element = try!(value.get_in(&index.to_string(), context));
+            elements.push(try!(T::from_value(element, context)));
+        }
+
+        Ok(elements)
+    }
+}
+
+/// Reads the object's own properties
+impl<T: FromValue> FromValue for HashMap<String, T> {
+    fn from_value(value: Value, context: &Context) -> Result<HashMap<String, T>, Value> {
+        let keys: Vec<String> = match value {
+            Value::Object(Object::Object(ref o)) => o.borrow().keys(),
+            v => return type_error(&v, "an object")
+        };
+
+        let mut map = HashMap::with_capacity(keys.len());
+        for key in keys {
+            let property = try!(value.get_in(&key, context));
+            map.insert(key, try!(T::from_value(property, context)));
+        }
+
+        Ok(map)
+    }
+}
+
+macro_rules! integer_conversions {
+    ( $( $t:ty ),* ) => {
+        $(
+            /// Only whole numbers that fit in the type can be converted
+            impl FromValue for $t {
+                fn from_value(value: Value, context: &Context) -> Result<$t, Value> {
+                    let n = try!(f64::from_value(value, context));
+
+                    // One more than the maximum is a power of two, which is exact as a float even
+                    // when the maximum itself would round up to it
+                    if n.fract() == 0.0 && n >= <$t>::min_value() as f64 && n < <$t>::max_value() as f64 + 1.0 {
+                        Ok(n as $t)
+                    } else {
+                        throw_error(ErrorKind::TypeError, format!("{} is not an integer between {} and {}", n, <$t>::min_value(), <$t>::max_value()))
+                    }
+                }
+            }
+
+            impl ToValue for $t {
+                fn to_value(&self, _context: &Context) -> JSResult {
+                    Ok(Value::Number(*self as f64))
+                }
+            }
+        )*
+    }
+}
+
+integer_conversions!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl ToValue for f64 {
+    fn to_value(&self, _context: &Context) -> JSResult {
+        Ok(Value::Number(*self))
+    }
+}
+
+impl ToValue for f32 {
+    fn to_value(&self, _context: &Context) -> JSResult {
+        Ok(Value::Number(*self as f64))
+    }
+}
+
+impl ToValue for bool {
+    fn to_value(&self, _context: &Context) -> JSResult {
+        Ok(Value::Boolean(*self))
+    }
+}
+
+impl ToValue for String {
+    fn to_value(&self, _context: &Context) -> JSResult {
+        Ok(Value::String(self.clone()))
+    }
+}
+
+impl ToValue for &str {
+    fn to_value(&self, _context: &Context) -> JSResult {
+        Ok(Value::String(self.to_string()))
+    }
+}
+
+impl ToValue for () {
+    fn to_value(&self, _context: &Context) -> JSResult {
+        Ok(Value::Undefined)
+    }
+}
+
+/// `None` becomes `undefined`
+impl<T: ToValue> ToValue for Option<T> {
+    fn to_value(&self, context: &Context) -> JSResult {
+        match self {
+            &Some(ref v) => v.to_value(context),
+            &None => Ok(Value::Undefined)
+        }
+    }
+}
+
+impl<T: ToValue> ToValue for Vec<T> {
+    fn to_value(&self, context: &Context) -> JSResult {
+        let mut elements = Vec::with_capacity(self.len());
+        for element in self {
+            elements.push(try!(element.to_value(context)));
+        }
+
+        Ok(Value::Object(try!(interpret::new_array(elements, context.global.clone()))))
+    }
+}
+
+impl<T: ToValue> ToValue for HashMap<String, T> {
+    fn to_value(&self, context: &Context) -> JSResult {
+        let object = try!(interpret::new_object(context.global.clone()));
+        for (key, value) in self {
+            try!(object.set(key, try!(value.to_value(context))));
+        }
+
+        Ok(Value::Object(object))
+    }
+}
+
+/// The result of a native function, which throws an Error with the message of any `Err`
+pub trait ToResult {
+    fn to_result(self, context: &Context) -> JSResult;
+}
+
+impl<T: ToValue> ToResult for T {
+    fn to_result(self, context: &Context) -> JSResult {
+        self.to_value(context)
+    }
+}
+
+impl<T: ToValue, E: fmt::Display> ToResult for Result<T, E> {
+    fn to_result(self, context: &Context) -> JSResult {
+        match self {
+            Ok(value) => value.to_value(context),
+            Err(e) => throw_error(ErrorKind::Error, e.to_string())
+        }
+    }
+}
+
+/// Rust functions that can be called from JavaScript, converting their arguments with `FromValue`
+///
+/// `Args` is the tuple of argument types, which only exists to tell the implementations apart.
+pub trait IntoNative<Args> {
+    fn into_native(self, name: &str) -> Function;
+}
+
+macro_rules! native_conversions {
+    ( $( $arg:ident ),* ) => {
+        impl<F, R, $( $arg ),*> IntoNative<($( $arg, )*)> for F
+            where F: Fn($( $arg ),*) -> R + 'static, R: ToResult, $( $arg: FromValue ),*
+        {
+            #[allow(non_snake_case, unused_mut, unused_variables)]
+            fn into_native(self, name: &str) -> Function {
+                Function::native(name, move |arguments, context| {
+                    let mut arguments = arguments.into_iter();
+                    $(
+                        let $arg = try!($arg::from_value(arguments.next().unwrap_or(Value::Undefined), &context));
+                    )*
+                    self($( $arg ),*).to_result(&context)
+                })
+            }
+        }
+    }
+}
+
+native_conversions!();
+native_conversions!(A);
+native_conversions!(A, B);
+native_conversions!(A, B, C);
+native_conversions!(A, B, C, D);
+native_conversions!(A, B, C, D, E);
+native_conversions!(A, B, C, D, E, G);
+
+/// Reads and converts the property `key` of `object`, for derived `FromValue` implementations
+#[doc(hidden)]
+pub fn property<T: FromValue>(object: &Value, key: &str, context: &Context) -> Result<T, Value> {
+    T::from_value(try!(object.get_in(key, context)), context)
+}
+
+/// Checks that a value can be read as a struct called `name`, for derived `FromValue` implementations
+#[doc(hidden)]
+pub fn expect_object(value: &Value, name: &str) -> Result<(), Value> {
+    match value {
+        &Value::Object(Object::Object(_)) => Ok(()),
+        v => type_error(v, &format!("a {}", name))
+    }
+}
+
+/// Splits a value into the name of an enum variant and its contents, for derived `FromValue` implementations
+///
+/// Variants without contents are strings, and other variants are objects with a single property.
+#[doc(hidden)]
+pub fn variant(value: Value, name: &str, context: &Context) -> Result<(String, Option<Value>), Value> {
+    let key = match value {
+        Value::String(variant) => return Ok((variant, None)),
+        Value::Object(Object::Object(ref o)) => {
+            let mut keys = o.borrow().keys();
+            if keys.len() == 1 { keys.pop() } else { None }
+        },
+        _ => None
+    };
+
+    match key {
+        Some(key) => {
+            let contents = try!(value.get_in(&key, context));
+            Ok((key, Some(contents)))
+        },
+        None => type_error(&value, &format!("a {}", name))
+    }
+}
+
+/// Throws the error for a variant that the enum called `name` doesn't have
+#[doc(hidden)]
+pub fn unknown_variant<T>(variant: &str, name: &str) -> Result<T, Value> {
+    throw_error(ErrorKind::TypeError, format!("{} is not a variant of {}", variant, name))
+}
@@ -1,16 +1,23 @@
-use std::collections::HashMap;
 use std::{fmt, iter, f64, cmp};
 use std::ops::Deref;
+use std::mem;
 
 pub use std::rc::Rc;
 pub use std::cell::RefCell;
+use std::collections::HashMap;
+
+use stacker;
 
 use super::ast;
+use super::properties::{PropertyMap, Property, PropertyDescriptor, Slot, array_index};
 
 pub type JSResult = Result<Value, Value>;
 
+/// Converts Rust values into JavaScript values
+///
+/// Any objects that are created get their prototypes from `context.global`.
 pub trait ToValue {
-    fn to_value(&self) -> Value;
+    fn to_value(&self, context: &Context) -> JSResult;
 }
 
 trait GetFromResult {
@@ -26,45 +33,402 @@ impl GetFromResult for JSResult {
     }
 }
 
+/// A variable in a declarative environment record
+#[derive(Debug, Clone)]
+struct Binding {
+    /// The value, or `None` until the `let` or `const` declaration that creates it has run
+    value: Option<Value>,
+    /// Whether the variable can be assigned to, which is false for `const`
+    mutable: bool,
+    /// Whether the variable was declared with `let` or `const`, which `var` can't redeclare
+    lexical: bool
+}
+
+/// Where the variables of one scope are kept
+#[derive(Debug)]
+enum EnvironmentRecord {
+    /// Variables kept by the interpreter, used by functions, blocks and catch clauses
+    Declarative(RefCell<HashMap<String, Binding>>),
+    /// Variables that are the own properties of an object, used for the global object
+    Object(Object)
+}
+
+/// The variables that code can see, which are looked up from the innermost scope outwards
+#[derive(Debug, Clone)]
+pub struct Scope {
+    record: Rc<EnvironmentRecord>,
+    parent: Option<Rc<Scope>>,
+    /// Whether `var` and function declarations go in this scope, rather than the one around it
+    holds_vars: bool
+}
+
+impl Scope {
+    /// Creates the outermost scope
+    ///
+    /// `var` and function declarations become properties of `global`, while `let` and `const`
+    /// declarations are kept apart from it.
+    pub fn new(global: Object) -> Scope {
+        let globals = Scope {
+            record: Rc::new(EnvironmentRecord::Object(global)),
+            parent: None,
+            holds_vars: true
+        };
+
+        globals.block()
+    }
+
+    /// Creates the scope of a function call inside this one
+    pub fn function(&self) -> Scope {
+        Scope {holds_vars: true, .. self.block()}
+    }
+
+    /// Creates the scope of a block inside this one
+    pub fn block(&self) -> Scope {
+        Scope {
+            record: Rc::new(EnvironmentRecord::Declarative(RefCell::new(HashMap::new()))),
+            parent: Some(Rc::new(self.clone())),
+            holds_vars: false
+        }
+    }
+
+    /// Copies this scope's variables into a new scope, so that closures made afterwards don't
+    /// see later assignments to this one
+    fn copy(&self) -> Scope {
+        let record = match *self.record {
+            EnvironmentRecord::Declarative(ref bindings) => EnvironmentRecord::Declarative(RefCell::new(bindings.borrow().clone())),
+            EnvironmentRecord::Object(ref object) => EnvironmentRecord::Object(object.clone())
+        };
+
+        Scope {record: Rc::new(record), .. self.clone()}
+    }
+
+    /// Whether `key` is declared in this scope, not counting the ones around it
+    fn declares(&self, key: &str) -> bool {
+        match *self.record {
+            EnvironmentRecord::Declarative(ref bindings) => bindings.borrow().contains_key(key),
+            EnvironmentRecord::Object(ref object) => match object {
+                &Object::Object(ref obj) => obj.borrow().has_own(key),
+                &Object::Null => false
+            }
+        }
+    }
+
+    /// Finds the innermost scope that declares `key`
+    fn lookup(&self, key: &str) -> Option<&Scope> {
+        match (self.declares(key), &self.parent) {
+            (true, _) => Some(self),
+            (false, &Some(ref parent)) => parent.lookup(key),
+            (false, &None) => None
+        }
+    }
+
+    /// The scope that a `var` or function declaration of `key` goes in, throwing a SyntaxError if
+    /// it would redeclare a `let` or `const` variable on the way there
+    fn var_scope_for(&self, key: &str) -> Result<&Scope, Value> {
+        if let EnvironmentRecord::Declarative(ref bindings) = *self.record {
+            if bindings.borrow().get(key).is_some_and(|binding| binding.lexical) {
+                return throw_error(ErrorKind::SyntaxError, format!("redeclaration of {}", key));
+            }
+        }
+
+        match (self.holds_vars, &self.parent) {
+            (false, &Some(ref parent)) => parent.var_scope_for(key),
+            _ => Ok(self)
+        }
+    }
+
+    /// The outermost scope, whose variables are the global object's properties
+    fn globals(&self) -> &Scope {
+        match self.parent {
+            Some(ref parent) => parent.globals(),
+            None => self
+        }
+    }
+
+    /// Reads a variable, throwing a ReferenceError if it hasn't been declared or initialized
+    pub fn get(&self, key: &str, context: &Context) -> JSResult {
+        let scope = match self.lookup(key) {
+            Some(scope) => scope,
+            None => return throw_error(ErrorKind::ReferenceError, format!("{} is not defined", key))
+        };
+
+        match *scope.record {
+            EnvironmentRecord::Declarative(ref bindings) => match bindings.borrow()[key].value {
+                Some(ref value) => Ok(value.clone()),
+                None => uninitialized_error(key)
+            },
+            EnvironmentRecord::Object(ref object) => Value::Object(object.clone()).get_in(key, context)
+        }
+    }
+
+    /// Assigns to a variable, which becomes a global if it hasn't been declared
+    ///
+    /// Strict mode code can't create globals this way, so it throws a ReferenceError instead.
+    pub fn set(&self, key: &str, val: Value, context: &Context) -> JSResult {
+        let scope = match self.lookup(key) {
+            Some(scope) => scope,
+            None if context.strict => return throw_error(ErrorKind::ReferenceError, format!("assignment to undeclared variable {}", key)),
+            None => self.globals()
+        };
+
+        match *scope.record {
+            EnvironmentRecord::Declarative(ref bindings) => {
+                let mut bindings = bindings.borrow_mut();
+                let binding = bindings.get_mut(key).unwrap();
+                match binding {
+                    &mut Binding {value: None, ..} => uninitialized_error(key),
+                    &mut Binding {mutable: false, ..} => throw_error(ErrorKind::TypeError, format!("invalid assignment to const '{}'", key)),
+                    _ => {
+                        binding.value = Some(val.clone());
+                        Ok(val)
+                    }
+                }
+            },
+            EnvironmentRecord::Object(ref object) => object.put(key, val, context)
+        }
+    }
+
+    /// Binds `key` in this scope, replacing any variable it already has with that name
+    pub fn declare(&self, key: &str, val: Value) -> JSResult {
+        match *self.record {
+            EnvironmentRecord::Declarative(ref bindings) => {
+                bindings.borrow_mut().insert(key.to_string(), Binding {value: Some(val.clone()), mutable: true, lexical: false});
+                Ok(val)
+            },
+            EnvironmentRecord::Object(ref object) => object.set(key, val)
+        }
+    }
+
+    /// Declares a `var` in the scope of the function around this one, keeping its value if it
+    /// has already been declared
+    pub fn declare_var(&self, key: &str) -> Result<(), Value> {
+        let scope = try!(self.var_scope_for(key));
+        if !scope.declares(key) {
+            try!(scope.declare(key, Value::Undefined));
+        }
+
+        Ok(())
+    }
+
+    /// Declares a `let` or `const` variable in this scope, which can't be used until it's initialized
+    pub fn declare_lexical(&self, key: &str, mutable: bool) -> Result<(), Value> {
+        match *self.record {
+            EnvironmentRecord::Declarative(ref bindings) => {
+                let mut bindings = bindings.borrow_mut();
+                if bindings.contains_key(key) {
+                    return throw_error(ErrorKind::SyntaxError, format!("redeclaration of {}", key));
+                }
+
+                bindings.insert(key.to_string(), Binding {value: None, mutable: mutable, lexical: true});
+                Ok(())
+            },
+            EnvironmentRecord::Object(_) => throw_error(ErrorKind::SyntaxError, format!("can't declare {} in an object's scope", key))
+        }
+    }
+
+    /// Declares a parameter whose default value hasn't been evaluated yet, so that it can't be
+    /// used until it's initialized but can still be redeclared with `var`
+    pub fn declare_parameter(&self, key: &str) -> Result<(), Value> {
+        match *self.record {
+            EnvironmentRecord::Declarative(ref bindings) => {
+                bindings.borrow_mut().insert(key.to_string(), Binding {value: None, mutable: true, lexical: false});
+                Ok(())
+            },
+            EnvironmentRecord::Object(_) => throw_error(ErrorKind::SyntaxError, format!("can't declare {} in an object's scope", key))
+        }
+    }
+
+    /// Gives a `let` or `const` variable in this scope its first value
+    pub fn initialize(&self, key: &str, val: Value) -> Result<(), Value> {
+        match *self.record {
+            EnvironmentRecord::Declarative(ref bindings) => match bindings.borrow_mut().get_mut(key) {
+                Some(binding) => {
+                    binding.value = Some(val);
+                    Ok(())
+                },
+                None => throw_error(ErrorKind::ReferenceError, format!("{} is not defined", key))
+            },
+            EnvironmentRecord::Object(_) => throw_error(ErrorKind::SyntaxError, format!("can't declare {} in an object's scope", key))
+        }
+    }
+}
+
+/// Throws the error for a `let` or `const` variable that's used before its declaration has run
+fn uninitialized_error(key: &str) -> JSResult {
+    throw_error(ErrorKind::ReferenceError, format!("can't access lexical declaration '{}' before initialization", key))
+}
+
 /// Contains the state for an interpreter thread
 #[derive(Debug, Clone)]
 pub struct Context {
     pub this: Value,
-    pub local: Object,
-    pub global: Object
+    pub local: Scope,
+    pub global: Object,
+    /// The name of the file being evaluated, used when reporting errors
+    pub file: Option<Rc<String>>,
+    pub stack: CallStack,
+    /// Whether the code is in strict mode, where some silent failures throw errors instead
+    pub strict: bool
 }
 
 impl Context {
     /// Creates a top-level context
     ///
-    /// `this`, `local`, and `global` are all set to `obj`.
+    /// `this` and `global` are set to `obj`, whose properties are the variables of `local`.
     pub fn new(obj: Object) -> Context {
         Context {
             this: Value::Object(obj.clone()),
-            local: obj.clone(),
-            global: obj
+            local: Scope::new(obj.clone()),
+            global: obj,
+            file: None,
+            stack: CallStack::new(),
+            strict: false
         }
     }
 }
 
+/// A function call that hasn't returned yet
+#[derive(Debug, Clone)]
+pub struct Frame {
+    /// The name of the function, or `None` for top-level code and anonymous functions
+    pub function: Option<String>,
+    /// The file the function was defined in, or `None` for native functions
+    pub file: Option<Rc<String>>,
+    pub native: bool,
+    /// The position of the innermost expression being evaluated in this frame
+    pub position: Option<ast::Position>
+}
+
+impl Frame {
+    fn to_string(&self) -> String {
+        let location = if self.native {
+            "native".to_string()
+        } else {
+            let file = match self.file {
+                Some(ref f) => f.to_string(),
+                None => "<anonymous>".to_string()
+            };
+
+            match self.position {
+                Some(p) => format!("{}:{}:{}", file, p.line, p.column),
+                None => file
+            }
+        };
+
+        match self.function {
+            Some(ref name) => format!("    at {} ({})", name, location),
+            None => format!("    at {}", location)
+        }
+    }
+}
+
+/// The most frames a call stack can hold before a RangeError is thrown
+pub const MAX_CALL_DEPTH: usize = 5000;
+
+/// The longest array that is walked element by element before a RangeError is thrown
+///
+/// Array lengths come from scripts, so without a limit a single `length` assignment could ask
+/// for more memory than the host has.
+pub const MAX_ELEMENTS: u32 = 1 << 24;
+
+/// How close a call can get to the end of the native stack before more is allocated
+const STACK_RED_ZONE: usize = 128 * 1024;
+
+/// The size of each extra piece of native stack allocated for deep recursion
+const STACK_SEGMENT: usize = 2 * 1024 * 1024;
+
+/// The frames of the functions currently being called, shared by every context of an interpreter
+#[derive(Debug, Clone, Default)]
+pub struct CallStack {
+    frames: Rc<RefCell<Vec<Frame>>>,
+    /// The RangeError that was last thrown for going past `MAX_CALL_DEPTH`
+    overflow: Rc<RefCell<Option<Value>>>
+}
+
+impl CallStack {
+    pub fn new() -> CallStack {
+        CallStack::default()
+    }
+
+    /// Enters a new frame, or throws a RangeError if there are too many already
+    pub fn push(&self, frame: Frame) -> Result<(), Value> {
+        if self.frames.borrow().len() >= MAX_CALL_DEPTH {
+            let overflow = throw_error(ErrorKind::RangeError, "maximum call stack size exceeded".to_string());
+            *self.overflow.borrow_mut() = overflow.clone().err();
+            return overflow;
+        }
+
+        self.frames.borrow_mut().push(frame);
+        Ok(())
+    }
+
+    pub fn pop(&self) {
+        self.frames.borrow_mut().pop();
+    }
+
+    /// Leaves every frame above the first `depth`
+    pub fn truncate(&self, depth: usize) {
+        self.frames.borrow_mut().truncate(depth);
+    }
+
+    pub fn depth(&self) -> usize {
+        self.frames.borrow().len()
+    }
+
+    /// Whether `error` is the RangeError that was thrown when the stack last overflowed
+    pub fn is_overflow(&self, error: &Value) -> bool {
+        self.overflow.borrow().as_ref().is_some_and(|overflow| overflow.strict_equals(error))
+    }
+
+    /// Forgets the last overflow, so that its error is treated like any other value
+    pub fn reset_overflow(&self) {
+        *self.overflow.borrow_mut() = None;
+    }
+
+    /// Moves the innermost frame to `position`, returning where it was before
+    pub fn set_position(&self, position: Option<ast::Position>) -> Option<ast::Position> {
+        match self.frames.borrow_mut().last_mut() {
+            Some(frame) => mem::replace(&mut frame.position, position),
+            None => None
+        }
+    }
+
+    /// Formats the frames, innermost first, leaving out the innermost `skip` frames
+    pub fn trace(&self, skip: usize) -> Vec<String> {
+        self.frames.borrow().iter().rev().skip(skip).map(Frame::to_string).collect()
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct UserFunction {
     pub function: ast::Function,
-    pub local: Object
+    pub local: Scope,
+    /// The file the function was defined in
+    pub file: Option<Rc<String>>,
+    /// Whether the function is strict mode code, either because of its own directive or the code around it
+    pub strict: bool
 }
 
 impl UserFunction {
-    // Creates a UserFunction from `function` and `local`
-    pub fn new(function: ast::Function, local: Object) -> UserFunction {
+    // Creates a UserFunction from `function`, `local` and `file`
+    pub fn new(function: ast::Function, local: Scope, file: Option<Rc<String>>, strict: bool) -> UserFunction {
+        let strict = strict || ast::is_strict(&function.body.block);
+
         UserFunction {
             function: function,
-            local: local
+            local: local,
+            file: file,
+            strict: strict
         }
     }
 }
 
+/// A function implemented in Rust, which can capture state of its own
+pub type NativeFunction = Rc<dyn Fn(Vec<Value>, Context) -> JSResult>;
+
+#[derive(Clone)]
 pub enum Function {
-    Native(String, fn(Vec<Value>, Context) -> JSResult),
+    Native(String, NativeFunction),
     User(UserFunction),
 }
 
@@ -78,60 +442,146 @@ impl fmt::Debug for Function {
 }
 
 impl Function {
+    /// Creates a native function called `name` from a closure
+    pub fn native<F>(name: &str, function: F) -> Function
+        where F: Fn(Vec<Value>, Context) -> JSResult + 'static
+    {
+        Function::Native(name.to_string(), Rc::new(function))
+    }
+
     fn apply(&self, arguments: Vec<Value>, context: Context) -> JSResult {
-        match self {
-            &Function::Native(_, ref f) => f(arguments, context),
-            &Function::User (UserFunction {function: ast::Function {id: _, parameters: ref p, body: ref b, source: _}, local: ref closure_scope}) => {
-                let inner_env = Object::Object(Rc::new(RefCell::new(ActualObject::create(closure_scope.clone()))));
-                let undef = Value::Undefined;
-                for (argument, parameter) in arguments.iter().chain(iter::repeat(&undef)).zip(p) {
-                    try!(inner_env.set(parameter, argument.clone()));
-                }
+        // Every call recurses through the evaluator, so the native stack is extended on the heap
+        // when a script nests calls deeply
+        stacker::maybe_grow(STACK_RED_ZONE, STACK_SEGMENT, || {
+            match self {
+                &Function::Native(ref id, ref f) => {
+                    let stack = context.stack.clone();
+                    try!(stack.push(Frame {function: Some(id.clone()), file: None, native: true, position: None}));
+
+                    // Errors thrown by native code are traced before the native frame is left
+                    let result = f(arguments, context).inspect_err(|error| capture_stack(error, &stack, 0));
+
+                    stack.pop();
+                    result
+                },
+                &Function::User (UserFunction {ref function, local: ref closure_scope, ref file, strict}) => {
+                    try!(context.stack.push(Frame {function: function.id.clone(), file: file.clone(), native: false, position: None}));
+
+                    let inner_context = Context {this: context.this, local: closure_scope.function(), global: context.global, file: file.clone(), stack: context.stack.clone(), strict: strict};
+                    let result = match bind_parameters(function, arguments, &inner_context) {
+                        Ok(()) => eval_inner_block(&function.body, inner_context).into_result(),
+                        Err(e) => Err(e)
+                    };
 
-                match eval_inner_block(b, Context {this: context.this, local: inner_env.clone(), global: context.global}) {
-                    Tri::Continue(v) => Ok(v),
-                    Tri::Return(v) => Ok(v),
-                    Tri::Error(v) => Err(v)
+                    context.stack.pop();
+                    result
                 }
             }
-        }
+        })
     }
 
     fn debug_string(&self) -> String {
         match self {
             &Function::Native(ref id, _) => format!("function {}()", id),
-            &Function::User(UserFunction {function: ast::Function {id: Some(ref id), parameters: _, body: _, source: _}, local: _ }) => format!("function {}()", id),
-            &Function::User(UserFunction {function: ast::Function {id: None, parameters: _, body: _, source: _}, local: _}) => "function()".to_string(),
+            &Function::User(UserFunction {function: ast::Function {id: Some(ref id), .. }, .. }) => format!("function {}()", id),
+            &Function::User(UserFunction {function: ast::Function {id: None, .. }, .. }) => "function()".to_string(),
         }
     }
 
     pub fn to_string(&self) -> String {
         match self {
             &Function::Native(ref id, _) => format!("function {}() {{\n    [native code]\n}}", id),
-            &Function::User(UserFunction {function: ast::Function {id: _, parameters: _, body: _, source: ref s}, local: _}) => s.to_string()
+            &Function::User(UserFunction {function: ast::Function {source: ref s, .. }, .. }) => s.to_string()
         }
     }
 }
 
-#[derive(Debug)]
+/// The kinds of errors that the interpreter throws, each with its own constructor
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorKind {
+    Error,
+    TypeError,
+    ReferenceError,
+    SyntaxError,
+    RangeError,
+    EvalError
+}
+
+impl ErrorKind {
+    /// The name of the constructor for this kind of error
+    pub fn name(&self) -> &'static str {
+        match self {
+            &ErrorKind::Error => "Error",
+            &ErrorKind::TypeError => "TypeError",
+            &ErrorKind::ReferenceError => "ReferenceError",
+            &ErrorKind::SyntaxError => "SyntaxError",
+            &ErrorKind::RangeError => "RangeError",
+            &ErrorKind::EvalError => "EvalError"
+        }
+    }
+}
+
+/// Properties that are provided by Rust code instead of being stored in the object
+///
+/// This lets scripts use live Rust data without copying it into JavaScript objects. Properties
+/// that the host doesn't have are looked up on the object's prototype.
+pub trait HostObject {
+    /// Reads an own property, returning `None` if there isn't one
+    fn get(&self, key: &str) -> Option<Value>;
+
+    /// Writes a property, which can be refused by throwing an error
+    fn set(&mut self, key: &str, value: Value) -> Result<(), Value>;
+
+    fn has(&self, key: &str) -> bool {
+        self.get(key).is_some()
+    }
+
+    /// Removes an own property, returning whether it's gone
+    fn delete(&mut self, key: &str) -> bool {
+        !self.has(key)
+    }
+
+    /// The names of the own properties, in the order they should be listed
+    fn keys(&self) -> Vec<String>;
+}
+
 pub enum ObjectExtension {
     Function(Function),
+    /// An array, which keeps its `length` one past the highest index that has been written
+    Array(u32),
+    Error(ErrorKind),
+    Host(Box<dyn HostObject>),
     None
 }
 
+impl fmt::Debug for ObjectExtension {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &ObjectExtension::Function(ref f) => fmt.debug_tuple("Function").field(f).finish(),
+            &ObjectExtension::Array(length) => fmt.debug_tuple("Array").field(&length).finish(),
+            &ObjectExtension::Error(kind) => fmt.debug_tuple("Error").field(&kind).finish(),
+            &ObjectExtension::Host(ref host) => fmt.debug_tuple("Host").field(&host.keys()).finish(),
+            &ObjectExtension::None => fmt.write_str("None")
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ActualObject {
-    pub values: HashMap<String, Value>,
+    pub values: PropertyMap,
     pub prototype: Object,
-    pub otype: ObjectExtension
+    pub otype: ObjectExtension,
+    /// Whether new properties can be added
+    pub extensible: bool
 }
 
 impl ActualObject {
     pub fn new() -> ActualObject {
         ActualObject {
-            values: HashMap::new(),
+            values: PropertyMap::new(),
             prototype: Object::Null,
-            otype: ObjectExtension::None
+            otype: ObjectExtension::None,
+            extensible: true
         }
     }
 
@@ -139,64 +589,279 @@ impl ActualObject {
         let proto = proto.clone();
 
         ActualObject {
-            values: HashMap::new(),
+            values: PropertyMap::new(),
             prototype: proto,
-            otype: ObjectExtension::None
+            otype: ObjectExtension::None,
+            extensible: true
         }
     }
 
-    pub fn from_map(map: HashMap<String, Value>, proto: Object) -> ActualObject {
+    /// Creates an object with the properties in `map`, which are added in order
+    pub fn from_map<M: IntoIterator<Item = (String, Value)>>(map: M, proto: Object) -> ActualObject {
         ActualObject {
-            values: map,
+            values: map.into_iter().collect(),
             prototype: proto,
-            otype: ObjectExtension::None
+            otype: ObjectExtension::None,
+            extensible: true
         }
     }
 
-    fn outer_set(&mut self, key: &str, val: Value) -> Value {
-        let key = key.to_string();
-        if self.values.contains_key(&key) {
-            self.values.insert(key, val.clone());
-        } else if let Object::Object(ref proto) = self.prototype {
-            proto.borrow_mut().outer_set(&key, val.clone());
-        } else {
-            self.values.insert(key, val.clone());
+    pub fn from_host(host: Box<dyn HostObject>, proto: Object) -> ActualObject {
+        ActualObject {
+            values: PropertyMap::new(),
+            prototype: proto,
+            otype: ObjectExtension::Host(host),
+            extensible: true
         }
+    }
 
-        val
+    pub fn has_own(&self, key: &str) -> bool {
+        match self.otype {
+            ObjectExtension::Array(_) if key == "length" => true,
+            ObjectExtension::Host(ref host) => host.has(key),
+            _ => self.values.contains_key(key)
+        }
     }
 
-    fn get(&self, key: &str) -> Value {
-        match self.values.get(&key.to_string()) {
-            Some(v) => v.clone(),
-            None => match self.prototype {
-                Object::Object(ref proto) => proto.borrow().get(key),
-                Object::Null => Value::Undefined
+    /// An own property along with its attributes
+    pub fn own_property(&self, key: &str) -> Option<Property> {
+        match self.otype {
+            ObjectExtension::Array(length) if key == "length" => Some(Property {
+                slot: Slot::Data {value: Value::Number(length as f64), writable: true},
+                enumerable: false,
+                configurable: false
+            }),
+            ObjectExtension::Host(ref host) => host.get(key).map(Property::new),
+            _ => self.values.property(key).cloned()
+        }
+    }
+
+    /// The names of the own enumerable properties
+    pub fn keys(&self) -> Vec<String> {
+        match self.otype {
+            ObjectExtension::Host(ref host) => host.keys(),
+            _ => self.values.iter().filter(|&(_, property)| property.enumerable).map(|(key, _)| key).collect()
+        }
+    }
+
+    /// Removes an own property, returning whether it's gone
+    pub fn delete(&mut self, key: &str) -> bool {
+        match self.otype {
+            ObjectExtension::Array(_) if key == "length" => false,
+            ObjectExtension::Host(ref mut host) => host.delete(key),
+            _ => match self.values.property(key) {
+                Some(property) if !property.configurable => false,
+                _ => {
+                    self.values.remove(key);
+                    true
+                }
             }
         }
     }
 
-    fn get_or_err(&self, key: &str) -> JSResult {
-        match self.values.get(&key.to_string()) {
-            Some(v) => Ok(v.clone()),
+    /// Finds a property on this object or its prototypes
+    pub fn find_property(&self, key: &str) -> Option<Property> {
+        match self.own_property(key) {
+            Some(property) => Some(property),
             None => match self.prototype {
-                Object::Object(ref proto) => Ok(proto.borrow().get(key)),
-                Object::Null => throw_string(format!("{} is not defined", key))
+                Object::Object(ref proto) => proto.borrow().find_property(key),
+                Object::Null => None
             }
         }
     }
 
-    fn set(&mut self, key: &str, val: Value) -> Value {
-        self.values.insert(key.to_string(), val.clone());
-        val
+    /// Reads a property without calling getters, so accessors read as `undefined`
+    fn get(&self, key: &str) -> Value {
+        match self.find_property(key) {
+            Some(Property {slot: Slot::Data {value, ..}, ..}) => value,
+            _ => Value::Undefined
+        }
+    }
+
+    /// Assigns to a property, returning whether it could be written
+    ///
+    /// Read-only properties can't be written, including inherited ones, and new properties can't be
+    /// added to objects that aren't extensible. Accessors are replaced rather than called, so they
+    /// should be looked for first.
+    fn set(&mut self, key: &str, val: Value) -> Result<bool, Value> {
+        if let ObjectExtension::Host(ref mut host) = self.otype {
+            try!(host.set(key, val.clone()));
+            return Ok(true);
+        }
+
+        if let ObjectExtension::Array(ref mut length) = self.otype {
+            if key == "length" {
+                let new_length = val.to_uint32();
+                if new_length as f64 != val.to_number() {
+                    return throw_error(ErrorKind::RangeError, format!("invalid array length {}", val.debug_string()));
+                }
+
+                // Shrinking an array deletes every element past the new end, unless some can't be deleted
+                if new_length < *length {
+                    *length = self.values.truncate_indices(new_length);
+                    return Ok(*length == new_length);
+                }
+
+                *length = new_length;
+                return Ok(true);
+            }
+        }
+
+        match self.values.property(key) {
+            Some(&Property {slot: Slot::Data {writable: false, ..}, ..}) => return Ok(false),
+            Some(_) => (),
+            None => {
+                let inherited = match self.prototype {
+                    Object::Object(ref proto) => proto.borrow().find_property(key),
+                    Object::Null => None
+                };
+
+                let read_only = match inherited {
+                    Some(Property {slot: Slot::Data {writable, ..}, ..}) => !writable,
+                    _ => false
+                };
+
+                if !self.extensible || read_only {
+                    return Ok(false);
+                }
+            }
+        }
+
+        if let ObjectExtension::Array(ref mut length) = self.otype {
+            if let Some(index) = array_index(key) {
+                if index >= *length {
+                    *length = index + 1;
+                }
+            }
+        }
+
+        self.values.insert(key.to_string(), val);
+        Ok(true)
+    }
+
+    /// Creates or changes an own property, returning whether the change is allowed
+    pub fn define_own_property(&mut self, key: &str, descriptor: PropertyDescriptor) -> Result<bool, Value> {
+        match self.otype {
+            // Host properties can only have their values set
+            ObjectExtension::Host(ref mut host) => {
+                if descriptor.is_accessor() || descriptor.writable == Some(false) || descriptor.enumerable == Some(false) || descriptor.configurable == Some(false) {
+                    return Ok(false);
+                }
+
+                if let Some(value) = descriptor.value {
+                    try!(host.set(key, value));
+                }
+                return Ok(true);
+            },
+            // Array lengths can't be made read-only
+            ObjectExtension::Array(_) if key == "length" => {
+                if descriptor.is_accessor() || descriptor.writable == Some(false) || descriptor.enumerable == Some(true) || descriptor.configurable == Some(true) {
+                    return Ok(false);
+                }
+
+                return match descriptor.value {
+                    Some(value) => self.set(key, value),
+                    None => Ok(true)
+                };
+            },
+            _ => ()
+        }
+
+        let property = match self.values.property(key).cloned() {
+            None if !self.extensible => return Ok(false),
+            None => Property::from_descriptor(descriptor),
+            Some(ref current) if !current.allows(&descriptor) => return Ok(false),
+            Some(current) => current.update(descriptor)
+        };
+
+        if let ObjectExtension::Array(ref mut length) = self.otype {
+            if let Some(index) = array_index(key) {
+                if index >= *length {
+                    *length = index + 1;
+                }
+            }
+        }
+
+        self.values.define(key.to_string(), property);
+        Ok(true)
+    }
+
+    /// Stops new properties from being added, which host objects don't allow
+    pub fn prevent_extensions(&mut self) -> bool {
+        if let ObjectExtension::Host(_) = self.otype {
+            return false;
+        }
+
+        self.extensible = false;
+        true
+    }
+
+    /// Stops properties from being added, deleted or reconfigured, and if `freeze` is set, from being written
+    ///
+    /// Array lengths stay writable, but elements that can't be deleted stop arrays from shrinking.
+    pub fn seal(&mut self, freeze: bool) -> bool {
+        if !self.prevent_extensions() {
+            return false;
+        }
+
+        for key in self.values.keys() {
+            let property = self.values.property_mut(&key).unwrap();
+            property.configurable = false;
+            if let (true, &mut Slot::Data {ref mut writable, ..}) = (freeze, &mut property.slot) {
+                *writable = false;
+            }
+        }
+
+        true
+    }
+
+    /// Whether the object has been sealed, or frozen if `frozen` is set
+    pub fn is_sealed(&self, frozen: bool) -> bool {
+        !self.extensible && self.values.iter().all(|(_, property)| !(property.configurable || frozen && property.is_writable()))
     }
 
     pub fn debug_string(&self) -> String {
         match &self.otype {
             &ObjectExtension::Function(ref f) => f.debug_string(),
-            &ObjectExtension::None => {
-                let middle: String = self.values.iter()
-                .map(|(key, value)| "\"".to_string() + key + "\": " + &value.debug_string())
+            &ObjectExtension::Array(length) => {
+                // Only the elements that are present are visited, with each run of holes counted
+                let mut elements = vec![];
+                let mut next = 0;
+                for element in self.values.indices(length).map(Some).chain(Some(None)) {
+                    match element.map_or(length, |(index, _)| index) - next {
+                        0 => {},
+                        1 => elements.push("<1 empty item>".to_string()),
+                        holes => elements.push(format!("<{} empty items>", holes))
+                    }
+
+                    if let Some((index, property)) = element {
+                        elements.push(property.value().map(|v| v.debug_string()).unwrap_or(String::new()));
+                        next = index + 1;
+                    }
+                }
+
+                "[".to_string() + &elements.join(", ") + "]"
+            },
+            &ObjectExtension::Error(kind) => {
+                let name = match self.get("name") {
+                    Value::String(name) => name,
+                    _ => kind.name().to_string()
+                };
+
+                let description = match self.get("message") {
+                    Value::String(ref message) if !message.is_empty() => name + ": " + message,
+                    _ => name
+                };
+
+                match (self.get("fileName"), self.get("lineNumber"), self.get("columnNumber")) {
+                    (Value::String(file), Value::Number(line), Value::Number(column)) =>
+                        format!("{} ({}:{}:{})", description, file, line, column),
+                    _ => description
+                }
+            },
+            &ObjectExtension::Host(_) | &ObjectExtension::None => {
+                let middle: String = self.keys().iter()
+                .map(|key| "\"".to_string() + key + "\": " + &self.debug_property(key))
                 .fold("".to_string(), |result, next| if result.len() > 0 {result + ", " + &next} else {next});
 
                 "{".to_string() + &middle + "}"
@@ -204,18 +869,54 @@ impl ActualObject {
         }
     }
 
+    /// Formats an own property for `debug_string`, without calling its getter
+    fn debug_property(&self, key: &str) -> String {
+        match self.own_property(key).map(|property| property.slot) {
+            Some(Slot::Accessor {get: Value::Undefined, ..}) => "[Setter]".to_string(),
+            Some(Slot::Accessor {set: Value::Undefined, ..}) => "[Getter]".to_string(),
+            Some(Slot::Accessor {..}) => "[Getter/Setter]".to_string(),
+            Some(Slot::Data {value, ..}) => value.debug_string(),
+            None => Value::Undefined.debug_string()
+        }
+    }
+
     fn from_function(func: Function, prototype: Object) -> ActualObject {
         ActualObject {
-            values: HashMap::new(),
+            values: PropertyMap::new(),
+            prototype: prototype,
+            otype: ObjectExtension::Function(func),
+            extensible: true
+        }
+    }
+
+    fn from_error(kind: ErrorKind, message: &str, prototype: Object) -> ActualObject {
+        ActualObject {
+            values: iter::once(("message".to_string(), Value::String(message.to_string()))).collect(),
+            prototype: prototype,
+            otype: ObjectExtension::Error(kind),
+            extensible: true
+        }
+    }
+
+    fn from_array(elements: Vec<Value>, prototype: Object) -> ActualObject {
+        let length = elements.len() as u32;
+        let values = elements.into_iter()
+        .enumerate()
+        .map(|(index, value)| (index.to_string(), value))
+        .collect();
+
+        ActualObject {
+            values: values,
             prototype: prototype,
-            otype: ObjectExtension::Function(func)
+            otype: ObjectExtension::Array(length),
+            extensible: true
         }
     }
 
     pub fn apply(&self, arguments: Vec<Value>, context: Context) -> JSResult {
         match &self.otype {
             &ObjectExtension::Function(ref f) => f.apply(arguments, context),
-            _ => throw_string(format!("{:?} is not a function", self))
+            _ => throw_error(ErrorKind::TypeError, format!("{} is not a function", self.debug_string()))
         }
     }
 }
@@ -235,7 +936,7 @@ impl Object {
         Object::Object(Rc::new(RefCell::new(ActualObject::create(proto))))
     }
 
-    pub fn from_map(obj: HashMap<String, Value>, proto: Object) -> Object {
+    pub fn from_map<M: IntoIterator<Item = (String, Value)>>(obj: M, proto: Object) -> Object {
         Object::Object(Rc::new(RefCell::new(ActualObject::from_map(obj, proto))))
     }
 
@@ -243,31 +944,161 @@ impl Object {
         Object::Object(Rc::new(RefCell::new(ActualObject::from_function(func, prototype))))
     }
 
-    fn outer_set(&self, key: &str, val: Value) -> JSResult {
+    pub fn from_array(elements: Vec<Value>, prototype: Object) -> Object {
+        Object::Object(Rc::new(RefCell::new(ActualObject::from_array(elements, prototype))))
+    }
+
+    pub fn from_error(kind: ErrorKind, message: &str, prototype: Object) -> Object {
+        Object::Object(Rc::new(RefCell::new(ActualObject::from_error(kind, message, prototype))))
+    }
+
+    /// Creates an object whose properties are provided by `host`
+    pub fn from_host<H: HostObject + 'static>(host: H, prototype: Object) -> Object {
+        Object::Object(Rc::new(RefCell::new(ActualObject::from_host(Box::new(host), prototype))))
+    }
+
+    pub fn is_array(&self) -> bool {
         match self {
-            &Object::Object(ref obj) => Ok(obj.borrow_mut().outer_set(key, val)),
-            &Object::Null => throw_string("null has no properties".to_string())
+            &Object::Object(ref obj) => matches!(obj.borrow().otype, ObjectExtension::Array(_)),
+            &Object::Null => false
         }
     }
 
+    /// Throws the error for a property that couldn't be written
+    fn write_error(&self, key: &str) -> JSResult {
+        // Array lengths are always writable, so they only fail when elements can't be deleted
+        if self.is_array() && key == "length" {
+            return throw_error(ErrorKind::TypeError, "can't delete non-configurable array element".to_string());
+        }
+
+        let property = match self {
+            &Object::Object(ref obj) => obj.borrow().find_property(key),
+            &Object::Null => None
+        };
+
+        match property {
+            Some(Property {slot: Slot::Accessor {..}, ..}) => throw_error(ErrorKind::TypeError, format!("setting getter-only property \"{}\"", key)),
+            Some(_) => throw_error(ErrorKind::TypeError, format!("\"{}\" is read-only", key)),
+            None => throw_error(ErrorKind::TypeError, format!("can't define property \"{}\": {} is not extensible", key, self.debug_string()))
+        }
+    }
+
+    /// Reads a property, throwing a TypeError if it has a getter, which needs a context to run
     pub fn get(&self, key: &str) -> JSResult {
+        self.read(key, &Value::Object(self.clone()), None)
+    }
+
+    /// Reads a property, calling its getter with `receiver` as `this`
+    fn read(&self, key: &str, receiver: &Value, context: Option<&Context>) -> JSResult {
+        let property = match self {
+            &Object::Object(ref obj) => obj.borrow().find_property(key),
+            &Object::Null => return throw_error(ErrorKind::TypeError, "null has no properties".to_string())
+        };
+
+        match property {
+            Some(Property {slot: Slot::Data {value, ..}, ..}) => Ok(value),
+            Some(Property {slot: Slot::Accessor {get: Value::Undefined, ..}, ..}) | None => Ok(Value::Undefined),
+            Some(Property {slot: Slot::Accessor {get, ..}, ..}) => call_accessor(key, &get, receiver, vec![], context)
+        }
+    }
+
+    /// Assigns to a property, throwing a TypeError if it can't be written or has a setter, which
+    /// needs a context to run
+    pub fn set(&self, key: &str, val: Value) -> JSResult {
+        self.write(key, val, true, None)
+    }
+
+    /// Assigns to a property, calling its setter if it has one, where failing to write only throws
+    /// in strict mode
+    pub fn put(&self, key: &str, val: Value, context: &Context) -> JSResult {
+        self.write(key, val, context.strict, Some(context))
+    }
+
+    fn write(&self, key: &str, val: Value, strict: bool, context: Option<&Context>) -> JSResult {
+        let obj = match self {
+            &Object::Object(ref obj) => obj,
+            &Object::Null => return throw_error(ErrorKind::TypeError, "null has no properties".to_string())
+        };
+
+        // Setters are found before anything is borrowed, since they may use this object
+        let property = obj.borrow().find_property(key);
+        let written = match property {
+            Some(Property {slot: Slot::Accessor {set: Value::Undefined, ..}, ..}) => false,
+            Some(Property {slot: Slot::Accessor {set, ..}, ..}) => {
+                try!(call_accessor(key, &set, &Value::Object(self.clone()), vec![val.clone()], context));
+                true
+            },
+            _ => try!(obj.borrow_mut().set(key, val.clone()))
+        };
+
+        if written || !strict {
+            Ok(val)
+        } else {
+            self.write_error(key)
+        }
+    }
+
+    /// Creates or changes an own property, throwing a TypeError if the change isn't allowed
+    /// Defines an own property holding `value` that can be written, listed and deleted, without
+    /// running any setter on the prototype chain
+    pub fn define_value(&self, key: &str, value: Value) -> Result<(), Value> {
+        self.define_own_property(key, PropertyDescriptor {
+            value: Some(value),
+            writable: Some(true),
+            enumerable: Some(true),
+            configurable: Some(true),
+            .. PropertyDescriptor::default()
+        })
+    }
+
+    pub fn define_own_property(&self, key: &str, descriptor: PropertyDescriptor) -> Result<(), Value> {
         match self {
-            &Object::Object(ref obj) => Ok(obj.borrow().get(key)),
-            &Object::Null => throw_string("null has no properties".to_string())
+            &Object::Object(ref obj) => {
+                if try!(obj.borrow_mut().define_own_property(key, descriptor)) {
+                    Ok(())
+                } else if obj.borrow().has_own(key) {
+                    throw_error(ErrorKind::TypeError, format!("can't redefine non-configurable property \"{}\"", key))
+                } else {
+                    throw_error(ErrorKind::TypeError, format!("can't define property \"{}\": {} is not extensible", key, self.debug_string()))
+                }
+            },
+            &Object::Null => throw_error(ErrorKind::TypeError, "null has no properties".to_string())
         }
     }
 
-    pub fn get_or_err(&self, key: &str) -> JSResult {
+    pub fn own_property(&self, key: &str) -> Result<Option<Property>, Value> {
         match self {
-            &Object::Object(ref obj) => obj.borrow().get_or_err(key),
-            &Object::Null => throw_string("null has no properties".to_string())
+            &Object::Object(ref obj) => Ok(obj.borrow().own_property(key)),
+            &Object::Null => throw_error(ErrorKind::TypeError, "null has no properties".to_string())
         }
     }
 
-    pub fn set(&self, key: &str, val: Value) -> JSResult {
+    /// Whether the object or anything on its prototype chain has a property
+    pub fn has_property(&self, key: &str) -> bool {
         match self {
-            &Object::Object(ref obj) => Ok(obj.borrow_mut().set(key, val)),
-            &Object::Null => throw_string("null has no properties".to_string())
+            &Object::Object(ref obj) => obj.borrow().find_property(key).is_some(),
+            &Object::Null => false
+        }
+    }
+
+    pub fn has_own(&self, key: &str) -> Result<bool, Value> {
+        match self {
+            &Object::Object(ref obj) => Ok(obj.borrow().has_own(key)),
+            &Object::Null => throw_error(ErrorKind::TypeError, "null has no properties".to_string())
+        }
+    }
+
+    pub fn keys(&self) -> Result<Vec<String>, Value> {
+        match self {
+            &Object::Object(ref obj) => Ok(obj.borrow().keys()),
+            &Object::Null => throw_error(ErrorKind::TypeError, "null has no properties".to_string())
+        }
+    }
+
+    pub fn delete(&self, key: &str) -> Result<bool, Value> {
+        match self {
+            &Object::Object(ref obj) => Ok(obj.borrow_mut().delete(key)),
+            &Object::Null => throw_error(ErrorKind::TypeError, "null has no properties".to_string())
         }
     }
 
@@ -279,9 +1110,23 @@ impl Object {
     }
 }
 
+/// Calls the getter or setter of the property `key` with `receiver` as `this`
+fn call_accessor(key: &str, accessor: &Value, receiver: &Value, arguments: Vec<Value>, context: Option<&Context>) -> JSResult {
+    match context {
+        Some(context) => accessor.apply(arguments, Context {this: receiver.clone(), local: Scope::new(context.global.clone()), .. context.clone()}),
+        None => throw_error(ErrorKind::TypeError, format!("can't call the accessor for \"{}\" without a context", key))
+    }
+}
+
 impl ToValue for Object {
-    fn to_value(&self) -> Value {
-        Value::Object(self.clone())
+    fn to_value(&self, _context: &Context) -> JSResult {
+        Ok(Value::Object(self.clone()))
+    }
+}
+
+impl From<Object> for Value {
+    fn from(object: Object) -> Value {
+        Value::Object(object)
     }
 }
 
@@ -309,37 +1154,79 @@ pub enum Value {
 }
 
 impl Value {
+    /// Reads a property, calling its getter in a new top-level context of `global`
+    ///
+    /// This is for hosts that aren't running a script. Code that has a context uses `get_in`,
+    /// so that getters run on its call stack.
     pub fn get(&self, key: &str, global: Object) -> JSResult {
+        self.get_in(key, &Context::new(global))
+    }
+
+    /// Reads a property, calling its getter with this value as `this`
+    ///
+    /// Properties of primitives are read from the prototype of their constructor in `context.global`.
+    pub fn get_in(&self, key: &str, context: &Context) -> JSResult {
         match self {
-            &Value::Number(_) => global.clone().get("Number").get("prototype", global.clone()).get(key, global),
-            &Value::Boolean(_) => global.clone().get("Boolean").get("prototype", global.clone()).get(key, global),
-            &Value::String(_) => global.clone().get("String").get("prototype", global.clone()).get(key, global),
-            &Value::Object(ref obj) => obj.get(key),
-            &Value::Undefined => throw_string("undefined has no properties".to_string())
+            &Value::Object(ref obj) => obj.read(key, self, Some(context)),
+            &Value::Undefined => throw_error(ErrorKind::TypeError, "undefined has no properties".to_string()),
+            _ => try!(self.primitive_prototype(context.global.clone())).read(key, self, Some(context))
         }
     }
 
+    /// Assigns to a property, throwing a TypeError if it can't be written or has a setter, which
+    /// needs a context to run
     pub fn set(&self, key: &str, val: Value) -> JSResult {
         match self {
-            &Value::Number(_) => Ok(Value::Undefined),
-            &Value::Boolean(_) => Ok(Value::Undefined),
-            &Value::String(_) => Ok(Value::Undefined),
             &Value::Object(ref obj) => obj.set(key, val),
-            &Value::Undefined => throw_string("undefined has no properties".to_string())
+            &Value::Undefined => throw_error(ErrorKind::TypeError, "undefined has no properties".to_string()),
+            _ => Ok(Value::Undefined)
         }
     }
 
-    pub fn outer_set(&self, key: &str, val: Value) -> JSResult {
+    /// Assigns to a property, calling its setter with this value as `this`, where failing to write
+    /// only throws in strict mode
+    ///
+    /// Primitives can't have properties of their own, so only setters on their prototypes do anything.
+    pub fn put(&self, key: &str, val: Value, context: &Context) -> JSResult {
         match self {
-            &Value::Object(ref obj) => obj.outer_set(key, val),
-            _ => throw_string(format!("{:?} is not an object", self))
+            &Value::Object(ref obj) => obj.put(key, val, context),
+            &Value::Undefined => throw_error(ErrorKind::TypeError, "undefined has no properties".to_string()),
+            _ => {
+                let property = match try!(self.primitive_prototype(context.global.clone())) {
+                    Object::Object(ref proto) => proto.borrow().find_property(key),
+                    Object::Null => None
+                };
+
+                match property {
+                    Some(Property {slot: Slot::Accessor {ref set, ..}, ..}) if *set != Value::Undefined => {
+                        try!(call_accessor(key, set, self, vec![val.clone()], Some(context)));
+                        Ok(val)
+                    },
+                    _ => Ok(Value::Undefined)
+                }
+            }
+        }
+    }
+
+    /// The prototype that properties of a primitive are looked up on
+    fn primitive_prototype(&self, global: Object) -> Result<Object, Value> {
+        let constructor = match self {
+            &Value::Number(_) => "Number",
+            &Value::Boolean(_) => "Boolean",
+            &Value::String(_) => "String",
+            _ => return throw_error(ErrorKind::TypeError, format!("{} is not a primitive", self.debug_string()))
+        };
+
+        match try!(try!(global.get(constructor)).get("prototype", global)) {
+            Value::Object(proto) => Ok(proto),
+            _ => throw_error(ErrorKind::TypeError, format!("{}.prototype must be an object", constructor))
         }
     }
 
     /// Formats output for debugging functions
     pub fn debug_string(&self) -> String {
         match self {
-            &Value::Number(n) => n.to_string(),
+            &Value::Number(n) => number_to_string(n),
             &Value::Boolean(b) => b.to_string(),
             &Value::String(ref s) => s.to_string(),
             &Value::Object(ref o) => o.debug_string(),
@@ -349,11 +1236,16 @@ impl Value {
 
     // TODO: figure out better naming conventions
     /// Converts value to string using its `toString` attribute
-    pub fn js_to_string(&self, global: Object) -> Result<String, Value> {
-        self.get("toString", global.clone())
-            .and_then(|to_string| to_string.apply(vec![], Context {this: self.clone(), local: global.clone(), global: global.clone()}))
-            .map(|val| val.to_string())
-            .or(throw_string(format!("can't convert {} to primitive type", self.debug_string())))
+    ///
+    /// `toString` is called on the call stack of `context`, and anything it throws is passed on.
+    pub fn js_to_string(&self, context: &Context) -> Result<String, Value> {
+        match self.get_in("toString", context) {
+            Ok(ref to_string) if to_string.is_function() => {
+                let result = try!(to_string.apply(vec![], Context {this: self.clone(), local: Scope::new(context.global.clone()), .. context.clone()}));
+                Ok(result.to_string())
+            },
+            _ => throw_error(ErrorKind::TypeError, format!("can't convert {} to primitive type", self.debug_string()))
+        }
     }
 
     pub fn from_function(func: Function, prototype: Object) -> Value {
@@ -362,18 +1254,33 @@ impl Value {
 
     pub fn apply(&self, arguments: Vec<Value>, context: Context) -> JSResult {
         match self {
-            &Value::Object(Object::Object(ref o)) => o.borrow().apply(arguments, context),
-            _ => throw_string(format!("{:?} is not a function", self))
+            &Value::Object(Object::Object(ref o)) => {
+                // The function is cloned out so that it can modify its own object while it runs
+                let function = match o.borrow().otype {
+                    ObjectExtension::Function(ref f) => f.clone(),
+                    _ => return throw_error(ErrorKind::TypeError, format!("{} is not a function", self.debug_string()))
+                };
+
+                function.apply(arguments, context)
+            },
+            _ => throw_error(ErrorKind::TypeError, format!("{} is not a function", self.debug_string()))
+        }
+    }
+
+    pub fn is_function(&self) -> bool {
+        match self {
+            &Value::Object(Object::Object(ref o)) => matches!(o.borrow().otype, ObjectExtension::Function(_)),
+            _ => false
         }
     }
 
-    fn add(&self, right: &Value, global: Object) -> JSResult {
+    fn add(&self, right: &Value, context: &Context) -> JSResult {
         match (self.clone(), right.clone()) {
             (Value::Number(left), Value::Number(right)) => Ok(Value::Number(left + right)),
 
-            (left, Value::String(right)) => Ok(Value::String(try!(left.js_to_string(global)) + &right)),
+            (left, Value::String(right)) => Ok(Value::String(try!(left.js_to_string(context)) + &right)),
             (Value::String(left), right) => {
-                let right = try!(right.js_to_string(global));
+                let right = try!(right.js_to_string(context));
                 Ok(Value::String(left + &right))
             },
             _ => {
@@ -382,8 +1289,8 @@ impl Value {
                 if !left_num.is_nan() && !right_num.is_nan() {
                     Ok(Value::Number(left_num + right_num))
                 } else {
-                    let left_string = try!(self.js_to_string(global.clone()));
-                    let right_string = try!(right.js_to_string(global));
+                    let left_string = try!(self.js_to_string(context));
+                    let right_string = try!(right.js_to_string(context));
 
                     Ok(Value::String(left_string + &right_string))
                 }
@@ -395,17 +1302,148 @@ impl Value {
         self == right
     }
 
+    /// Compares values like `===`, except that NaN is the same as itself and 0 isn't the same as -0
+    pub fn same_value(&self, right: &Value) -> bool {
+        match (self, right) {
+            (&Value::Number(a), &Value::Number(b)) if a.is_nan() => b.is_nan(),
+            (&Value::Number(a), &Value::Number(b)) if a == 0.0 && b == 0.0 => a.is_sign_negative() == b.is_sign_negative(),
+            _ => self.strict_equals(right)
+        }
+    }
+
+    /// Compares values using the abstract equality algorithm of `==`
+    ///
+    /// Values of different types are converted towards numbers, and objects are converted to
+    /// primitives when compared with a string or a number.
+    pub fn equals(&self, right: &Value, context: &Context) -> Result<bool, Value> {
+        match (self, right) {
+            (&Value::Number(_), &Value::Number(_)) |
+            (&Value::String(_), &Value::String(_)) |
+            (&Value::Boolean(_), &Value::Boolean(_)) |
+            (&Value::Object(_), &Value::Object(_)) |
+            (&Value::Undefined, &Value::Undefined) => Ok(self.strict_equals(right)),
+
+            (&Value::Object(Object::Null), &Value::Undefined) |
+            (&Value::Undefined, &Value::Object(Object::Null)) => Ok(true),
+
+            (&Value::Number(_), &Value::String(_)) |
+            (&Value::String(_), &Value::Number(_)) => Ok(self.to_number() == right.to_number()),
+
+            (&Value::Boolean(_), _) => Value::Number(self.to_number()).equals(right, context),
+            (_, &Value::Boolean(_)) => self.equals(&Value::Number(right.to_number()), context),
+
+            (&Value::Number(_), &Value::Object(Object::Object(_))) |
+            (&Value::String(_), &Value::Object(Object::Object(_))) => self.equals(&try!(right.to_primitive(context)), context),
+            (&Value::Object(Object::Object(_)), &Value::Number(_)) |
+            (&Value::Object(Object::Object(_)), &Value::String(_)) => try!(self.to_primitive(context)).equals(right, context),
+
+            _ => Ok(false)
+        }
+    }
+
+    /// Checks whether `constructor.prototype` is on the prototype chain of this value
+    pub fn instance_of(&self, constructor: &Value, context: &Context) -> Result<bool, Value> {
+        if !constructor.is_function() {
+            return throw_error(ErrorKind::TypeError, format!("{} is not a function", constructor.debug_string()));
+        }
+
+        let proto = match try!(constructor.get_in("prototype", context)) {
+            Value::Object(o) => o,
+            _ => return throw_error(ErrorKind::TypeError, "prototype must be an object".to_string())
+        };
+
+        let mut current = match self {
+            &Value::Object(Object::Object(ref o)) => o.borrow().prototype.clone(),
+            _ => return Ok(false)
+        };
+
+        while let Object::Object(o) = current {
+            if Object::Object(o.clone()) == proto {
+                return Ok(true);
+            }
+
+            current = o.borrow().prototype.clone();
+        }
+
+        Ok(false)
+    }
+
+    /// Orders values for the relational operators
+    ///
+    /// Two strings are compared lexicographically and anything else numerically. Returns
+    /// `None` when the values are unordered, which happens whenever `NaN` is involved.
+    pub fn compare(&self, right: &Value, context: &Context) -> Result<Option<cmp::Ordering>, Value> {
+        let left = try!(self.to_primitive(context));
+        let right = try!(right.to_primitive(context));
+
+        match (left, right) {
+            (Value::String(ref l), Value::String(ref r)) => Ok(Some(l.cmp(r))),
+            (l, r) => Ok(l.to_number().partial_cmp(&r.to_number()))
+        }
+    }
+
     // Conversions
+
+    /// Converts objects to primitives, preferring numbers
+    ///
+    /// `valueOf` is tried first and then `toString`, skipping either one if it isn't a function or
+    /// returns another object.
+    pub fn to_primitive(&self, context: &Context) -> JSResult {
+        if let &Value::Object(Object::Object(_)) = self {
+            for method in &["valueOf", "toString"] {
+                let method = try!(self.get_in(method, context));
+                if !method.is_function() {
+                    continue;
+                }
+
+                match try!(method.apply(vec![], Context {this: self.clone(), local: Scope::new(context.global.clone()), .. context.clone()})) {
+                    Value::Object(Object::Object(_)) => (),
+                    primitive => return Ok(primitive)
+                }
+            }
+
+            return throw_error(ErrorKind::TypeError, format!("can't convert {} to primitive type", self.debug_string()));
+        }
+
+        Ok(self.clone())
+    }
+
     pub fn to_number(&self) -> f64 {
         match self {
             &Value::Number(n) => n,
             &Value::Boolean(b) => if b {1.0} else {0.0},
-            &Value::String(ref s) => s.parse().unwrap_or(f64::NAN),
+            &Value::String(ref s) => {
+                let s = s.trim();
+                if s.is_empty() { 0.0 } else { s.parse().unwrap_or(f64::NAN) }
+            },
+            &Value::Object(Object::Null) => 0.0,
             &Value::Object(_) => f64::NAN,
             &Value::Undefined => f64::NAN
         }
     }
 
+    /// Converts to a signed 32-bit integer, wrapping around like the bitwise operators do
+    pub fn to_int32(&self) -> i32 {
+        self.to_uint32() as i32
+    }
+
+    /// Converts to an unsigned 32-bit integer, truncating and then wrapping modulo 2^32
+    pub fn to_uint32(&self) -> u32 {
+        let n = self.to_number();
+
+        if !n.is_finite() {
+            return 0;
+        }
+
+        let wrapped = n.trunc() % 4294967296.0;
+
+        if wrapped < 0.0 {
+            (wrapped + 4294967296.0) as u32
+        } else {
+            wrapped as u32
+        }
+    }
+
     pub fn to_boolean(&self) -> bool {
         match self {
             &Value::Number(n) => n != 0.0,
@@ -427,15 +1465,148 @@ impl Value {
     }
 }
 
+/// Formats a number the way JavaScript does
+///
+/// The shortest digits that read back as `n` are used, written out in full for exponents from
+/// -7 to 20 and in exponential notation like `1e+21` outside of that.
+pub fn number_to_string(n: f64) -> String {
+    if n.is_nan() {
+        return "NaN".to_string();
+    } else if n == 0.0 {
+        return "0".to_string();
+    } else if n.is_infinite() {
+        return if n > 0.0 { "Infinity" } else { "-Infinity" }.to_string();
+    } else if n < 0.0 {
+        return "-".to_string() + &number_to_string(-n);
+    }
+
+    // Rust's exponential formatting gives the shortest digits, like `1.2345e-7`
+    let formatted = format!("{:e}", n);
+    let (mantissa, exponent) = formatted.split_at(formatted.find('e').unwrap());
+    let digits: String = mantissa.chars().filter(|&c| c != '.').collect();
+    let exponent: i32 = exponent[1..].parse().unwrap();
+
+    // The decimal point comes after the first `point` digits
+    let (k, point) = (digits.len() as i32, exponent + 1);
+
+    if k <= point && point <= 21 {
+        digits + &"0".repeat((point - k) as usize)
+    } else if 0 < point && point <= 21 {
+        let (whole, fraction) = digits.split_at(point as usize);
+        format!("{}.{}", whole, fraction)
+    } else if -6 < point && point <= 0 {
+        format!("0.{}{}", "0".repeat(-point as usize), digits)
+    } else {
+        let sign = if exponent < 0 { "-" } else { "+" };
+        let (first, rest) = digits.split_at(1);
+
+        if rest.is_empty() {
+            format!("{}e{}{}", first, sign, exponent.abs())
+        } else {
+            format!("{}.{}e{}{}", first, rest, sign, exponent.abs())
+        }
+    }
+}
+
 impl ToValue for Value {
-    fn to_value(&self) -> Value {
-        self.clone()
+    fn to_value(&self, _context: &Context) -> JSResult {
+        Ok(self.clone())
+    }
+}
+
+/// Throws a new error of the given kind
+///
+/// The error isn't linked to any global object yet, so it starts without a prototype.
+/// `attach_error_prototype` gives it one once it reaches a `catch` or the host.
+pub fn throw_error<T>(kind: ErrorKind, message: String) -> Result<T, Value> {
+    Err(Value::Object(Object::from_error(kind, &message, Object::Null)))
+}
+
+/// Records where an error was thrown, unless it already has a location
+///
+/// Sets `fileName`, `lineNumber` and `columnNumber` on the error, and captures its `stack` if that hasn't happened yet.
+pub fn locate_error(error: &Value, position: ast::Position, context: &Context) {
+    if let &Value::Object(Object::Object(ref o)) = error {
+        {
+            let mut error = o.borrow_mut();
+
+            match error.otype {
+                ObjectExtension::Error(_) => (),
+                _ => return
+            }
+
+            if error.values.contains_key("lineNumber") {
+                return;
+            }
+
+            let file_name = match context.file {
+                Some(ref f) => f.to_string(),
+                None => "<anonymous>".to_string()
+            };
+
+            error.values.insert("fileName".to_string(), Value::String(file_name));
+            error.values.insert("lineNumber".to_string(), Value::Number(position.line as f64));
+            error.values.insert("columnNumber".to_string(), Value::Number(position.column as f64));
+        }
+
+        // Code run outside of `Yuk::eval` has no frames, so the location is all there is
+        let mut trace = context.stack.trace(0);
+        if trace.is_empty() {
+            trace.push(Frame {function: None, file: context.file.clone(), native: false, position: Some(position)}.to_string());
+        }
+        set_stack(o, trace);
+    }
+}
+
+/// Sets the `stack` of an error to the frames in `stack`, unless it already has one
+///
+/// The innermost `skip` frames are left out.
+pub fn capture_stack(error: &Value, stack: &CallStack, skip: usize) {
+    if let &Value::Object(Object::Object(ref o)) = error {
+        set_stack(o, stack.trace(skip));
+    }
+}
+
+fn set_stack(error: &Rc<RefCell<ActualObject>>, trace: Vec<String>) {
+    let mut error = error.borrow_mut();
+
+    let kind = match error.otype {
+        ObjectExtension::Error(kind) => kind,
+        _ => return
+    };
+
+    if error.values.contains_key("stack") {
+        return;
+    }
+
+    let mut stack = match error.values.get("message") {
+        Some(&Value::String(ref message)) if !message.is_empty() => format!("{}: {}", kind.name(), message),
+        _ => kind.name().to_string()
+    };
+
+    for frame in trace {
+        stack = stack + "\n" + &frame;
     }
+
+    error.values.insert("stack".to_string(), Value::String(stack));
 }
 
-// TODO: Use proper exceptions, rather than strings
-pub fn throw_string<T>(err: String) -> Result<T, Value> {
-    Err(Value::String(err))
+/// Links an error thrown by `throw_error` to the prototype of its constructor in `global`
+pub fn attach_error_prototype(error: &Value, global: Object) {
+    if let &Value::Object(Object::Object(ref o)) = error {
+        let kind = match o.borrow().otype {
+            ObjectExtension::Error(kind) => kind,
+            _ => return
+        };
+
+        if o.borrow().prototype != Object::Null {
+            return;
+        }
+
+        if let Ok(Value::Object(proto)) = global.get(kind.name()).get("prototype", global.clone()) {
+            o.borrow_mut().prototype = proto;
+        }
+    }
 }
 
 // TODO: come up with better name, or get rid of this altogether
@@ -445,14 +1616,38 @@ pub fn throw_string<T>(err: String) -> Result<T, Value> {
 pub enum Tri {
     Continue(Value),
     Return(Value),
-    Error(Value)
+    Error(Value),
+    Break(Option<ast::Identifier>),
+    ContinueLoop(Option<ast::Identifier>)
+}
+
+impl Tri {
+    /// Converts the completion of a function body or script into a result
+    ///
+    /// A `break` or `continue` that escapes every loop and label is an error.
+    pub fn into_result(self) -> JSResult {
+        match self {
+            Tri::Continue(v) => Ok(v),
+            Tri::Return(v) => Ok(v),
+            Tri::Error(e) => Err(e),
+            Tri::Break(Some(label)) | Tri::ContinueLoop(Some(label)) => throw_error(ErrorKind::SyntaxError, format!("label {} is not defined", label)),
+            Tri::Break(None) => throw_error(ErrorKind::SyntaxError, "break must be inside a loop or labelled statement".to_string()),
+            Tri::ContinueLoop(None) => throw_error(ErrorKind::SyntaxError, "continue must be inside a loop".to_string())
+        }
+    }
 }
 
+/// Evaluates a block, which gets a scope of its own if it declares `let` or `const` variables
 fn eval_inner_block(block: &ast::InnerBlock, context: Context) -> Tri {
+    let context = if block.block.iter().any(ast::Statement::is_lexical_declaration) {
+        Context {local: context.local.block(), .. context}
+    } else {
+        context
+    };
+
     match eval_block(&block.block, context.clone()) {
         Tri::Continue(_) => (),
-        Tri::Return(v) => return Tri::Return(v),
-        Tri::Error(e) => return Tri::Error(e)
+        other => return other
     }
 
     match &block.return_exp {
@@ -465,18 +1660,18 @@ fn eval_inner_block(block: &ast::InnerBlock, context: Context) -> Tri {
 }
 
 fn eval_call(function: &ast::Expression, arguments: &ast::ExpressionList, mut context: Context) -> JSResult {
-    let func = try!(eval_expression(function, context.clone()));
-    let args = try!(eval_expression_list(arguments, context.clone()));
-
-    context.this = match function {
-        &ast::Expression::Access(ref a) => match a {
-            // TODO: This is probably bad -- p is evaluated **twice**, and therefore side effects happen twice
-            &ast::Access::Member(ref p, _) => try!(eval_expression(p, context.clone())),
-            _ => Value::Object(context.global.clone())
+    // Methods are called with their object as `this`, which is only evaluated once
+    let (func, this) = match function.unlocated() {
+        &ast::Expression::Access(ast::Access::Member(ref p, ref a)) => {
+            let obj = try!(eval_expression(p, context.clone()));
+            let id = try!(eval_accessor(a, context.clone()));
+            (try!(obj.get_in(&id, &context)), obj)
         },
-        _ => Value::Object(context.global.clone())
+        _ => (try!(eval_expression(function, context.clone())), Value::Object(context.global.clone()))
     };
+    let args = try!(eval_expression_list(arguments, context.clone()));
 
+    context.this = this;
     func.apply(args, context)
 }
 
@@ -484,14 +1679,17 @@ fn eval_new(function: &ast::Expression, arguments: &ast::ExpressionList, mut con
     let func = try!(eval_expression(function, context.clone()));
     let args = try!(eval_expression_list(arguments, context.clone()));
 
-    let proto = match func.get("prototype", context.global.clone()) {
+    let proto = match func.get_in("prototype", &context) {
         Ok(Value::Object(o)) => o,
-        _ => return throw_string("prototype must be an object".to_string())
+        _ => return throw_error(ErrorKind::TypeError, "prototype must be an object".to_string())
     };
     context.this = Value::Object(Object::create(proto));
 
-    try!(func.apply(args, context.clone()));
-    Ok(context.this)
+    // A constructor that returns an object replaces the newly created one
+    match try!(func.apply(args, context.clone())) {
+        obj @ Value::Object(Object::Object(_)) => Ok(obj),
+        _ => Ok(context.this)
+    }
 }
 
 fn eval_expression_list(expressions: &Vec<ast::Expression>, context: Context) -> Result<Vec<Value>, Value> {
@@ -508,30 +1706,51 @@ fn eval_expression_list(expressions: &Vec<ast::Expression>, context: Context) ->
 fn eval_accessor(acor: &ast::Accessor, context: Context) -> Result<String, Value> {
     match acor {
         &ast::Accessor::Identifier(ref id) => Ok(id.clone()),
-        &ast::Accessor::Expression(ref e) => try!(eval_expression(e, context.clone())).js_to_string(context.global)
+        &ast::Accessor::Expression(ref e) => try!(eval_expression(e, context.clone())).js_to_string(&context)
     }
 }
 
-fn access_get(access: &ast::Access, context: Context) -> JSResult {
-    match access {
-        &ast::Access::Member(ref e, ref a) => {
-            let id = try!(eval_accessor(a, context.clone()));
-            eval_expression(e, context.clone()).get(&id, context.global)
-        },
-        &ast::Access::Identifier(ref i) => context.local.get_or_err(i)
+/// The target of an assignment, with any object and key expressions already evaluated
+enum Reference {
+    Identifier(ast::Identifier),
+    Member(Value, String)
+}
+
+impl Reference {
+    fn get(&self, context: Context) -> JSResult {
+        match self {
+            &Reference::Identifier(ref i) => context.local.get(i, &context),
+            &Reference::Member(ref obj, ref id) => obj.get_in(id, &context)
+        }
+    }
+
+    fn set(&self, context: Context, val: Value) -> JSResult {
+        match self {
+            &Reference::Identifier(ref i) => context.local.set(i, val, &context),
+            &Reference::Member(ref obj, ref id) => obj.put(id, val, &context)
+        }
     }
 }
 
-fn access_set(access: &ast::Access, context: Context, val: Value) -> JSResult {
+fn resolve_access(access: &ast::Access, context: Context) -> Result<Reference, Value> {
     match access {
         &ast::Access::Member(ref e, ref a) => {
-            let id = try!(eval_accessor(a, context.clone()));
-            try!(eval_expression(e, context)).set(&id, val)
+            let obj = try!(eval_expression(e, context.clone()));
+            let id = try!(eval_accessor(a, context));
+            Ok(Reference::Member(obj, id))
         },
-        &ast::Access::Identifier(ref i) => context.local.outer_set(i, val)
+        &ast::Access::Identifier(ref i) => Ok(Reference::Identifier(i.clone()))
     }
 }
 
+fn access_get(access: &ast::Access, context: Context) -> JSResult {
+    try!(resolve_access(access, context.clone())).get(context)
+}
+
+fn access_set(access: &ast::Access, context: Context, val: Value) -> JSResult {
+    try!(resolve_access(access, context.clone())).set(context, val)
+}
+
 fn eval_unary(op: &ast::UnaryOp, exp: &ast::Expression, context: Context) -> JSResult {
     let val = try!(eval_expression(exp, context));
 
@@ -540,6 +1759,8 @@ fn eval_unary(op: &ast::UnaryOp, exp: &ast::Expression, context: Context) -> JSR
         &ast::UnaryOp::Negative => Ok(Value::Number(-val.to_number())),
 
         &ast::UnaryOp::LogicalNot => Ok(Value::Boolean(!val.to_boolean())),
+
+        &ast::UnaryOp::BitwiseNot => Ok(Value::Number(!val.to_int32() as f64)),
     }
 }
 
@@ -547,8 +1768,6 @@ fn eval_binary(op: &ast::BinaryOp, left: &ast::Expression, right: &ast::Expressi
     let left = try!(eval_expression(left, context.clone()));
 
     match op {
-        &ast::BinaryOp::Add => left.add(&try!(eval_expression(right, context.clone())), context.global),
-        &ast::BinaryOp::Subtract => Ok(Value::Number(left.to_number() - try!(eval_expression(right, context.clone())).to_number())),
         &ast::BinaryOp::LogicalAnd => Ok(if left.to_boolean() {
             let right = try!(eval_expression(right, context.clone()));
             if right.to_boolean() {
@@ -569,38 +1788,185 @@ fn eval_binary(op: &ast::BinaryOp, left: &ast::Expression, right: &ast::Expressi
                 Value::Boolean(false)
             }
         }),
+        _ => {
+            let right = try!(eval_expression(right, context.clone()));
+            apply_binary(op, &left, &right, &context)
+        }
+    }
+}
 
-        &ast::BinaryOp::Multiply => Ok(Value::Number(left.to_number() * try!(eval_expression(right, context.clone())).to_number())),
-        &ast::BinaryOp::Divide => Ok(Value::Number(left.to_number() / try!(eval_expression(right, context.clone())).to_number())),
-
-        &ast::BinaryOp::StrictEquals => Ok(Value::Boolean(left.strict_equals(&try!(eval_expression(right, context.clone())))))
+/// Applies a binary operator to values that have already been evaluated
+///
+/// The logical operators short-circuit, so they are only handled by `eval_binary`.
+fn apply_binary(op: &ast::BinaryOp, left: &Value, right: &Value, context: &Context) -> JSResult {
+    match op {
+        &ast::BinaryOp::Add => left.add(right, context),
+        &ast::BinaryOp::Subtract => Ok(Value::Number(left.to_number() - right.to_number())),
+        &ast::BinaryOp::LogicalAnd | &ast::BinaryOp::LogicalOr => unreachable!("logical operators are evaluated by eval_binary"),
+
+        &ast::BinaryOp::Multiply => Ok(Value::Number(left.to_number() * right.to_number())),
+        &ast::BinaryOp::Divide => Ok(Value::Number(left.to_number() / right.to_number())),
+        &ast::BinaryOp::Modulo => Ok(Value::Number(left.to_number() % right.to_number())),
+
+        &ast::BinaryOp::BitwiseAnd => Ok(Value::Number((left.to_int32() & right.to_int32()) as f64)),
+        &ast::BinaryOp::BitwiseOr => Ok(Value::Number((left.to_int32() | right.to_int32()) as f64)),
+        &ast::BinaryOp::BitwiseXor => Ok(Value::Number((left.to_int32() ^ right.to_int32()) as f64)),
+
+        // Only the low five bits of the shift count are used
+        &ast::BinaryOp::LeftShift => Ok(Value::Number(left.to_int32().wrapping_shl(right.to_uint32()) as f64)),
+        &ast::BinaryOp::SignedRightShift => Ok(Value::Number(left.to_int32().wrapping_shr(right.to_uint32()) as f64)),
+        &ast::BinaryOp::UnsignedRightShift => Ok(Value::Number(left.to_uint32().wrapping_shr(right.to_uint32()) as f64)),
+
+        &ast::BinaryOp::Equals => Ok(Value::Boolean(try!(left.equals(right, context)))),
+        &ast::BinaryOp::NotEquals => Ok(Value::Boolean(!try!(left.equals(right, context)))),
+        &ast::BinaryOp::StrictEquals => Ok(Value::Boolean(left.strict_equals(right))),
+        &ast::BinaryOp::InstanceOf => Ok(Value::Boolean(try!(left.instance_of(right, context)))),
+        &ast::BinaryOp::StrictNotEquals => Ok(Value::Boolean(!left.strict_equals(right))),
+
+        &ast::BinaryOp::LessThan |
+        &ast::BinaryOp::GreaterThan |
+        &ast::BinaryOp::LessThanOrEqual |
+        &ast::BinaryOp::GreaterThanOrEqual => {
+            let ordering = try!(left.compare(right, context));
+
+            Ok(Value::Boolean(match (op, ordering) {
+                (_, None) => false,
+                (&ast::BinaryOp::LessThan, Some(o)) => o == cmp::Ordering::Less,
+                (&ast::BinaryOp::GreaterThan, Some(o)) => o == cmp::Ordering::Greater,
+                (&ast::BinaryOp::LessThanOrEqual, Some(o)) => o != cmp::Ordering::Greater,
+                (_, Some(o)) => o != cmp::Ordering::Less
+            }))
+        }
     }
 }
 
+/// Evaluates a compound assignment such as `a += b`
+///
+/// The target is only resolved once, so any expressions in it are evaluated once.
+fn eval_compound_assignment(op: &ast::BinaryOp, access: &ast::Access, rhs: &ast::Expression, context: Context) -> JSResult {
+    let reference = try!(resolve_access(access, context.clone()));
+    let left = try!(reference.get(context.clone()));
+    let right = try!(eval_expression(rhs, context.clone()));
+
+    let result = try!(apply_binary(op, &left, &right, &context));
+    reference.set(context, result)
+}
+
+/// Evaluates `++` or `--`, returning the new value if `prefix` is set and the old one otherwise
+fn eval_update(op: &ast::UpdateOp, access: &ast::Access, prefix: bool, context: Context) -> JSResult {
+    let reference = try!(resolve_access(access, context.clone()));
+    let old = try!(reference.get(context.clone())).to_number();
+
+    let new = match op {
+        &ast::UpdateOp::Increment => old + 1.0,
+        &ast::UpdateOp::Decrement => old - 1.0
+    };
+
+    try!(reference.set(context, Value::Number(new)));
+    Ok(Value::Number(if prefix { new } else { old }))
+}
+
 fn new_function(f: ast::Function, context: Context) -> JSResult {
     let fp = match try!(try!(context.global.get("Function")).get("prototype", context.global.clone())) {
         Value::Object(o) => o,
-        _ => return throw_string("Function.prototype must be an object".to_string())
+        _ => return throw_error(ErrorKind::TypeError, "Function.prototype must be an object".to_string())
     };
 
     let op = match try!(try!(context.global.get("Object")).get("prototype", context.global.clone())) {
         Value::Object(o) => o,
-        _ => return throw_string("Object.prototype must be an object".to_string())
+        _ => return throw_error(ErrorKind::TypeError, "Object.prototype must be an object".to_string())
     };
 
-    let func = Object::from_function(Function::User(UserFunction::new(f.clone(), context.local)), fp);
+    let func = Object::from_function(Function::User(UserFunction::new(f.clone(), context.local, context.file.clone(), context.strict)), fp);
     let proto = Object::create(op);
     try!(proto.set("constructor", Value::Object(func.clone())));
     try!(func.set("prototype", Value::Object(proto)));
     Ok(Value::Object(func))
 }
 
+/// Binds the arguments of a call in the scope of the function being called
+///
+/// Missing and `undefined` arguments take the default value of their parameter, which is
+/// evaluated after the parameters before it have been bound. Any arguments past the last
+/// parameter go into the rest parameter, and all of them are in the `arguments` object.
+fn bind_parameters(function: &ast::Function, arguments: Vec<Value>, context: &Context) -> Result<(), Value> {
+    let arguments_object = try!(new_object(context.global.clone()));
+    for (index, argument) in arguments.iter().enumerate() {
+        try!(arguments_object.set(&index.to_string(), argument.clone()));
+    }
+
+    try!(arguments_object.define_own_property("length", PropertyDescriptor {
+        value: Some(Value::Number(arguments.len() as f64)),
+        writable: Some(true),
+        enumerable: Some(false),
+        configurable: Some(true),
+        .. PropertyDescriptor::default()
+    }));
+
+    // A parameter called `arguments` takes the place of the object
+    if !function.parameters.iter().any(|parameter| parameter.id == "arguments") {
+        try!(context.local.declare("arguments", Value::Object(arguments_object)));
+    }
+
+    // Default values can only see the parameters before them, so every parameter starts out
+    // uninitialized, like a `let` variable, instead of falling through to an outer variable
+    let has_defaults = function.parameters.iter().any(|parameter| parameter.default.is_some());
+    if has_defaults {
+        for parameter in &function.parameters {
+            try!(context.local.declare_parameter(&parameter.id));
+        }
+    }
+
+    for (index, parameter) in function.parameters.iter().enumerate() {
+        let value = match (arguments.get(index), &parameter.default) {
+            (None, &Some(ref default)) | (Some(&Value::Undefined), &Some(ref default)) => try!(eval_expression(default, context.clone())),
+            (Some(argument), _) => argument.clone(),
+            (None, &None) => Value::Undefined
+        };
+
+        if has_defaults {
+            try!(context.local.initialize(&parameter.id, value));
+        } else {
+            try!(context.local.declare(&parameter.id, value));
+        }
+    }
+
+    if let Some(ref rest) = function.rest {
+        let rest_arguments = arguments.iter().skip(function.parameters.len()).cloned().collect();
+        try!(context.local.declare(rest, Value::Object(try!(new_array(rest_arguments, context.global.clone())))));
+    }
+
+    Ok(())
+}
+
+/// Creates an array holding `elements`, using the `Array.prototype` found in `global`
+pub fn new_array(elements: Vec<Value>, global: Object) -> Result<Object, Value> {
+    let proto = match try!(try!(global.get("Array")).get("prototype", global.clone())) {
+        Value::Object(o) => o,
+        _ => return throw_error(ErrorKind::TypeError, "Array.prototype must be an object".to_string())
+    };
+
+    Ok(Object::from_array(elements, proto))
+}
+
+pub fn new_object(global: Object) -> Result<Object, Value> {
+    let proto = match try!(try!(global.get("Object")).get("prototype", global.clone())) {
+        Value::Object(o) => o,
+        _ => return throw_error(ErrorKind::TypeError, "Object.prototype must be an object".to_string())
+    };
+
+    Ok(Object::create(proto))
+}
+
 fn eval_expression(expression: &ast::Expression, context: Context) -> JSResult {
     match expression {
         &ast::Expression::Assignment(ref lhs, ref rhs) => {
             let rhs = try!(eval_expression(rhs, context.clone()));
             access_set(lhs, context, rhs)
         },
+        &ast::Expression::CompoundAssignment(ref op, ref lhs, ref rhs) => eval_compound_assignment(op, lhs, rhs, context),
+        &ast::Expression::PrefixUpdate(ref op, ref a) => eval_update(op, a, true, context),
+        &ast::Expression::PostfixUpdate(ref op, ref a) => eval_update(op, a, false, context),
         &ast::Expression::Call(ref f, ref a) => eval_call(f, a, context),
         &ast::Expression::New(ref c, ref a) => eval_new(c, a, context),
         &ast::Expression::Access(ref a) => access_get(a, context),
@@ -620,22 +1986,61 @@ fn eval_expression(expression: &ast::Expression, context: Context) -> JSResult {
         &ast::Expression::Object(ref exprs) => {
             let proto = match try!(try!(context.global.get("Object")).get("prototype", context.global.clone())) {
                 Value::Object(o) => o,
-                _ => try!(throw_string("Object.prototype must be an object".to_string()))
+                _ => try!(throw_error(ErrorKind::TypeError, "Object.prototype must be an object".to_string()))
             };
 
-            let mut obj = HashMap::new();
-            for &(ref key, ref expr) in exprs {
-                obj.insert(key.clone(), try!(eval_expression(expr, context.clone())));
+            // Properties are defined rather than assigned, so that setters on the prototype aren't called
+            let obj = Object::create(proto);
+            for property in exprs {
+                let (key, descriptor) = match property {
+                    &ast::Property::Value(ref key, ref expr) => (key, PropertyDescriptor {
+                        value: Some(try!(eval_expression(expr, context.clone()))),
+                        writable: Some(true),
+                        .. PropertyDescriptor::default()
+                    }),
+                    &ast::Property::Getter(ref key, ref f) => (key, PropertyDescriptor {
+                        get: Some(try!(new_function(f.clone(), context.clone()))),
+                        .. PropertyDescriptor::default()
+                    }),
+                    &ast::Property::Setter(ref key, ref f) => (key, PropertyDescriptor {
+                        set: Some(try!(new_function(f.clone(), context.clone()))),
+                        .. PropertyDescriptor::default()
+                    })
+                };
+
+                try!(obj.define_own_property(key, PropertyDescriptor {enumerable: Some(true), configurable: Some(true), .. descriptor}));
+            }
+
+            Ok(Value::Object(obj))
+        },
+        &ast::Expression::Array(ref elements) => {
+            let array = try!(new_array(vec![], context.global.clone()));
+
+            for (index, element) in elements.iter().enumerate() {
+                if let &Some(ref e) = element {
+                    try!(array.set(&index.to_string(), try!(eval_expression(e, context.clone()))));
+                }
             }
 
-            Ok(Value::Object(Object::from_map(obj, proto)))
+            // Trailing holes still count towards the length
+            try!(array.set("length", Value::Number(elements.len() as f64)));
+            Ok(Value::Object(array))
         },
-        &ast::Expression::This => Ok(context.this)
+        &ast::Expression::This => Ok(context.this),
+        &ast::Expression::Located(position, ref e) => {
+            let previous = context.stack.set_position(Some(position));
+
+            let result = eval_expression(e, context.clone()).inspect_err(|error| locate_error(error, position, &context));
+
+            context.stack.set_position(previous);
+            result
+        }
     }
 }
 
 fn eval_statement(statement: &ast::Statement, context: Context) -> Tri {
     match statement {
+        &ast::Statement::Located(position, ref s) => eval_located(position, &context, || eval_statement(s, context.clone())),
         &ast::Statement::Expression(ref e) => match eval_expression(e, context) {
             Ok(v) => Tri::Continue(v),
             Err(e) => Tri::Error(e)
@@ -648,7 +2053,8 @@ fn eval_statement(statement: &ast::Statement, context: Context) -> Tri {
                         Err(e) => return Tri::Error(e)
                     };
 
-                    match context.local.set(id, init_val) {
+                    // The variable was declared when it was hoisted, so this only assigns to it
+                    match context.local.set(id, init_val, &context) {
                         Ok(_) => (),
                         Err(e) => return Tri::Error(e)
                     };
@@ -656,14 +2062,43 @@ fn eval_statement(statement: &ast::Statement, context: Context) -> Tri {
 
                 Tri::Continue(Value::Undefined)
             },
-            _ => Tri::Continue(Value::Undefined)
+            &ast::Declaration::Let(ref id, ref init) => {
+                let init_val = match init {
+                    &Some(ref expr) => match eval_expression(expr, context.clone()) {
+                        Ok(v) => v,
+                        Err(e) => return Tri::Error(e)
+                    },
+                    &None => Value::Undefined
+                };
+
+                match context.local.initialize(id, init_val) {
+                    Ok(()) => Tri::Continue(Value::Undefined),
+                    Err(e) => Tri::Error(e)
+                }
+            },
+            &ast::Declaration::Const(ref id, ref init) => {
+                let init_val = match eval_expression(init, context.clone()) {
+                    Ok(v) => v,
+                    Err(e) => return Tri::Error(e)
+                };
+
+                match context.local.initialize(id, init_val) {
+                    Ok(()) => Tri::Continue(Value::Undefined),
+                    Err(e) => Tri::Error(e)
+                }
+            },
+            &ast::Declaration::Function(..) => Tri::Continue(Value::Undefined)
         },
         &ast::Statement::Throw(ref e) => {
-            let error_val = match eval_expression(e, context) {
+            let error_val = match eval_expression(e, context.clone()) {
                 Ok(v) => v,
                 Err(e) => return Tri::Error(e)
             };
 
+            if let &ast::Expression::Located(position, _) = e {
+                locate_error(&error_val, position, &context);
+            }
+
             Tri::Error(error_val)
         },
         &ast::Statement::If(ref condition, ref consequent, ref alternate) => {
@@ -679,42 +2114,218 @@ fn eval_statement(statement: &ast::Statement, context: Context) -> Tri {
             } else {
                 Tri::Continue(Value::Undefined)
             }
-        }
+        },
+        &ast::Statement::While(..) | &ast::Statement::DoWhile(..) | &ast::Statement::For(..) | &ast::Statement::Labelled(..) => {
+            eval_labelled(statement, &[], context)
+        },
+        &ast::Statement::Break(ref label) => Tri::Break(label.clone()),
+        &ast::Statement::Continue(ref label) => Tri::ContinueLoop(label.clone()),
+        &ast::Statement::Block(ref block) => eval_inner_block(block, context),
+        &ast::Statement::Try(ref block, ref handler, ref finalizer) => eval_try(block, handler.as_ref(), finalizer.as_ref(), context),
         &ast::Statement::Empty => Tri::Continue(Value::Undefined)
     }
 }
 
+/// Evaluates a `try` statement
+///
+/// The catch parameter is bound in a scope of its own. A `finally` block that completes
+/// normally keeps the completion of the `try` or `catch` block, while any other completion
+/// (`return`, `throw`, `break` or `continue`) replaces it.
+fn eval_try(block: &ast::InnerBlock, handler: Option<&(ast::Identifier, ast::InnerBlock)>, finalizer: Option<&ast::InnerBlock>, context: Context) -> Tri {
+    let result = match (eval_inner_block(block, context.clone()), handler) {
+        (Tri::Error(e), Some(&(ref id, ref catch_block))) => {
+            attach_error_prototype(&e, context.global.clone());
+
+            let catch_scope = context.local.block();
+            if let Err(e) = catch_scope.declare(id, e) {
+                return Tri::Error(e);
+            }
+
+            eval_inner_block(catch_block, Context {local: catch_scope, .. context.clone()})
+        },
+        (result, _) => result
+    };
+
+    match finalizer {
+        Some(finalizer) => match eval_inner_block(finalizer, context) {
+            Tri::Continue(_) => result,
+            other => other
+        },
+        None => result
+    }
+}
+
+/// Runs `evaluate` with the innermost frame at `position`, which also locates any error it throws
+fn eval_located<F>(position: ast::Position, context: &Context, evaluate: F) -> Tri where F: FnOnce() -> Tri {
+    let previous = context.stack.set_position(Some(position));
+    let result = evaluate();
+    context.stack.set_position(previous);
+
+    if let Tri::Error(ref error) = result {
+        locate_error(error, position, context);
+    }
+
+    result
+}
+
+/// Evaluates `statement` with the labels that were attached to it
+///
+/// Loops consume `break` and `continue` statements that target them, and any labelled
+/// statement consumes a `break` to its own label.
+fn eval_labelled(statement: &ast::Statement, labels: &[ast::Identifier], context: Context) -> Tri {
+    let result = match statement {
+        &ast::Statement::Located(position, ref body) => {
+            return eval_located(position, &context, || eval_labelled(body, labels, context.clone()));
+        },
+        &ast::Statement::Labelled(ref label, ref body) => {
+            let mut labels = labels.to_vec();
+            labels.push(label.clone());
+
+            return eval_labelled(body, &labels, context);
+        },
+        &ast::Statement::While(ref condition, ref body) => eval_loop(Some(condition), None, body, true, false, labels, context),
+        &ast::Statement::DoWhile(ref body, ref condition) => eval_loop(Some(condition), None, body, false, false, labels, context),
+        &ast::Statement::For(ref init, ref test, ref update, ref body) => {
+            // Variables declared with `let` or `const` belong to the loop, with a fresh copy for each iteration
+            let lexical = match init {
+                &Some(ref init) => match **init {
+                    ast::Statement::Declaration(ref decl) => decl.is_lexical(),
+                    _ => false
+                },
+                &None => false
+            };
+            let context = if lexical {
+                Context {local: context.local.block(), .. context}
+            } else {
+                context
+            };
+
+            if let &Some(ref init) = init {
+                if let ast::Statement::Declaration(ref decl) = **init {
+                    if let Err(e) = hoist_declaration(decl, context.clone()) {
+                        return Tri::Error(e);
+                    }
+                }
+
+                match eval_statement(init, context.clone()) {
+                    Tri::Continue(_) => (),
+                    other => return other
+                }
+            }
+
+            eval_loop(test.as_ref(), update.as_ref(), body, true, lexical, labels, context)
+        },
+        _ => eval_statement(statement, context)
+    };
+
+    match result {
+        Tri::Break(Some(ref label)) if labels.contains(label) => Tri::Continue(Value::Undefined),
+        other => other
+    }
+}
+
+/// Runs `body` until `condition` is falsy, evaluating `update` after each iteration
+///
+/// A missing `condition` loops forever. If `test_first` is false the body runs once before
+/// the condition is first checked, as in `do ... while`. If `copy_scope` is set, each iteration
+/// gets its own copy of the variables in the loop's scope. `labels` are the labels this loop
+/// answers to for `break` and `continue`.
+fn eval_loop(condition: Option<&ast::Expression>, update: Option<&ast::Expression>, body: &ast::InnerBlock, test_first: bool, copy_scope: bool, labels: &[ast::Identifier], context: Context) -> Tri {
+    let mut last = Value::Undefined;
+    let mut first = true;
+    let mut context = context;
+
+    if copy_scope {
+        context.local = context.local.copy();
+    }
+
+    loop {
+        if let Some(condition) = condition {
+            if test_first || !first {
+                match eval_expression(condition, context.clone()) {
+                    Ok(v) => if !v.to_boolean() {
+                        break;
+                    },
+                    Err(e) => return Tri::Error(e)
+                }
+            }
+        }
+        first = false;
+
+        match eval_inner_block(body, context.clone()) {
+            Tri::Continue(v) => last = v,
+            Tri::Break(None) => break,
+            Tri::Break(Some(ref label)) if labels.contains(label) => break,
+            Tri::ContinueLoop(None) => (),
+            Tri::ContinueLoop(Some(ref label)) if labels.contains(label) => (),
+            other => return other
+        }
+
+        if copy_scope {
+            context.local = context.local.copy();
+        }
+
+        if let Some(update) = update {
+            if let Err(e) = eval_expression(update, context.clone()) {
+                return Tri::Error(e);
+            }
+        }
+    }
+
+    Tri::Continue(last)
+}
+
+/// Binds a declaration before any statements in its block run
+///
+/// Variables declared with `var` start out as `undefined` in the function around them, and
+/// functions are created there immediately. `let` and `const` variables belong to the block
+/// and can't be used until their declaration runs.
+fn hoist_declaration(decl: &ast::Declaration, context: Context) -> Result<(), Value> {
+    match decl {
+        &ast::Declaration::Variable(ref id, _) => try!(context.local.declare_var(id)),
+        &ast::Declaration::Let(ref id, _) => try!(context.local.declare_lexical(id, true)),
+        &ast::Declaration::Const(ref id, _) => try!(context.local.declare_lexical(id, false)),
+        &ast::Declaration::Function(ref id, ref f) => {
+            let function = try!(new_function(f.clone(), context.clone()));
+            try!(try!(context.local.var_scope_for(id)).declare(id, function));
+        }
+    };
+
+    Ok(())
+}
+
 pub fn eval_block(program: &ast::Block, context: Context) -> Tri {
     let mut last = Value::Undefined;
 
     // inefficient (I think) but convenient to parse
+    let mut declared: Vec<(&str, bool)> = vec![];
     for statement in program {
-        if let &ast::Statement::Declaration(ref decl) = statement {
-            match decl {
-                &ast::Declaration::Variable(ref id, _) => match context.local.set(id, Value::Undefined) {
-                    Ok(_) => (),
-                    Err(e) => return Tri::Error(e)
-                },
-                &ast::Declaration::Function(ref id, ref f) => {
-                    let function = match new_function(f.clone(), context.clone()) {
-                        Ok(f) => f,
-                        Err(e) => return Tri::Error(e)
-                    };
+        if let &ast::Statement::Declaration(ref decl) = statement.unlocated() {
+            // Only `var` and function declarations can share a name in the same block. This also
+            // catches the global scope, where `var` declarations are kept on the global object
+            // instead of alongside `let` and `const` ones.
+            let (id, lexical) = (decl.id().as_str(), decl.is_lexical());
+            let result = if declared.iter().any(|&(other, other_lexical)| other == id && (lexical || other_lexical)) {
+                throw_error(ErrorKind::SyntaxError, format!("redeclaration of {}", id))
+            } else {
+                hoist_declaration(decl, context.clone())
+            };
+            declared.push((id, lexical));
 
-                    match context.local.set(id, function) {
-                        Ok(_) => (),
-                        Err(e) => return Tri::Error(e)
-                    }
+            if let Err(e) = result {
+                if let &ast::Statement::Located(position, _) = statement {
+                    locate_error(&e, position, &context);
                 }
-            };
+
+                return Tri::Error(e);
+            }
         }
     }
 
     for statement in program {
         last = match eval_statement(statement, context.clone()) {
             Tri::Continue(v) => v,
-            Tri::Return(v) => return Tri::Return(v),
-            Tri::Error(e) => return Tri::Error(e)
+            other => return other
         };
     }
 
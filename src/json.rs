@@ -0,0 +1,499 @@
+//! `JSON.parse` and `JSON.stringify`
+
+use std::{f64, char};
+
+use super::interpret::{self, Value, Object, Context, JSResult, ErrorKind, throw_error};
+
+/// How deeply objects and arrays can be nested, which bounds the recursion of parsing, reviving
+/// and stringifying
+const MAX_DEPTH: usize = 512;
+
+/// Parses JSON `text`, passing every value through `reviver` if it's a function
+pub fn parse(text: &str, reviver: &Value, context: &Context) -> JSResult {
+    let mut parser = Parser { chars: text.chars().collect(), position: 0, depth: 0, context: context };
+
+    parser.skip_whitespace();
+    let value = try!(parser.value());
+    parser.skip_whitespace();
+    if parser.position < parser.chars.len() {
+        return parser.unexpected();
+    }
+
+    if !reviver.is_function() {
+        return Ok(value);
+    }
+
+    let holder = try!(interpret::new_object(context.global.clone()));
+    try!(holder.define_value("", value));
+    revive(&Value::Object(holder), "", reviver, 0, context)
+}
+
+/// Calls the reviver on the property `key` of `holder`, after reviving everything inside it
+///
+/// `depth` is the number of objects that `holder` is inside.
+fn revive(holder: &Value, key: &str, reviver: &Value, depth: usize, context: &Context) -> JSResult {
+    let value = try!(holder.get_in(key, context));
+
+    if let Value::Object(ref object @ Object::Object(_)) = value {
+        // The reviver can make objects that are deeper than any the parser made
+        if depth >= MAX_DEPTH {
+            return throw_error(ErrorKind::RangeError, "JSON.parse: nesting is too deep to revive".to_string());
+        }
+
+        let keys = if object.is_array() {
+            let length = try!(value.get_in("length", context)).to_uint32();
+            if length > interpret::MAX_ELEMENTS {
+                return throw_error(ErrorKind::RangeError, format!("JSON.parse: array of length {} is too long to revive", length));
+            }
+            (0..length).map(|index| index.to_string()).collect()
+        } else {
+            try!(object.keys())
+        };
+
+        // Revived values become own properties, without running setters on the prototype chain
+        for key in keys {
+            match try!(revive(&value, &key, reviver, depth + 1, context)) {
+                Value::Undefined => { try!(object.delete(&key)); },
+                revived => try!(object.define_value(&key, revived))
+            }
+        }
+    }
+
+    reviver.apply(
+        vec![Value::String(key.to_string()), value],
+        Context {this: holder.clone(), local: interpret::Scope::new(context.global.clone()), .. context.clone()}
+    )
+}
+
+struct Parser<'a> {
+    chars: Vec<char>,
+    position: usize,
+    /// The number of objects and arrays that are open
+    depth: usize,
+    context: &'a Context
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<char> {
+        self.chars.get(self.position).cloned()
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.peek();
+        self.position += 1;
+        c
+    }
+
+    fn unexpected<T>(&self) -> Result<T, Value> {
+        match self.peek() {
+            Some(c) => throw_error(ErrorKind::SyntaxError, format!("JSON.parse: unexpected character '{}' at position {}", c, self.position)),
+            None => throw_error(ErrorKind::SyntaxError, "JSON.parse: unexpected end of data".to_string())
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), Value> {
+        if self.peek() == Some(expected) {
+            self.position += 1;
+            Ok(())
+        } else {
+            self.unexpected()
+        }
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(' ') | Some('\t') | Some('\n') | Some('\r') = self.peek() {
+            self.position += 1;
+        }
+    }
+
+    fn keyword(&mut self, keyword: &str, value: Value) -> JSResult {
+        for expected in keyword.chars() {
+            try!(self.expect(expected));
+        }
+
+        Ok(value)
+    }
+
+    fn value(&mut self) -> JSResult {
+        match self.peek() {
+            Some('{') | Some('[') => {
+                if self.depth >= MAX_DEPTH {
+                    return throw_error(ErrorKind::SyntaxError, format!("JSON.parse: nesting is too deep at position {}", self.position));
+                }
+
+                self.depth += 1;
+                let value = if self.peek() == Some('{') { self.object() } else { self.array() };
+                self.depth -= 1;
+                value
+            },
+            Some('"') => self.string().map(Value::String),
+            Some('-') | Some('0'..='9') => self.number(),
+            Some('t') => self.keyword("true", Value::Boolean(true)),
+            Some('f') => self.keyword("false", Value::Boolean(false)),
+            Some('n') => self.keyword("null", Value::Object(Object::Null)),
+            _ => self.unexpected()
+        }
+    }
+
+    fn object(&mut self) -> JSResult {
+        try!(self.expect('{'));
+        let object = try!(interpret::new_object(self.context.global.clone()));
+
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Value::Object(object));
+        }
+
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return self.unexpected();
+            }
+            let key = try!(self.string());
+
+            self.skip_whitespace();
+            try!(self.expect(':'));
+            self.skip_whitespace();
+            try!(object.define_value(&key, try!(self.value())));
+
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => (),
+                Some('}') => return Ok(Value::Object(object)),
+                _ => {
+                    self.position -= 1;
+                    return self.unexpected();
+                }
+            }
+        }
+    }
+
+    fn array(&mut self) -> JSResult {
+        try!(self.expect('['));
+        let mut elements = vec![];
+
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Value::Object(try!(interpret::new_array(elements, self.context.global.clone()))));
+        }
+
+        loop {
+            self.skip_whitespace();
+            elements.push(try!(self.value()));
+
+            self.skip_whitespace();
+            match self.next() {
+                Some(',') => (),
+                Some(']') => return Ok(Value::Object(try!(interpret::new_array(elements, self.context.global.clone())))),
+                _ => {
+                    self.position -= 1;
+                    return self.unexpected();
+                }
+            }
+        }
+    }
+
+    fn digits(&mut self) -> Result<(), Value> {
+        match self.peek() {
+            Some('0'..='9') => (),
+            _ => return self.unexpected()
+        }
+
+        while let Some('0'..='9') = self.peek() {
+            self.position += 1;
+        }
+
+        Ok(())
+    }
+
+    fn number(&mut self) -> JSResult {
+        let start = self.position;
+
+        if self.peek() == Some('-') {
+            self.position += 1;
+        }
+
+        // Numbers can't have leading zeros
+        if self.peek() == Some('0') {
+            self.position += 1;
+        } else {
+            try!(self.digits());
+        }
+
+        if self.peek() == Some('.') {
+            self.position += 1;
+            try!(self.digits());
+        }
+
+        if let Some('e') | Some('E') = self.peek() {
+            self.position += 1;
+            if let Some('+') | Some('-') = self.peek() {
+                self.position += 1;
+            }
+            try!(self.digits());
+        }
+
+        let text: String = self.chars[start..self.position].iter().cloned().collect();
+        Ok(Value::Number(text.parse().unwrap_or(f64::NAN)))
+    }
+
+    fn hex_escape(&mut self) -> Result<u32, Value> {
+        let mut code = 0;
+
+        for _ in 0..4 {
+            match self.peek().and_then(|c| c.to_digit(16)) {
+                Some(digit) => code = code * 16 + digit,
+                None => return self.unexpected()
+            }
+            self.position += 1;
+        }
+
+        Ok(code)
+    }
+
+    fn string(&mut self) -> Result<String, Value> {
+        try!(self.expect('"'));
+        let mut string = String::new();
+
+        loop {
+            match self.peek() {
+                Some('"') => {
+                    self.position += 1;
+                    return Ok(string);
+                },
+                Some('\\') => {
+                    self.position += 1;
+                    let escaped = match self.peek() {
+                        Some('"') => '"',
+                        Some('\\') => '\\',
+                        Some('/') => '/',
+                        Some('b') => '\u{8}',
+                        Some('f') => '\u{c}',
+                        Some('n') => '\n',
+                        Some('r') => '\r',
+                        Some('t') => '\t',
+                        Some('u') => {
+                            self.position += 1;
+                            string.push(try!(self.unicode_escape()));
+                            continue;
+                        },
+                        _ => return self.unexpected()
+                    };
+
+                    self.position += 1;
+                    string.push(escaped);
+                },
+                Some(c) if c >= ' ' => {
+                    self.position += 1;
+                    string.push(c);
+                },
+                _ => return self.unexpected()
+            }
+        }
+    }
+
+    /// Reads the digits of a `\u` escape, combining surrogate pairs
+    ///
+    /// Strings can't hold unpaired surrogates, so they become U+FFFD.
+    fn unicode_escape(&mut self) -> Result<char, Value> {
+        let code = try!(self.hex_escape());
+
+        if (0xD800..0xDC00).contains(&code) && self.chars[self.position..].starts_with(&['\\', 'u']) {
+            let start = self.position;
+            self.position += 2;
+            let low = try!(self.hex_escape());
+
+            if (0xDC00..0xE000).contains(&low) {
+                return Ok(char::from_u32(0x10000 + ((code - 0xD800) << 10) + (low - 0xDC00)).unwrap());
+            }
+
+            self.position = start;
+        }
+
+        Ok(char::from_u32(code).unwrap_or('\u{FFFD}'))
+    }
+}
+
+/// Converts `value` to JSON, or `undefined` if it has no JSON representation
+///
+/// `replacer` is either a function that is called on every property, or an array of the property
+/// names that are included. `space` is the indentation, as a number of spaces or a string.
+pub fn stringify(value: Value, replacer: &Value, space: &Value, context: &Context) -> JSResult {
+    let properties = match replacer {
+        &Value::Object(ref list) if list.is_array() => {
+            let length = try!(replacer.get_in("length", context)).to_uint32();
+            if length > interpret::MAX_ELEMENTS {
+                return throw_error(ErrorKind::RangeError, format!("JSON.stringify: replacer of length {} is too long", length));
+            }
+            let mut properties: Vec<String> = vec![];
+
+            for index in 0..length {
+                let property = match try!(replacer.get_in(&index.to_string(), context)) {
+                    Value::String(s) => s,
+                    Value::Number(n) => interpret::number_to_string(n),
+                    _ => continue
+                };
+
+                if !properties.contains(&property) {
+                    properties.push(property);
+                }
+            }
+
+            Some(properties)
+        },
+        _ => None
+    };
+
+    // The indentation is capped at ten characters
+    let gap = match space {
+        &Value::Number(n) if n >= 1.0 => spaces(n.min(10.0) as usize),
+        &Value::String(ref s) => s.chars().take(10).collect(),
+        _ => String::new()
+    };
+
+    let mut stringifier = Stringifier {
+        context: context,
+        replacer: if replacer.is_function() { Some(replacer.clone()) } else { None },
+        properties: properties,
+        gap: gap,
+        indent: String::new(),
+        stack: vec![]
+    };
+
+    let holder = try!(interpret::new_object(context.global.clone()));
+    try!(holder.define_value("", value));
+
+    match try!(stringifier.property(&Value::Object(holder), "")) {
+        Some(json) => Ok(Value::String(json)),
+        None => Ok(Value::Undefined)
+    }
+}
+
+fn spaces(count: usize) -> String {
+    (0..count).map(|_| ' ').collect()
+}
+
+/// Quotes a string, escaping it as JSON
+fn quote(string: &str) -> String {
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push('"');
+
+    for c in string.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\u{8}' => quoted.push_str("\\b"),
+            '\u{c}' => quoted.push_str("\\f"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if c < ' ' => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c)
+        }
+    }
+
+    quoted.push('"');
+    quoted
+}
+
+struct Stringifier<'a> {
+    context: &'a Context,
+    replacer: Option<Value>,
+    /// The only properties of objects that are included, if the replacer is an array
+    properties: Option<Vec<String>>,
+    gap: String,
+    indent: String,
+    /// The objects that are being stringified, which would make a cycle if they were reached again
+    stack: Vec<Object>
+}
+
+impl<'a> Stringifier<'a> {
+    fn call(&self, function: &Value, this: &Value, arguments: Vec<Value>) -> JSResult {
+        function.apply(arguments, Context {this: this.clone(), local: interpret::Scope::new(self.context.global.clone()), .. self.context.clone()})
+    }
+
+    /// Stringifies the property `key` of `holder`, returning `None` for values that are left out
+    fn property(&mut self, holder: &Value, key: &str) -> Result<Option<String>, Value> {
+        let mut value = try!(holder.get_in(key, self.context));
+
+        if let Value::Object(Object::Object(_)) = value {
+            let to_json = try!(value.get_in("toJSON", self.context));
+            if to_json.is_function() {
+                value = try!(self.call(&to_json, &value, vec![Value::String(key.to_string())]));
+            }
+        }
+
+        if let Some(replacer) = self.replacer.clone() {
+            value = try!(self.call(&replacer, holder, vec![Value::String(key.to_string()), value]));
+        }
+
+        Ok(match value {
+            Value::Object(Object::Null) => Some("null".to_string()),
+            Value::Boolean(b) => Some(b.to_string()),
+            Value::String(ref s) => Some(quote(s)),
+            Value::Number(n) if n.is_finite() => Some(interpret::number_to_string(n)),
+            Value::Number(_) => Some("null".to_string()),
+            ref function if function.is_function() => None,
+            Value::Object(ref object) => Some(try!(self.object(object))),
+            Value::Undefined => None
+        })
+    }
+
+    fn object(&mut self, object: &Object) -> Result<String, Value> {
+        if self.stack.contains(object) {
+            return throw_error(ErrorKind::TypeError, "cyclic object value".to_string());
+        }
+
+        if self.stack.len() >= MAX_DEPTH {
+            return throw_error(ErrorKind::RangeError, "JSON.stringify: nesting is too deep".to_string());
+        }
+
+        self.stack.push(object.clone());
+        let outer_indent = self.indent.clone();
+        self.indent.push_str(&self.gap.clone());
+
+        let holder = Value::Object(object.clone());
+        let mut members = vec![];
+        let (open, close) = if object.is_array() {
+            let length = try!(holder.get_in("length", self.context)).to_uint32();
+            if length > interpret::MAX_ELEMENTS {
+                return throw_error(ErrorKind::RangeError, format!("JSON.stringify: array of length {} is too long", length));
+            }
+            for index in 0..length {
+                let element = try!(self.property(&holder, &index.to_string()));
+                members.push(element.unwrap_or("null".to_string()));
+            }
+
+            ("[", "]")
+        } else {
+            let keys = match self.properties {
+                Some(ref properties) => properties.clone(),
+                None => try!(object.keys())
+            };
+
+            let separator = if self.gap.is_empty() { ":" } else { ": " };
+            for key in keys {
+                if let Some(member) = try!(self.property(&holder, &key)) {
+                    members.push(quote(&key) + separator + &member);
+                }
+            }
+
+            ("{", "}")
+        };
+
+        let json = if members.is_empty() {
+            open.to_string() + close
+        } else if self.gap.is_empty() {
+            open.to_string() + &members.join(",") + close
+        } else {
+            let separator = ",\n".to_string() + &self.indent;
+            format!("{}\n{}{}\n{}{}", open, self.indent, members.join(&separator), outer_indent, close)
+        };
+
+        self.stack.pop();
+        self.indent = outer_indent;
+        Ok(json)
+    }
+}
@@ -1,11 +1,19 @@
 //! Embeddable JavaScript interpreter.
 
+extern crate stacker;
+
+#[cfg(feature = "serde")]
 #[macro_use]
-extern crate maplit;
+extern crate serde;
 
 pub mod parser;
 
 pub mod ast;
 pub mod interpret;
+pub mod properties;
+pub mod convert;
+#[cfg(feature = "serde")]
+pub mod serialize;
 
 pub mod runtime;
+mod json;
@@ -6,12 +6,24 @@ use std::{io, env, fs};
 use std::io::Write;
 
 use yuk::parser;
-use yuk::runtime::Yuk;
+use yuk::runtime::{Yuk, Error};
+use yuk::interpret::Value;
 
 fn is_interactive() -> bool {
     (unsafe { libc::isatty(libc::STDIN_FILENO as i32) }) != 0
 }
 
+/// Describes an error from a script, including the stack trace of uncaught exceptions
+fn describe_error(yuk: &Yuk, error: &Error) -> String {
+    match error.thrown() {
+        Some(value@&Value::Object(_)) => match value.get("stack", yuk.global.clone()) {
+            Ok(Value::String(stack)) => stack,
+            _ => error.to_string()
+        },
+        _ => error.to_string()
+    }
+}
+
 fn start_repl() {
     let mut yuk = Yuk::create_stdlib();
 
@@ -34,7 +46,7 @@ fn start_repl() {
             source
         };
 
-        match yuk.eval(&source) {
+        match yuk.eval(&source, None) {
             Ok(result) => {
                 let mut t = term::stdout().unwrap();
 
@@ -47,7 +59,7 @@ fn start_repl() {
                 let mut t = term::stderr().unwrap();
 
                 t.fg(term::color::BRIGHT_RED).unwrap();
-                writeln!(t, "{}", e.debug_string()).unwrap();
+                writeln!(t, "{}", describe_error(&yuk, &e)).unwrap();
 
                 t.reset().unwrap();
             }
@@ -55,7 +67,7 @@ fn start_repl() {
     }
 }
 
-fn run_script<T: io::Read>(mut file: T) -> bool {
+fn run_script<T: io::Read>(mut file: T, filename: Option<&str>) -> bool {
     let source = {
         let mut s = String::new();
         file.read_to_string(&mut s).ok().expect("Could not read file");
@@ -63,13 +75,14 @@ fn run_script<T: io::Read>(mut file: T) -> bool {
         s
     };
 
-    let result = Yuk::create_stdlib().eval(&source);
+    let mut yuk = Yuk::create_stdlib();
+    let result = yuk.eval(&source, filename);
 
     if let &Err(ref e) = &result {
         let mut t = term::stderr().unwrap();
 
         t.fg(term::color::BRIGHT_RED).unwrap();
-        writeln!(t, "{}", e.debug_string()).unwrap();
+        writeln!(t, "{}", describe_error(&yuk, e)).unwrap();
 
         t.reset().unwrap();
     }
@@ -80,10 +93,10 @@ fn run_script<T: io::Read>(mut file: T) -> bool {
 fn main() {
     if let Some(filename) = env::args().nth(1) {
         let file = fs::File::open(&filename).ok().expect(&format!("Could not open {}", filename));
-        run_script(file);
+        run_script(file, Some(&filename));
     } else if is_interactive() {
         start_repl();
     } else {
-        run_script(io::stdin());
+        run_script(io::stdin(), None);
     }
 }
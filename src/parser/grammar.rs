@@ -1,8 +1,8 @@
 // Generated by rust-peg. Do not edit.
 #![allow(non_snake_case, unused)]
+use self::RuleResult::{Failed, Matched};
 use super::super::{ast, interpret};
 use std::f64;
-use self::RuleResult::{Matched, Failed};
 fn escape_default(s: &str) -> String {
     s.chars().flat_map(|c| c.escape_default()).collect()
 }
@@ -12,7 +12,10 @@ fn char_range_at(s: &str, pos: usize) -> (char, usize) {
     (*c, next_pos)
 }
 #[derive(Clone)]
-enum RuleResult<T> { Matched(usize, T), Failed, }
+enum RuleResult<T> {
+    Matched(usize, T),
+    Failed,
+}
 #[derive(PartialEq, Eq, Debug, Clone)]
 pub struct ParseError {
     pub line: usize,
@@ -22,42 +25,47 @@ pub struct ParseError {
 }
 pub type ParseResult<T> = Result<T, ParseError>;
 impl ::std::fmt::Display for ParseError {
-    fn fmt(&self, fmt: &mut ::std::fmt::Formatter)
-     -> ::std::result::Result<(), ::std::fmt::Error> {
-        try!(write ! (
-             fmt , "error at {}:{}: expected " , self . line , self . column
-             ));
-        if self.expected.len() == 1 {
-            try!(write ! (
-                 fmt , "`{}`" , escape_default (
-                 self . expected . iter (  ) . next (  ) . unwrap (  ) ) ));
+    fn fmt(&self, fmt: &mut ::std::fmt::Formatter) -> ::std::result::Result<(), ::std::fmt::Error> {
+        write!(fmt, "error at {}:{}: expected ", self.line, self.column)?;
+        if self.expected.len() == 0 {
+            write!(fmt, "EOF")?;
+        } else if self.expected.len() == 1 {
+            write!(
+                fmt,
+                "`{}`",
+                escape_default(self.expected.iter().next().unwrap())
+            )?;
         } else {
             let mut iter = self.expected.iter();
-            try!(write ! (
-                 fmt , "one of `{}`" , escape_default (
-                 iter . next (  ) . unwrap (  ) ) ));
+            write!(fmt, "one of `{}`", escape_default(iter.next().unwrap()))?;
             for elem in iter {
-                try!(write ! ( fmt , ", `{}`" , escape_default ( elem ) ));
+                write!(fmt, ", `{}`", escape_default(elem))?;
             }
         }
         Ok(())
     }
 }
 impl ::std::error::Error for ParseError {
-    fn description(&self) -> &str { "parse error" }
+    fn description(&self) -> &str {
+        "parse error"
+    }
 }
-fn slice_eq(input: &str, state: &mut ParseState, pos: usize, m: &'static str)
- -> RuleResult<()> {
+fn slice_eq(input: &str, state: &mut ParseState, pos: usize, m: &'static str) -> RuleResult<()> {
     #![inline]
     #![allow(dead_code)]
     let l = m.len();
-    if input.len() >= pos + l &&
-           &input.as_bytes()[pos..pos + l] == m.as_bytes() {
+    if input.len() >= pos + l && &input.as_bytes()[pos..pos + l] == m.as_bytes() {
         Matched(pos + l, ())
-    } else { state.mark_failure(pos, m) }
+    } else {
+        state.mark_failure(pos, m)
+    }
 }
-fn slice_eq_case_insensitive(input: &str, state: &mut ParseState, pos: usize,
-                             m: &'static str) -> RuleResult<()> {
+fn slice_eq_case_insensitive(
+    input: &str,
+    state: &mut ParseState,
+    pos: usize,
+    m: &'static str,
+) -> RuleResult<()> {
     #![inline]
     #![allow(dead_code)]
     let mut used = 0usize;
@@ -65,115 +73,124 @@ fn slice_eq_case_insensitive(input: &str, state: &mut ParseState, pos: usize,
     for m_char_upper in m.chars().flat_map(|x| x.to_uppercase()) {
         used += m_char_upper.len_utf8();
         let input_char_result = input_iter.next();
-        if input_char_result.is_none() ||
-               input_char_result.unwrap() != m_char_upper {
+        if input_char_result.is_none() || input_char_result.unwrap() != m_char_upper {
             return state.mark_failure(pos, m);
         }
     }
     Matched(pos + used, ())
 }
-fn any_char(input: &str, state: &mut ParseState, pos: usize)
- -> RuleResult<()> {
+fn any_char(input: &str, state: &mut ParseState, pos: usize) -> RuleResult<()> {
     #![inline]
     #![allow(dead_code)]
     if input.len() > pos {
         let (_, next) = char_range_at(input, pos);
         Matched(next, ())
-    } else { state.mark_failure(pos, "<character>") }
+    } else {
+        state.mark_failure(pos, "<character>")
+    }
 }
 fn pos_to_line(input: &str, pos: usize) -> (usize, usize) {
-    let mut remaining = pos;
-    let mut lineno: usize = 1;
-    for line in input.lines() {
-        let line_length = line.len() + 1;
-        if remaining < line_length { return (lineno, remaining + 1); }
-        remaining -= line_length;
-        lineno += 1;
+    let before = &input[..pos];
+    let line = before.as_bytes().iter().filter(|&&c| c == b'\n').count() + 1;
+    let col = before.chars().rev().take_while(|&c| c != '\n').count() + 1;
+    (line, col)
+}
+impl<'input> ParseState<'input> {
+    fn mark_failure(&mut self, pos: usize, expected: &'static str) -> RuleResult<()> {
+        if self.suppress_fail == 0 {
+            if pos > self.max_err_pos {
+                self.max_err_pos = pos;
+                self.expected.clear();
+            }
+            if pos == self.max_err_pos {
+                self.expected.insert(expected);
+            }
+        }
+        Failed
     }
-    return (lineno, remaining + 1);
 }
-struct ParseState {
+struct ParseState<'input> {
     max_err_pos: usize,
+    suppress_fail: usize,
     expected: ::std::collections::HashSet<&'static str>,
+    _phantom: ::std::marker::PhantomData<&'input ()>,
+    position_cache: ::std::collections::HashMap<usize, RuleResult<ast::Position>>,
 }
-impl ParseState {
-    fn new() -> ParseState {
-        ParseState{max_err_pos: 0,
-                   expected: ::std::collections::HashSet::new(),}
-    }
-    fn mark_failure(&mut self, pos: usize, expected: &'static str)
-     -> RuleResult<()> {
-        if pos > self.max_err_pos {
-            self.max_err_pos = pos;
-            self.expected.clear();
+impl<'input> ParseState<'input> {
+    fn new() -> ParseState<'input> {
+        ParseState {
+            max_err_pos: 0,
+            suppress_fail: 0,
+            expected: ::std::collections::HashSet::new(),
+            _phantom: ::std::marker::PhantomData,
+            position_cache: ::std::collections::HashMap::new(),
         }
-        if pos == self.max_err_pos { self.expected.insert(expected); }
-        Failed
     }
 }
-fn parse_parse<'input>(input: &'input str, state: &mut ParseState, pos: usize)
- -> RuleResult<ast::Block> {
-    parse_block(input, state, pos)
+
+fn __parse_parse<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Block> {
+    #![allow(non_snake_case, unused)]
+    __parse_block(__input, __state, __pos)
 }
-fn parse_block<'input>(input: &'input str, state: &mut ParseState, pos: usize)
- -> RuleResult<ast::Block> {
+
+fn __parse_block<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Block> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res =
-                {
-                    let mut repeat_pos = pos;
-                    let mut repeat_value = vec!();
-                    loop  {
-                        let pos = repeat_pos;
-                        let step_res = parse_statement(input, state, pos);
-                        match step_res {
-                            Matched(newpos, value) => {
-                                repeat_pos = newpos;
-                                repeat_value.push(value);
-                            }
-                            Failed => { break ; }
+            let __seq_res = {
+                let mut __repeat_pos = __pos;
+                let mut __repeat_value = vec![];
+                loop {
+                    let __pos = __repeat_pos;
+                    let __step_res = __parse_statement(__input, __state, __pos);
+                    match __step_res {
+                        Matched(__newpos, __value) => {
+                            __repeat_pos = __newpos;
+                            __repeat_value.push(__value);
+                        }
+                        Failed => {
+                            break;
                         }
                     }
-                    Matched(repeat_pos, repeat_value)
-                };
-            match seq_res {
-                Matched(pos, s) => {
-                    {
-                        let seq_res = parse___(input, state, pos);
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res =
-                                        match parse_expression(input, state,
-                                                               pos) {
-                                            Matched(newpos, value) => {
-                                                Matched(newpos, Some(value))
-                                            }
-                                            Failed => { Matched(pos, None) }
-                                        };
-                                    match seq_res {
-                                        Matched(pos, e) => {
-                                            {
-                                                let match_str =
-                                                    &input[start_pos..pos];
-                                                Matched(pos,
-                                                        {
-                                                            let mut s = s;
-                                                            if let Some(last)
-                                                                   = e {
-                                                                s.push(ast::Statement::Expression(last));
-                                                            }
-                                                            s
-                                                        })
-                                            }
+                }
+                Matched(__repeat_pos, __repeat_value)
+            };
+            match __seq_res {
+                Matched(__pos, s) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = match __parse_expression(__input, __state, __pos) {
+                                Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                Failed => Matched(__pos, None),
+                            };
+                            match __seq_res {
+                                Matched(__pos, e) => Matched(__pos, {
+                                    let match_str = &__input[__start_pos..__pos];
+                                    let start_pos = __start_pos;
+                                    let pos = __pos;
+                                    {
+                                        let mut s = s;
+                                        if let Some(last) = e {
+                                            s.push(ast::Statement::Expression(last));
                                         }
-                                        Failed => Failed,
+
+                                        s
                                     }
-                                }
+                                }),
+                                Failed => Failed,
                             }
-                            Failed => Failed,
                         }
+                        Failed => Failed,
                     }
                 }
                 Failed => Failed,
@@ -181,103 +198,250 @@ fn parse_block<'input>(input: &'input str, state: &mut ParseState, pos: usize)
         }
     }
 }
-fn parse_statement<'input>(input: &'input str, state: &mut ParseState,
-                           pos: usize) -> RuleResult<ast::Statement> {
+
+fn __parse_statement<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Statement> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
-            {
-                let start_pos = pos;
-                {
-                    let seq_res = parse___(input, state, pos);
-                    match seq_res {
-                        Matched(pos, _) => {
-                            {
-                                let seq_res = parse_if(input, state, pos);
-                                match seq_res {
-                                    Matched(pos, i) => {
-                                        {
-                                            let match_str =
-                                                &input[start_pos..pos];
-                                            Matched(pos, { i })
-                                        }
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse___(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse_position(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, p) => {
+                            let __seq_res = __parse_unlocated_statement(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, s) => Matched(__pos, {
+                                    let match_str = &__input[__start_pos..__pos];
+                                    let start_pos = __start_pos;
+                                    let pos = __pos;
+                                    {
+                                        s.located(p)
                                     }
-                                    Failed => Failed,
-                                }
+                                }),
+                                Failed => Failed,
                             }
                         }
                         Failed => Failed,
                     }
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_unlocated_statement<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Statement> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = __parse_if(__input, __state, __pos);
+                match __seq_res {
+                    Matched(__pos, i) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            i
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let choice_res =
+                let __choice_res = {
+                    let __start_pos = __pos;
                     {
-                        let start_pos = pos;
-                        {
-                            let seq_res = parse___(input, state, pos);
-                            match seq_res {
-                                Matched(pos, _) => {
+                        let __seq_res = __parse_loop(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, l) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    l
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = {
+                            let __start_pos = __pos;
+                            {
+                                let __seq_res = __parse_labelled(__input, __state, __pos);
+                                match __seq_res {
+                                    Matched(__pos, l) => Matched(__pos, {
+                                        let match_str = &__input[__start_pos..__pos];
+                                        let start_pos = __start_pos;
+                                        let pos = __pos;
+                                        {
+                                            l
+                                        }
+                                    }),
+                                    Failed => Failed,
+                                }
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __start_pos = __pos;
                                     {
-                                        let seq_res =
-                                            parse_terminated(input, state,
-                                                             pos);
-                                        match seq_res {
-                                            Matched(pos, t) => {
+                                        let __seq_res = __parse_try(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, t) => Matched(__pos, {
+                                                let match_str = &__input[__start_pos..__pos];
+                                                let start_pos = __start_pos;
+                                                let pos = __pos;
                                                 {
-                                                    let seq_res =
-                                                        parse_terminator(input,
-                                                                         state,
-                                                                         pos);
-                                                    match seq_res {
-                                                        Matched(pos, _) => {
-                                                            {
-                                                                let match_str =
-                                                                    &input[start_pos..pos];
-                                                                Matched(pos,
-                                                                        { t })
+                                                    t
+                                                }
+                                            }),
+                                            Failed => Failed,
+                                        }
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __choice_res = {
+                                            let __start_pos = __pos;
+                                            {
+                                                let __seq_res =
+                                                    slice_eq(__input, __state, __pos, "{");
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res =
+                                                            __parse___(__input, __state, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = __parse_inner_block(
+                                                                    __input, __state, __pos,
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, b) => {
+                                                                        let __seq_res = __parse___(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res =
+                                                                                    slice_eq(
+                                                                                        __input,
+                                                                                        __state,
+                                                                                        __pos, "}",
+                                                                                    );
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        _,
+                                                                                    ) => {
+                                                                                        let __seq_res = __parse___ ( __input , __state , __pos ) ;
+                                                                                        match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::Statement::Block(b)  } } ) } Failed => Failed , }
+                                                                                    }
+                                                                                    Failed => {
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
                                                             }
+                                                            Failed => Failed,
                                                         }
-                                                        Failed => Failed,
                                                     }
+                                                    Failed => Failed,
                                                 }
                                             }
-                                            Failed => Failed,
-                                        }
-                                    }
-                                }
-                                Failed => Failed,
-                            }
-                        }
-                    };
-                match choice_res {
-                    Matched(pos, value) => Matched(pos, value),
-                    Failed => {
-                        let start_pos = pos;
-                        {
-                            let seq_res = parse___(input, state, pos);
-                            match seq_res {
-                                Matched(pos, _) => {
-                                    {
-                                        let seq_res =
-                                            slice_eq(input, state, pos, ";");
-                                        match seq_res {
-                                            Matched(pos, _) => {
-                                                {
-                                                    let match_str =
-                                                        &input[start_pos..pos];
-                                                    Matched(pos,
-                                                            {
-                                                                ast::Statement::Empty
-                                                            })
+                                        };
+                                        match __choice_res {
+                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                            Failed => {
+                                                let __choice_res = {
+                                                    let __start_pos = __pos;
+                                                    {
+                                                        let __seq_res = __parse_terminated(
+                                                            __input, __state, __pos,
+                                                        );
+                                                        match __seq_res {
+                                                            Matched(__pos, t) => {
+                                                                let __seq_res = __parse_terminator(
+                                                                    __input, __state, __pos,
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        Matched(__pos, {
+                                                                            let match_str =
+                                                                                &__input[__start_pos
+                                                                                    ..__pos];
+                                                                            let start_pos =
+                                                                                __start_pos;
+                                                                            let pos = __pos;
+                                                                            {
+                                                                                t
+                                                                            }
+                                                                        })
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                };
+                                                match __choice_res {
+                                                    Matched(__pos, __value) => {
+                                                        Matched(__pos, __value)
+                                                    }
+                                                    Failed => {
+                                                        let __start_pos = __pos;
+                                                        {
+                                                            let __seq_res = slice_eq(
+                                                                __input, __state, __pos, ";",
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            ast::Statement::Empty
+                                                                        }
+                                                                    })
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                    }
                                                 }
                                             }
-                                            Failed => Failed,
                                         }
                                     }
                                 }
-                                Failed => Failed,
                             }
                         }
                     }
@@ -286,1421 +450,860 @@ fn parse_statement<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_terminator<'input>(input: &'input str, state: &mut ParseState,
-                            pos: usize) -> RuleResult<()> {
+
+fn __parse_terminator<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
-            {
-                let seq_res = parse___(input, state, pos);
-                match seq_res {
-                    Matched(pos, _) => { slice_eq(input, state, pos, ";") }
-                    Failed => Failed,
-                }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+        let __choice_res = {
+            let __seq_res = __parse___(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, _) => slice_eq(__input, __state, __pos, ";"),
+                Failed => Failed,
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let seq_res =
-                    {
-                        let mut repeat_pos = pos;
-                        loop  {
-                            let pos = repeat_pos;
-                            let step_res =
-                                parse_whitespace(input, state, pos);
-                            match step_res {
-                                Matched(newpos, value) => {
-                                    repeat_pos = newpos;
-                                }
-                                Failed => { break ; }
+                let __seq_res = {
+                    let mut __repeat_pos = __pos;
+                    loop {
+                        let __pos = __repeat_pos;
+                        let __step_res = __parse_whitespace(__input, __state, __pos);
+                        match __step_res {
+                            Matched(__newpos, __value) => {
+                                __repeat_pos = __newpos;
+                            }
+                            Failed => {
+                                break;
                             }
                         }
-                        Matched(repeat_pos, ())
-                    };
-                match seq_res {
-                    Matched(pos, _) => { parse_newline(input, state, pos) }
+                    }
+                    Matched(__repeat_pos, ())
+                };
+                match __seq_res {
+                    Matched(__pos, _) => __parse_newline(__input, __state, __pos),
                     Failed => Failed,
                 }
             }
         }
     }
 }
-fn parse_terminated<'input>(input: &'input str, state: &mut ParseState,
-                            pos: usize) -> RuleResult<ast::Statement> {
+
+fn __parse_terminated<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Statement> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
+        let __choice_res = {
+            let __start_pos = __pos;
             {
-                let start_pos = pos;
-                {
-                    let seq_res = parse_declaration(input, state, pos);
-                    match seq_res {
-                        Matched(pos, d) => {
-                            {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos,
-                                        { ast::Statement::Declaration(d) })
-                            }
+                let __seq_res = __parse_declaration(__input, __state, __pos);
+                match __seq_res {
+                    Matched(__pos, d) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::Statement::Declaration(d)
                         }
-                        Failed => Failed,
-                    }
+                    }),
+                    Failed => Failed,
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let choice_res =
+                let __choice_res = {
+                    let __start_pos = __pos;
                     {
-                        let start_pos = pos;
-                        {
-                            let seq_res =
-                                slice_eq(input, state, pos, "throw");
-                            match seq_res {
-                                Matched(pos, _) => {
-                                    {
-                                        let seq_res =
-                                            parse___(input, state, pos);
-                                        match seq_res {
-                                            Matched(pos, _) => {
-                                                {
-                                                    let seq_res =
-                                                        parse_expression(input,
-                                                                         state,
-                                                                         pos);
-                                                    match seq_res {
-                                                        Matched(pos, e) => {
-                                                            {
-                                                                let match_str =
-                                                                    &input[start_pos..pos];
-                                                                Matched(pos,
-                                                                        {
-                                                                            ast::Statement::Throw(e)
-                                                                        })
-                                                            }
+                        let __seq_res = slice_eq(__input, __state, __pos, "throw");
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = {
+                                    __state.suppress_fail += 1;
+                                    let __assert_res =
+                                        match __parse_identifier(__input, __state, __pos) {
+                                            Matched(pos, _) => Matched(pos, ()),
+                                            Failed => Failed,
+                                        };
+                                    __state.suppress_fail -= 1;
+                                    match __assert_res {
+                                        Failed => Matched(__pos, ()),
+                                        Matched(..) => Failed,
+                                    }
+                                };
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse___(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res =
+                                                    __parse_expression(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, e) => Matched(__pos, {
+                                                        let match_str =
+                                                            &__input[__start_pos..__pos];
+                                                        let start_pos = __start_pos;
+                                                        let pos = __pos;
+                                                        {
+                                                            ast::Statement::Throw(e)
                                                         }
-                                                        Failed => Failed,
-                                                    }
+                                                    }),
+                                                    Failed => Failed,
                                                 }
                                             }
                                             Failed => Failed,
                                         }
                                     }
+                                    Failed => Failed,
                                 }
-                                Failed => Failed,
                             }
+                            Failed => Failed,
                         }
-                    };
-                match choice_res {
-                    Matched(pos, value) => Matched(pos, value),
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
                     Failed => {
-                        let start_pos = pos;
-                        {
-                            let seq_res = parse_expression(input, state, pos);
-                            match seq_res {
-                                Matched(pos, e) => {
-                                    {
-                                        let match_str =
-                                            &input[start_pos..pos];
-                                        Matched(pos,
+                        let __choice_res = {
+                            let __start_pos = __pos;
+                            {
+                                let __seq_res = slice_eq(__input, __state, __pos, "break");
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = match __parse_label(__input, __state, __pos)
+                                        {
+                                            Matched(__newpos, __value) => {
+                                                Matched(__newpos, Some(__value))
+                                            }
+                                            Failed => Matched(__pos, None),
+                                        };
+                                        match __seq_res {
+                                            Matched(__pos, l) => Matched(__pos, {
+                                                let match_str = &__input[__start_pos..__pos];
+                                                let start_pos = __start_pos;
+                                                let pos = __pos;
                                                 {
-                                                    ast::Statement::Expression(e)
-                                                })
+                                                    ast::Statement::Break(l)
+                                                }
+                                            }),
+                                            Failed => Failed,
+                                        }
                                     }
+                                    Failed => Failed,
                                 }
-                                Failed => Failed,
                             }
-                        }
-                    }
-                }
-            }
-        }
-    }
-}
-fn parse_declaration<'input>(input: &'input str, state: &mut ParseState,
-                             pos: usize) -> RuleResult<ast::Declaration> {
-    {
-        let choice_res =
-            {
-                let start_pos = pos;
-                {
-                    let seq_res = slice_eq(input, state, pos, "var");
-                    match seq_res {
-                        Matched(pos, _) => {
-                            {
-                                let seq_res = parse___(input, state, pos);
-                                match seq_res {
-                                    Matched(pos, _) => {
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __start_pos = __pos;
+                                    {
+                                        let __seq_res =
+                                            slice_eq(__input, __state, __pos, "continue");
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res =
+                                                    match __parse_label(__input, __state, __pos) {
+                                                        Matched(__newpos, __value) => {
+                                                            Matched(__newpos, Some(__value))
+                                                        }
+                                                        Failed => Matched(__pos, None),
+                                                    };
+                                                match __seq_res {
+                                                    Matched(__pos, l) => Matched(__pos, {
+                                                        let match_str =
+                                                            &__input[__start_pos..__pos];
+                                                        let start_pos = __start_pos;
+                                                        let pos = __pos;
+                                                        {
+                                                            ast::Statement::Continue(l)
+                                                        }
+                                                    }),
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __start_pos = __pos;
                                         {
-                                            let seq_res =
-                                                parse_identifier(input, state,
-                                                                 pos);
-                                            match seq_res {
-                                                Matched(pos, id) => {
+                                            let __seq_res =
+                                                __parse_expression(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, e) => Matched(__pos, {
+                                                    let match_str = &__input[__start_pos..__pos];
+                                                    let start_pos = __start_pos;
+                                                    let pos = __pos;
                                                     {
-                                                        let seq_res =
-                                                            match {
-                                                                      let start_pos =
-                                                                          pos;
-                                                                      {
-                                                                          let seq_res =
-                                                                              parse___(input,
-                                                                                       state,
-                                                                                       pos);
-                                                                          match seq_res
-                                                                              {
-                                                                              Matched(pos,
-                                                                                      _)
-                                                                              =>
-                                                                              {
-                                                                                  {
-                                                                                      let seq_res =
-                                                                                          slice_eq(input,
-                                                                                                   state,
-                                                                                                   pos,
-                                                                                                   "=");
-                                                                                      match seq_res
-                                                                                          {
-                                                                                          Matched(pos,
-                                                                                                  _)
-                                                                                          =>
-                                                                                          {
-                                                                                              {
-                                                                                                  let seq_res =
-                                                                                                      parse___(input,
-                                                                                                               state,
-                                                                                                               pos);
-                                                                                                  match seq_res
-                                                                                                      {
-                                                                                                      Matched(pos,
-                                                                                                              _)
-                                                                                                      =>
-                                                                                                      {
-                                                                                                          {
-                                                                                                              let seq_res =
-                                                                                                                  parse_expression(input,
-                                                                                                                                   state,
-                                                                                                                                   pos);
-                                                                                                              match seq_res
-                                                                                                                  {
-                                                                                                                  Matched(pos,
-                                                                                                                          e)
-                                                                                                                  =>
-                                                                                                                  {
-                                                                                                                      {
-                                                                                                                          let match_str =
-                                                                                                                              &input[start_pos..pos];
-                                                                                                                          Matched(pos,
-                                                                                                                                  {
-                                                                                                                                      e
-                                                                                                                                  })
-                                                                                                                      }
-                                                                                                                  }
-                                                                                                                  Failed
-                                                                                                                  =>
-                                                                                                                  Failed,
-                                                                                                              }
-                                                                                                          }
-                                                                                                      }
-                                                                                                      Failed
-                                                                                                      =>
-                                                                                                      Failed,
-                                                                                                  }
-                                                                                              }
-                                                                                          }
-                                                                                          Failed
-                                                                                          =>
-                                                                                          Failed,
-                                                                                      }
-                                                                                  }
-                                                                              }
-                                                                              Failed
-                                                                              =>
-                                                                              Failed,
-                                                                          }
-                                                                      }
-                                                                  } {
-                                                                Matched(newpos,
-                                                                        value)
-                                                                => {
-                                                                    Matched(newpos,
-                                                                            Some(value))
-                                                                }
-                                                                Failed => {
-                                                                    Matched(pos,
-                                                                            None)
-                                                                }
-                                                            };
-                                                        match seq_res {
-                                                            Matched(pos, init)
-                                                            => {
-                                                                {
-                                                                    let match_str =
-                                                                        &input[start_pos..pos];
-                                                                    Matched(pos,
-                                                                            {
-                                                                                ast::Declaration::Variable(id,
-                                                                                                           init)
-                                                                            })
-                                                                }
-                                                            }
-                                                            Failed => Failed,
-                                                        }
+                                                        ast::Statement::Expression(e)
                                                     }
-                                                }
+                                                }),
                                                 Failed => Failed,
                                             }
                                         }
                                     }
-                                    Failed => Failed,
                                 }
                             }
                         }
-                        Failed => Failed,
                     }
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
-            Failed => parse_function_declaration(input, state, pos),
+            }
         }
     }
 }
-fn parse_if<'input>(input: &'input str, state: &mut ParseState, pos: usize)
- -> RuleResult<ast::Statement> {
+
+fn __parse_declaration<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Declaration> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
-        {
-            let seq_res = slice_eq(input, state, pos, "if");
-            match seq_res {
-                Matched(pos, _) => {
-                    {
-                        let seq_res = parse___(input, state, pos);
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res =
-                                        slice_eq(input, state, pos, "(");
-                                    match seq_res {
-                                        Matched(pos, _) => {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = slice_eq(__input, __state, __pos, "var");
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = __parse___(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = __parse_identifier(__input, __state, __pos);
+                                match __seq_res {
+                                    Matched(__pos, id) => {
+                                        let __seq_res = match {
+                                            let __start_pos = __pos;
                                             {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let seq_res =
-                                                                parse_expression(input,
-                                                                                 state,
-                                                                                 pos);
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        cond)
-                                                                => {
-                                                                    {
-                                                                        let seq_res =
-                                                                            parse___(input,
-                                                                                     state,
-                                                                                     pos);
-                                                                        match seq_res
-                                                                            {
-                                                                            Matched(pos,
-                                                                                    _)
-                                                                            =>
-                                                                            {
-                                                                                {
-                                                                                    let seq_res =
-                                                                                        slice_eq(input,
-                                                                                                 state,
-                                                                                                 pos,
-                                                                                                 ")");
-                                                                                    match seq_res
-                                                                                        {
-                                                                                        Matched(pos,
-                                                                                                _)
-                                                                                        =>
-                                                                                        {
-                                                                                            {
-                                                                                                let seq_res =
-                                                                                                    parse___(input,
-                                                                                                             state,
-                                                                                                             pos);
-                                                                                                match seq_res
-                                                                                                    {
-                                                                                                    Matched(pos,
-                                                                                                            _)
-                                                                                                    =>
-                                                                                                    {
-                                                                                                        {
-                                                                                                            let seq_res =
-                                                                                                                parse_braces(input,
-                                                                                                                             state,
-                                                                                                                             pos);
-                                                                                                            match seq_res
-                                                                                                                {
-                                                                                                                Matched(pos,
-                                                                                                                        cons)
-                                                                                                                =>
-                                                                                                                {
-                                                                                                                    {
-                                                                                                                        let seq_res =
-                                                                                                                            match {
-                                                                                                                                      let start_pos =
-                                                                                                                                          pos;
-                                                                                                                                      {
-                                                                                                                                          let seq_res =
-                                                                                                                                              parse___(input,
-                                                                                                                                                       state,
-                                                                                                                                                       pos);
-                                                                                                                                          match seq_res
-                                                                                                                                              {
-                                                                                                                                              Matched(pos,
-                                                                                                                                                      _)
-                                                                                                                                              =>
-                                                                                                                                              {
-                                                                                                                                                  {
-                                                                                                                                                      let seq_res =
-                                                                                                                                                          slice_eq(input,
-                                                                                                                                                                   state,
-                                                                                                                                                                   pos,
-                                                                                                                                                                   "else");
-                                                                                                                                                      match seq_res
-                                                                                                                                                          {
-                                                                                                                                                          Matched(pos,
-                                                                                                                                                                  _)
-                                                                                                                                                          =>
-                                                                                                                                                          {
-                                                                                                                                                              {
-                                                                                                                                                                  let seq_res =
-                                                                                                                                                                      parse___(input,
-                                                                                                                                                                               state,
-                                                                                                                                                                               pos);
-                                                                                                                                                                  match seq_res
-                                                                                                                                                                      {
-                                                                                                                                                                      Matched(pos,
-                                                                                                                                                                              _)
-                                                                                                                                                                      =>
-                                                                                                                                                                      {
-                                                                                                                                                                          {
-                                                                                                                                                                              let seq_res =
-                                                                                                                                                                                  parse_braces(input,
-                                                                                                                                                                                               state,
-                                                                                                                                                                                               pos);
-                                                                                                                                                                              match seq_res
-                                                                                                                                                                                  {
-                                                                                                                                                                                  Matched(pos,
-                                                                                                                                                                                          b)
-                                                                                                                                                                                  =>
-                                                                                                                                                                                  {
-                                                                                                                                                                                      {
-                                                                                                                                                                                          let match_str =
-                                                                                                                                                                                              &input[start_pos..pos];
-                                                                                                                                                                                          Matched(pos,
-                                                                                                                                                                                                  {
-                                                                                                                                                                                                      b
-                                                                                                                                                                                                  })
-                                                                                                                                                                                      }
-                                                                                                                                                                                  }
-                                                                                                                                                                                  Failed
-                                                                                                                                                                                  =>
-                                                                                                                                                                                  Failed,
-                                                                                                                                                                              }
-                                                                                                                                                                          }
-                                                                                                                                                                      }
-                                                                                                                                                                      Failed
-                                                                                                                                                                      =>
-                                                                                                                                                                      Failed,
-                                                                                                                                                                  }
-                                                                                                                                                              }
-                                                                                                                                                          }
-                                                                                                                                                          Failed
-                                                                                                                                                          =>
-                                                                                                                                                          Failed,
-                                                                                                                                                      }
-                                                                                                                                                  }
-                                                                                                                                              }
-                                                                                                                                              Failed
-                                                                                                                                              =>
-                                                                                                                                              Failed,
-                                                                                                                                          }
-                                                                                                                                      }
-                                                                                                                                  }
-                                                                                                                                {
-                                                                                                                                Matched(newpos,
-                                                                                                                                        value)
-                                                                                                                                =>
-                                                                                                                                {
-                                                                                                                                    Matched(newpos,
-                                                                                                                                            Some(value))
-                                                                                                                                }
-                                                                                                                                Failed
-                                                                                                                                =>
-                                                                                                                                {
-                                                                                                                                    Matched(pos,
-                                                                                                                                            None)
-                                                                                                                                }
-                                                                                                                            };
-                                                                                                                        match seq_res
-                                                                                                                            {
-                                                                                                                            Matched(pos,
-                                                                                                                                    alt)
-                                                                                                                            =>
-                                                                                                                            {
-                                                                                                                                {
-                                                                                                                                    let seq_res =
-                                                                                                                                        parse___(input,
-                                                                                                                                                 state,
-                                                                                                                                                 pos);
-                                                                                                                                    match seq_res
-                                                                                                                                        {
-                                                                                                                                        Matched(pos,
-                                                                                                                                                _)
-                                                                                                                                        =>
-                                                                                                                                        {
-                                                                                                                                            {
-                                                                                                                                                let match_str =
-                                                                                                                                                    &input[start_pos..pos];
-                                                                                                                                                Matched(pos,
-                                                                                                                                                        {
-                                                                                                                                                            ast::Statement::If(cond,
-                                                                                                                                                                               cons,
-                                                                                                                                                                               alt)
-                                                                                                                                                        })
-                                                                                                                                            }
-                                                                                                                                        }
-                                                                                                                                        Failed
-                                                                                                                                        =>
-                                                                                                                                        Failed,
-                                                                                                                                    }
-                                                                                                                                }
-                                                                                                                            }
-                                                                                                                            Failed
-                                                                                                                            =>
-                                                                                                                            Failed,
-                                                                                                                        }
-                                                                                                                    }
-                                                                                                                }
-                                                                                                                Failed
-                                                                                                                =>
-                                                                                                                Failed,
-                                                                                                            }
-                                                                                                        }
-                                                                                                    }
-                                                                                                    Failed
-                                                                                                    =>
-                                                                                                    Failed,
-                                                                                                }
-                                                                                            }
-                                                                                        }
-                                                                                        Failed
-                                                                                        =>
-                                                                                        Failed,
+                                                let __seq_res = __parse___(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res =
+                                                            slice_eq(__input, __state, __pos, "=");
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = __parse___(
+                                                                    __input, __state, __pos,
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res =
+                                                                            __parse_expression(
+                                                                                __input, __state,
+                                                                                __pos,
+                                                                            );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, e) => {
+                                                                                Matched(__pos, {
+                                                                                    let match_str = &__input[__start_pos..__pos];
+                                                                                    let start_pos =
+                                                                                        __start_pos;
+                                                                                    let pos = __pos;
+                                                                                    {
+                                                                                        e
                                                                                     }
-                                                                                }
+                                                                                })
                                                                             }
-                                                                            Failed
-                                                                            =>
-                                                                            Failed,
+                                                                            Failed => Failed,
                                                                         }
                                                                     }
+                                                                    Failed => Failed,
                                                                 }
-                                                                Failed =>
-                                                                Failed,
                                                             }
+                                                            Failed => Failed,
                                                         }
                                                     }
                                                     Failed => Failed,
                                                 }
                                             }
+                                        } {
+                                            Matched(__newpos, __value) => {
+                                                Matched(__newpos, Some(__value))
+                                            }
+                                            Failed => Matched(__pos, None),
+                                        };
+                                        match __seq_res {
+                                            Matched(__pos, init) => Matched(__pos, {
+                                                let match_str = &__input[__start_pos..__pos];
+                                                let start_pos = __start_pos;
+                                                let pos = __pos;
+                                                {
+                                                    ast::Declaration::Variable(id, init)
+                                                }
+                                            }),
+                                            Failed => Failed,
                                         }
-                                        Failed => Failed,
                                     }
+                                    Failed => Failed,
                                 }
                             }
                             Failed => Failed,
                         }
                     }
+                    Failed => Failed,
                 }
-                Failed => Failed,
             }
-        }
-    }
-}
-fn parse_braces<'input>(input: &'input str, state: &mut ParseState,
-                        pos: usize) -> RuleResult<ast::InnerBlock> {
-    {
-        let choice_res =
-            {
-                let start_pos = pos;
-                {
-                    let seq_res = parse_statement(input, state, pos);
-                    match seq_res {
-                        Matched(pos, s) => {
-                            {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos,
-                                        {
-                                            ast::InnerBlock{block: vec!(s),
-                                                            return_exp: None,}
-                                        })
-                            }
-                        }
-                        Failed => Failed,
-                    }
-                }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let choice_res =
+                let __choice_res = {
+                    let __start_pos = __pos;
                     {
-                        let start_pos = pos;
-                        {
-                            let seq_res = parse_return(input, state, pos);
-                            match seq_res {
-                                Matched(pos, r) => {
-                                    {
-                                        let match_str =
-                                            &input[start_pos..pos];
-                                        Matched(pos,
-                                                {
-                                                    ast::InnerBlock{block:
-                                                                        vec!(),
-                                                                    return_exp:
-                                                                        Some(r),}
-                                                })
+                        let __seq_res = slice_eq(__input, __state, __pos, "let");
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = {
+                                    __state.suppress_fail += 1;
+                                    let __assert_res =
+                                        match __parse_identifier(__input, __state, __pos) {
+                                            Matched(pos, _) => Matched(pos, ()),
+                                            Failed => Failed,
+                                        };
+                                    __state.suppress_fail -= 1;
+                                    match __assert_res {
+                                        Failed => Matched(__pos, ()),
+                                        Matched(..) => Failed,
                                     }
-                                }
-                                Failed => Failed,
-                            }
-                        }
-                    };
-                match choice_res {
-                    Matched(pos, value) => Matched(pos, value),
-                    Failed => {
-                        let start_pos = pos;
-                        {
-                            let seq_res = slice_eq(input, state, pos, "{");
-                            match seq_res {
-                                Matched(pos, _) => {
-                                    {
-                                        let seq_res =
-                                            parse___(input, state, pos);
-                                        match seq_res {
-                                            Matched(pos, _) => {
-                                                {
-                                                    let seq_res =
-                                                        parse_inner_block(input,
-                                                                          state,
-                                                                          pos);
-                                                    match seq_res {
-                                                        Matched(pos, b) => {
+                                };
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse___(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res =
+                                                    __parse_identifier(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, id) => {
+                                                        let __seq_res = match {
+                                                            let __start_pos = __pos;
                                                             {
-                                                                let seq_res =
-                                                                    parse___(input,
-                                                                             state,
-                                                                             pos);
-                                                                match seq_res
-                                                                    {
-                                                                    Matched(pos,
-                                                                            _)
-                                                                    => {
-                                                                        {
-                                                                            let seq_res =
-                                                                                slice_eq(input,
-                                                                                         state,
-                                                                                         pos,
-                                                                                         "}");
-                                                                            match seq_res
-                                                                                {
-                                                                                Matched(pos,
-                                                                                        _)
-                                                                                =>
-                                                                                {
-                                                                                    {
-                                                                                        let match_str =
-                                                                                            &input[start_pos..pos];
-                                                                                        Matched(pos,
-                                                                                                {
-                                                                                                    b
-                                                                                                })
+                                                                let __seq_res = __parse___(
+                                                                    __input, __state, __pos,
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = slice_eq(
+                                                                            __input, __state,
+                                                                            __pos, "=",
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res =
+                                                                                    __parse___(
+                                                                                        __input,
+                                                                                        __state,
+                                                                                        __pos,
+                                                                                    );
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        _,
+                                                                                    ) => {
+                                                                                        let __seq_res = __parse_expression ( __input , __state , __pos ) ;
+                                                                                        match __seq_res { Matched ( __pos , e ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  e  } } ) } Failed => Failed , }
+                                                                                    }
+                                                                                    Failed => {
+                                                                                        Failed
                                                                                     }
                                                                                 }
-                                                                                Failed
-                                                                                =>
-                                                                                Failed,
                                                                             }
+                                                                            Failed => Failed,
                                                                         }
                                                                     }
-                                                                    Failed =>
-                                                                    Failed,
+                                                                    Failed => Failed,
                                                                 }
                                                             }
+                                                        } {
+                                                            Matched(__newpos, __value) => {
+                                                                Matched(__newpos, Some(__value))
+                                                            }
+                                                            Failed => Matched(__pos, None),
+                                                        };
+                                                        match __seq_res {
+                                                            Matched(__pos, init) => {
+                                                                Matched(__pos, {
+                                                                    let match_str = &__input
+                                                                        [__start_pos..__pos];
+                                                                    let start_pos = __start_pos;
+                                                                    let pos = __pos;
+                                                                    {
+                                                                        ast::Declaration::Let(
+                                                                            id, init,
+                                                                        )
+                                                                    }
+                                                                })
+                                                            }
+                                                            Failed => Failed,
                                                         }
-                                                        Failed => Failed,
                                                     }
+                                                    Failed => Failed,
                                                 }
                                             }
                                             Failed => Failed,
                                         }
                                     }
+                                    Failed => Failed,
                                 }
-                                Failed => Failed,
                             }
+                            Failed => Failed,
                         }
                     }
-                }
-            }
-        }
-    }
-}
-fn parse_expression<'input>(input: &'input str, state: &mut ParseState,
-                            pos: usize) -> RuleResult<ast::Expression> {
-    {
-        let choice_res =
-            {
-                let start_pos = pos;
-                {
-                    let seq_res = parse_equality(input, state, pos);
-                    match seq_res {
-                        Matched(pos, cond) => {
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = {
+                            let __start_pos = __pos;
                             {
-                                let seq_res = parse___(input, state, pos);
-                                match seq_res {
-                                    Matched(pos, _) => {
-                                        {
-                                            let seq_res =
-                                                slice_eq(input, state, pos,
-                                                         "?");
-                                            match seq_res {
-                                                Matched(pos, _) => {
-                                                    {
-                                                        let seq_res =
-                                                            parse___(input,
-                                                                     state,
-                                                                     pos);
-                                                        match seq_res {
-                                                            Matched(pos, _) =>
-                                                            {
-                                                                {
-                                                                    let seq_res =
-                                                                        parse_expression(input,
-                                                                                         state,
-                                                                                         pos);
-                                                                    match seq_res
-                                                                        {
-                                                                        Matched(pos,
-                                                                                cons)
-                                                                        => {
-                                                                            {
-                                                                                let seq_res =
-                                                                                    parse___(input,
-                                                                                             state,
-                                                                                             pos);
-                                                                                match seq_res
-                                                                                    {
-                                                                                    Matched(pos,
-                                                                                            _)
-                                                                                    =>
-                                                                                    {
-                                                                                        {
-                                                                                            let seq_res =
-                                                                                                slice_eq(input,
-                                                                                                         state,
-                                                                                                         pos,
-                                                                                                         ":");
-                                                                                            match seq_res
-                                                                                                {
-                                                                                                Matched(pos,
-                                                                                                        _)
-                                                                                                =>
-                                                                                                {
-                                                                                                    {
-                                                                                                        let seq_res =
-                                                                                                            parse___(input,
-                                                                                                                     state,
-                                                                                                                     pos);
-                                                                                                        match seq_res
-                                                                                                            {
-                                                                                                            Matched(pos,
-                                                                                                                    _)
-                                                                                                            =>
-                                                                                                            {
-                                                                                                                {
-                                                                                                                    let seq_res =
-                                                                                                                        parse_expression(input,
-                                                                                                                                         state,
-                                                                                                                                         pos);
-                                                                                                                    match seq_res
-                                                                                                                        {
-                                                                                                                        Matched(pos,
-                                                                                                                                alt)
-                                                                                                                        =>
-                                                                                                                        {
-                                                                                                                            {
-                                                                                                                                let match_str =
-                                                                                                                                    &input[start_pos..pos];
-                                                                                                                                Matched(pos,
-                                                                                                                                        {
-                                                                                                                                            ast::Expression::Ternary(Box::new(cond),
-                                                                                                                                                                     Box::new(cons),
-                                                                                                                                                                     Box::new(alt))
-                                                                                                                                        })
-                                                                                                                            }
-                                                                                                                        }
-                                                                                                                        Failed
-                                                                                                                        =>
-                                                                                                                        Failed,
-                                                                                                                    }
-                                                                                                                }
-                                                                                                            }
-                                                                                                            Failed
-                                                                                                            =>
-                                                                                                            Failed,
-                                                                                                        }
-                                                                                                    }
-                                                                                                }
-                                                                                                Failed
-                                                                                                =>
-                                                                                                Failed,
-                                                                                            }
-                                                                                        }
+                                let __seq_res = slice_eq(__input, __state, __pos, "const");
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = {
+                                            __state.suppress_fail += 1;
+                                            let __assert_res =
+                                                match __parse_identifier(__input, __state, __pos) {
+                                                    Matched(pos, _) => Matched(pos, ()),
+                                                    Failed => Failed,
+                                                };
+                                            __state.suppress_fail -= 1;
+                                            match __assert_res {
+                                                Failed => Matched(__pos, ()),
+                                                Matched(..) => Failed,
+                                            }
+                                        };
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res = __parse___(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res = __parse_identifier(
+                                                            __input, __state, __pos,
+                                                        );
+                                                        match __seq_res {
+                                                            Matched(__pos, id) => {
+                                                                let __seq_res = __parse___(
+                                                                    __input, __state, __pos,
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = slice_eq(
+                                                                            __input, __state,
+                                                                            __pos, "=",
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res =
+                                                                                    __parse___(
+                                                                                        __input,
+                                                                                        __state,
+                                                                                        __pos,
+                                                                                    );
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        _,
+                                                                                    ) => {
+                                                                                        let __seq_res = __parse_expression ( __input , __state , __pos ) ;
+                                                                                        match __seq_res { Matched ( __pos , e ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::Declaration::Const(id, e)  } } ) } Failed => Failed , }
+                                                                                    }
+                                                                                    Failed => {
+                                                                                        Failed
                                                                                     }
-                                                                                    Failed
-                                                                                    =>
-                                                                                    Failed,
                                                                                 }
                                                                             }
+                                                                            Failed => Failed,
                                                                         }
-                                                                        Failed
-                                                                        =>
-                                                                        Failed,
                                                                     }
+                                                                    Failed => Failed,
                                                                 }
                                                             }
                                                             Failed => Failed,
                                                         }
                                                     }
+                                                    Failed => Failed,
                                                 }
-                                                Failed => Failed,
                                             }
+                                            Failed => Failed,
                                         }
                                     }
                                     Failed => Failed,
                                 }
                             }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => __parse_function_declaration(__input, __state, __pos),
                         }
-                        Failed => Failed,
                     }
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
-            Failed => parse_equality(input, state, pos),
+            }
         }
     }
 }
-fn parse_equality<'input>(input: &'input str, state: &mut ParseState,
-                          pos: usize) -> RuleResult<ast::Expression> {
+
+fn __parse_if<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Statement> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
-            {
-                let start_pos = pos;
-                {
-                    let seq_res = parse_sum(input, state, pos);
-                    match seq_res {
-                        Matched(pos, l) => {
-                            {
-                                let seq_res = parse___(input, state, pos);
-                                match seq_res {
-                                    Matched(pos, _) => {
-                                        {
-                                            let seq_res =
-                                                parse_equal_op(input, state,
-                                                               pos);
-                                            match seq_res {
-                                                Matched(pos, op) => {
-                                                    {
-                                                        let seq_res =
-                                                            parse___(input,
-                                                                     state,
-                                                                     pos);
-                                                        match seq_res {
-                                                            Matched(pos, _) =>
-                                                            {
-                                                                {
-                                                                    let seq_res =
-                                                                        parse_expression(input,
-                                                                                         state,
-                                                                                         pos);
-                                                                    match seq_res
-                                                                        {
-                                                                        Matched(pos,
-                                                                                r)
-                                                                        => {
-                                                                            {
-                                                                                let match_str =
-                                                                                    &input[start_pos..pos];
-                                                                                Matched(pos,
-                                                                                        {
-                                                                                            ast::Expression::Binary(op,
-                                                                                                                    Box::new(l),
-                                                                                                                    Box::new(r))
-                                                                                        })
+        let __start_pos = __pos;
+        {
+            let __seq_res = slice_eq(__input, __state, __pos, "if");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = slice_eq(__input, __state, __pos, "(");
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                __parse_expression(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, cond) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = slice_eq(
+                                                                __input, __state, __pos, ")",
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res = __parse___(
+                                                                        __input, __state, __pos,
+                                                                    );
+                                                                    match __seq_res {
+                                                                        Matched(__pos, _) => {
+                                                                            let __seq_res =
+                                                                                __parse_braces(
+                                                                                    __input,
+                                                                                    __state, __pos,
+                                                                                );
+                                                                            match __seq_res {
+                                                                                Matched(
+                                                                                    __pos,
+                                                                                    cons,
+                                                                                ) => {
+                                                                                    let __seq_res = match { let __start_pos = __pos ; { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = slice_eq ( __input , __state , __pos , "else" ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse_braces ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , b ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; { b } } ) } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } { Matched ( __newpos , __value ) => { Matched ( __newpos , Some ( __value ) ) } , Failed => { Matched ( __pos , None ) } , } ;
+                                                                                    match __seq_res
+                                                                                    {
+                                                                                        Matched(
+                                                                                            __pos,
+                                                                                            alt,
+                                                                                        ) => {
+                                                                                            let __seq_res = __parse___ ( __input , __state , __pos ) ;
+                                                                                            match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::Statement::If(cond, cons, alt)  } } ) } Failed => Failed , }
+                                                                                        }
+                                                                                        Failed => {
+                                                                                            Failed
+                                                                                        }
+                                                                                    }
+                                                                                }
+                                                                                Failed => Failed,
                                                                             }
                                                                         }
-                                                                        Failed
-                                                                        =>
-                                                                        Failed,
+                                                                        Failed => Failed,
                                                                     }
                                                                 }
+                                                                Failed => Failed,
                                                             }
-                                                            Failed => Failed,
                                                         }
+                                                        Failed => Failed,
                                                     }
                                                 }
                                                 Failed => Failed,
                                             }
                                         }
+                                        Failed => Failed,
                                     }
-                                    Failed => Failed,
                                 }
+                                Failed => Failed,
                             }
                         }
                         Failed => Failed,
                     }
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
-            Failed => parse_sum(input, state, pos),
-        }
-    }
-}
-fn parse_equal_op<'input>(input: &'input str, state: &mut ParseState,
-                          pos: usize) -> RuleResult<ast::BinaryOp> {
-    {
-        let start_pos = pos;
-        {
-            let seq_res = slice_eq(input, state, pos, "===");
-            match seq_res {
-                Matched(pos, _) => {
-                    {
-                        let match_str = &input[start_pos..pos];
-                        Matched(pos, { ast::BinaryOp::StrictEquals })
-                    }
-                }
                 Failed => Failed,
             }
         }
     }
 }
-fn parse_sum<'input>(input: &'input str, state: &mut ParseState, pos: usize)
- -> RuleResult<ast::Expression> {
+
+fn __parse_loop<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Statement> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
-        {
-            let seq_res = parse_product(input, state, pos);
-            match seq_res {
-                Matched(pos, l) => {
-                    {
-                        let seq_res =
-                            {
-                                let mut repeat_pos = pos;
-                                let mut repeat_value = vec!();
-                                loop  {
-                                    let pos = repeat_pos;
-                                    let step_res =
-                                        {
-                                            let start_pos = pos;
-                                            {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let seq_res =
-                                                                parse_sum_op(input,
-                                                                             state,
-                                                                             pos);
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        op) =>
-                                                                {
-                                                                    {
-                                                                        let seq_res =
-                                                                            parse___(input,
-                                                                                     state,
-                                                                                     pos);
-                                                                        match seq_res
-                                                                            {
-                                                                            Matched(pos,
-                                                                                    _)
-                                                                            =>
-                                                                            {
-                                                                                {
-                                                                                    let seq_res =
-                                                                                        parse_product(input,
-                                                                                                      state,
-                                                                                                      pos);
-                                                                                    match seq_res
-                                                                                        {
-                                                                                        Matched(pos,
-                                                                                                r)
-                                                                                        =>
-                                                                                        {
-                                                                                            {
-                                                                                                let match_str =
-                                                                                                    &input[start_pos..pos];
-                                                                                                Matched(pos,
-                                                                                                        {
-                                                                                                            (op,
-                                                                                                             r)
-                                                                                                        })
-                                                                                            }
-                                                                                        }
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = slice_eq(__input, __state, __pos, "while");
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = __parse___(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = slice_eq(__input, __state, __pos, "(");
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse___(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res =
+                                                    __parse_expression(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, cond) => {
+                                                        let __seq_res =
+                                                            __parse___(__input, __state, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = slice_eq(
+                                                                    __input, __state, __pos, ")",
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = __parse___(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res =
+                                                                                    __parse_braces(
+                                                                                        __input,
+                                                                                        __state,
+                                                                                        __pos,
+                                                                                    );
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        body,
+                                                                                    ) => {
+                                                                                        let __seq_res = __parse___ ( __input , __state , __pos ) ;
+                                                                                        match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::Statement::While(cond, body)  } } ) } Failed => Failed , }
+                                                                                    }
+                                                                                    Failed => {
                                                                                         Failed
-                                                                                        =>
-                                                                                        Failed,
                                                                                     }
                                                                                 }
                                                                             }
-                                                                            Failed
-                                                                            =>
-                                                                            Failed,
+                                                                            Failed => Failed,
                                                                         }
                                                                     }
+                                                                    Failed => Failed,
                                                                 }
-                                                                Failed =>
-                                                                Failed,
                                                             }
+                                                            Failed => Failed,
                                                         }
                                                     }
                                                     Failed => Failed,
                                                 }
                                             }
-                                        };
-                                    match step_res {
-                                        Matched(newpos, value) => {
-                                            repeat_pos = newpos;
-                                            repeat_value.push(value);
+                                            Failed => Failed,
                                         }
-                                        Failed => { break ; }
                                     }
-                                }
-                                Matched(repeat_pos, repeat_value)
-                            };
-                        match seq_res {
-                            Matched(pos, s) => {
-                                {
-                                    let match_str = &input[start_pos..pos];
-                                    Matched(pos,
-                                            {
-                                                let mut expr = l;
-                                                for (op, right) in s {
-                                                    expr =
-                                                        ast::Expression::Binary(op,
-                                                                                Box::new(expr),
-                                                                                Box::new(right))
-                                                }
-                                                expr
-                                            })
+                                    Failed => Failed,
                                 }
                             }
                             Failed => Failed,
                         }
                     }
+                    Failed => Failed,
                 }
-                Failed => Failed,
             }
-        }
-    }
-}
-fn parse_product<'input>(input: &'input str, state: &mut ParseState,
-                         pos: usize) -> RuleResult<ast::Expression> {
-    {
-        let start_pos = pos;
-        {
-            let seq_res = parse_simple_expression(input, state, pos);
-            match seq_res {
-                Matched(pos, l) => {
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
                     {
-                        let seq_res =
-                            {
-                                let mut repeat_pos = pos;
-                                let mut repeat_value = vec!();
-                                loop  {
-                                    let pos = repeat_pos;
-                                    let step_res =
-                                        {
-                                            let start_pos = pos;
-                                            {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let seq_res =
-                                                                parse_product_op(input,
-                                                                                 state,
-                                                                                 pos);
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        op) =>
-                                                                {
-                                                                    {
-                                                                        let seq_res =
-                                                                            parse___(input,
-                                                                                     state,
-                                                                                     pos);
-                                                                        match seq_res
-                                                                            {
-                                                                            Matched(pos,
-                                                                                    _)
-                                                                            =>
-                                                                            {
-                                                                                {
-                                                                                    let seq_res =
-                                                                                        parse_simple_expression(input,
-                                                                                                                state,
-                                                                                                                pos);
-                                                                                    match seq_res
-                                                                                        {
-                                                                                        Matched(pos,
-                                                                                                r)
-                                                                                        =>
-                                                                                        {
-                                                                                            {
-                                                                                                let match_str =
-                                                                                                    &input[start_pos..pos];
-                                                                                                Matched(pos,
-                                                                                                        {
-                                                                                                            (op,
-                                                                                                             r)
-                                                                                                        })
-                                                                                            }
-                                                                                        }
+                        let __seq_res = slice_eq(__input, __state, __pos, "do");
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = {
+                                    __state.suppress_fail += 1;
+                                    let __assert_res =
+                                        match __parse_identifier(__input, __state, __pos) {
+                                            Matched(pos, _) => Matched(pos, ()),
+                                            Failed => Failed,
+                                        };
+                                    __state.suppress_fail -= 1;
+                                    match __assert_res {
+                                        Failed => Matched(__pos, ()),
+                                        Matched(..) => Failed,
+                                    }
+                                };
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse___(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res =
+                                                    __parse_braces(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, body) => {
+                                                        let __seq_res =
+                                                            __parse___(__input, __state, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = slice_eq(
+                                                                    __input, __state, __pos,
+                                                                    "while",
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = __parse___(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res =
+                                                                                    slice_eq(
+                                                                                        __input,
+                                                                                        __state,
+                                                                                        __pos, "(",
+                                                                                    );
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        _,
+                                                                                    ) => {
+                                                                                        let __seq_res = __parse___ ( __input , __state , __pos ) ;
+                                                                                        match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse_expression ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , cond ) => { { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = slice_eq ( __input , __state , __pos , ")" ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = match { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { slice_eq ( __input , __state , __pos , ";" ) } Failed => Failed , } } { Matched ( __newpos , _ ) => { Matched ( __newpos , ( ) ) } , Failed => { Matched ( __pos , ( ) ) } , } ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::Statement::DoWhile(body, cond)  } } ) } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , }
+                                                                                    }
+                                                                                    Failed => {
                                                                                         Failed
-                                                                                        =>
-                                                                                        Failed,
                                                                                     }
                                                                                 }
                                                                             }
-                                                                            Failed
-                                                                            =>
-                                                                            Failed,
+                                                                            Failed => Failed,
                                                                         }
                                                                     }
+                                                                    Failed => Failed,
                                                                 }
-                                                                Failed =>
-                                                                Failed,
                                                             }
+                                                            Failed => Failed,
                                                         }
                                                     }
                                                     Failed => Failed,
                                                 }
                                             }
-                                        };
-                                    match step_res {
-                                        Matched(newpos, value) => {
-                                            repeat_pos = newpos;
-                                            repeat_value.push(value);
+                                            Failed => Failed,
                                         }
-                                        Failed => { break ; }
                                     }
-                                }
-                                Matched(repeat_pos, repeat_value)
-                            };
-                        match seq_res {
-                            Matched(pos, s) => {
-                                {
-                                    let match_str = &input[start_pos..pos];
-                                    Matched(pos,
-                                            {
-                                                let mut expr = l;
-                                                for (op, right) in s {
-                                                    expr =
-                                                        ast::Expression::Binary(op,
-                                                                                Box::new(expr),
-                                                                                Box::new(right));
-                                                }
-                                                expr
-                                            })
+                                    Failed => Failed,
                                 }
                             }
                             Failed => Failed,
                         }
                     }
-                }
-                Failed => Failed,
-            }
-        }
-    }
-}
-fn parse_simple_expression<'input>(input: &'input str, state: &mut ParseState,
-                                   pos: usize)
- -> RuleResult<ast::Expression> {
-    {
-        let choice_res = parse_new(input, state, pos);
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
-            Failed => {
-                let choice_res =
-                    {
-                        let start_pos = pos;
-                        {
-                            let seq_res = parse_literal(input, state, pos);
-                            match seq_res {
-                                Matched(pos, v) => {
-                                    {
-                                        let match_str =
-                                            &input[start_pos..pos];
-                                        Matched(pos,
-                                                {
-                                                    ast::Expression::Literal(v)
-                                                })
-                                    }
-                                }
-                                Failed => Failed,
-                            }
-                        }
-                    };
-                match choice_res {
-                    Matched(pos, value) => Matched(pos, value),
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
                     Failed => {
-                        let choice_res =
-                            {
-                                let start_pos = pos;
-                                {
-                                    let seq_res =
-                                        parse_function_literal(input, state,
-                                                               pos);
-                                    match seq_res {
-                                        Matched(pos, f) => {
-                                            {
-                                                let match_str =
-                                                    &input[start_pos..pos];
-                                                Matched(pos,
-                                                        {
-                                                            ast::Expression::Function(f)
-                                                        })
-                                            }
-                                        }
-                                        Failed => Failed,
-                                    }
-                                }
-                            };
-                        match choice_res {
-                            Matched(pos, value) => Matched(pos, value),
-                            Failed => {
-                                let choice_res =
-                                    {
-                                        let start_pos = pos;
-                                        {
-                                            let seq_res =
-                                                parse_object(input, state,
-                                                             pos);
-                                            match seq_res {
-                                                Matched(pos, o) => {
-                                                    {
-                                                        let match_str =
-                                                            &input[start_pos..pos];
-                                                        Matched(pos,
-                                                                {
-                                                                    ast::Expression::Object(o)
-                                                                })
-                                                    }
-                                                }
-                                                Failed => Failed,
-                                            }
-                                        }
-                                    };
-                                match choice_res {
-                                    Matched(pos, value) =>
-                                    Matched(pos, value),
-                                    Failed => {
-                                        let choice_res =
-                                            {
-                                                let start_pos = pos;
-                                                {
-                                                    let seq_res =
-                                                        parse_access(input,
-                                                                     state,
-                                                                     pos);
-                                                    match seq_res {
-                                                        Matched(pos, lhs) => {
-                                                            {
-                                                                let seq_res =
-                                                                    parse___(input,
-                                                                             state,
-                                                                             pos);
-                                                                match seq_res
-                                                                    {
-                                                                    Matched(pos,
-                                                                            _)
-                                                                    => {
-                                                                        {
-                                                                            let seq_res =
-                                                                                slice_eq(input,
-                                                                                         state,
-                                                                                         pos,
-                                                                                         "=");
-                                                                            match seq_res
-                                                                                {
-                                                                                Matched(pos,
-                                                                                        _)
-                                                                                =>
-                                                                                {
+                        let __start_pos = __pos;
+                        {
+                            let __seq_res = slice_eq(__input, __state, __pos, "for");
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "(");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = match __parse_for_init(
+                                                                __input, __state, __pos,
+                                                            ) {
+                                                                Matched(__newpos, __value) => {
+                                                                    Matched(__newpos, Some(__value))
+                                                                }
+                                                                Failed => Matched(__pos, None),
+                                                            };
+                                                            match __seq_res {
+                                                                Matched(__pos, init) => {
+                                                                    let __seq_res = __parse___(
+                                                                        __input, __state, __pos,
+                                                                    );
+                                                                    match __seq_res {
+                                                                        Matched(__pos, _) => {
+                                                                            let __seq_res =
+                                                                                slice_eq(
+                                                                                    __input,
+                                                                                    __state, __pos,
+                                                                                    ";",
+                                                                                );
+                                                                            match __seq_res {
+                                                                                Matched(
+                                                                                    __pos,
+                                                                                    _,
+                                                                                ) => {
+                                                                                    let __seq_res =
+                                                                                        __parse___(
+                                                                                            __input,
+                                                                                            __state,
+                                                                                            __pos,
+                                                                                        );
+                                                                                    match __seq_res
                                                                                     {
-                                                                                        let seq_res =
-                                                                                            parse___(input,
-                                                                                                     state,
-                                                                                                     pos);
-                                                                                        match seq_res
-                                                                                            {
-                                                                                            Matched(pos,
-                                                                                                    _)
-                                                                                            =>
-                                                                                            {
-                                                                                                {
-                                                                                                    let seq_res =
-                                                                                                        parse_expression(input,
-                                                                                                                         state,
-                                                                                                                         pos);
-                                                                                                    match seq_res
-                                                                                                        {
-                                                                                                        Matched(pos,
-                                                                                                                rhs)
-                                                                                                        =>
-                                                                                                        {
-                                                                                                            {
-                                                                                                                let match_str =
-                                                                                                                    &input[start_pos..pos];
-                                                                                                                Matched(pos,
-                                                                                                                        {
-                                                                                                                            ast::Expression::Assignment(lhs,
-                                                                                                                                                        Box::new(rhs))
-                                                                                                                        })
-                                                                                                            }
-                                                                                                        }
-                                                                                                        Failed
-                                                                                                        =>
-                                                                                                        Failed,
-                                                                                                    }
-                                                                                                }
-                                                                                            }
+                                                                                        Matched(
+                                                                                            __pos,
+                                                                                            _,
+                                                                                        ) => {
+                                                                                            let __seq_res = match __parse_expression ( __input , __state , __pos ) { Matched ( __newpos , __value ) => { Matched ( __newpos , Some ( __value ) ) } , Failed => { Matched ( __pos , None ) } , } ;
+                                                                                            match __seq_res { Matched ( __pos , test ) => { { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = slice_eq ( __input , __state , __pos , ";" ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = match __parse_expression ( __input , __state , __pos ) { Matched ( __newpos , __value ) => { Matched ( __newpos , Some ( __value ) ) } , Failed => { Matched ( __pos , None ) } , } ; match __seq_res { Matched ( __pos , update ) => { { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = slice_eq ( __input , __state , __pos , ")" ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse_braces ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , body ) => { { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {
+        ast::Statement::For(init.map(Box::new), test, update, body)
+     } } ) } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , }
+                                                                                        }
+                                                                                        Failed => {
                                                                                             Failed
-                                                                                            =>
-                                                                                            Failed,
                                                                                         }
                                                                                     }
                                                                                 }
-                                                                                Failed
-                                                                                =>
-                                                                                Failed,
+                                                                                Failed => Failed,
                                                                             }
                                                                         }
+                                                                        Failed => Failed,
                                                                     }
-                                                                    Failed =>
-                                                                    Failed,
                                                                 }
+                                                                Failed => Failed,
                                                             }
                                                         }
                                                         Failed => Failed,
                                                     }
                                                 }
-                                            };
-                                        match choice_res {
-                                            Matched(pos, value) =>
-                                            Matched(pos, value),
-                                            Failed => {
-                                                let choice_res =
-                                                    parse_method_call(input,
-                                                                      state,
-                                                                      pos);
-                                                match choice_res {
-                                                    Matched(pos, value) =>
-                                                    Matched(pos, value),
-                                                    Failed => {
-                                                        let choice_res =
-                                                            parse_atomic_expression(input,
-                                                                                    state,
-                                                                                    pos);
-                                                        match choice_res {
-                                                            Matched(pos,
-                                                                    value) =>
-                                                            Matched(pos,
-                                                                    value),
-                                                            Failed => {
-                                                                let start_pos =
-                                                                    pos;
-                                                                {
-                                                                    let seq_res =
-                                                                        parse_unary_op(input,
-                                                                                       state,
-                                                                                       pos);
-                                                                    match seq_res
-                                                                        {
-                                                                        Matched(pos,
-                                                                                op)
-                                                                        => {
-                                                                            {
-                                                                                let seq_res =
-                                                                                    parse___(input,
-                                                                                             state,
-                                                                                             pos);
-                                                                                match seq_res
-                                                                                    {
-                                                                                    Matched(pos,
-                                                                                            _)
-                                                                                    =>
-                                                                                    {
-                                                                                        {
-                                                                                            let seq_res =
-                                                                                                parse_simple_expression(input,
-                                                                                                                        state,
-                                                                                                                        pos);
-                                                                                            match seq_res
-                                                                                                {
-                                                                                                Matched(pos,
-                                                                                                        exp)
-                                                                                                =>
-                                                                                                {
-                                                                                                    {
-                                                                                                        let match_str =
-                                                                                                            &input[start_pos..pos];
-                                                                                                        Matched(pos,
-                                                                                                                {
-                                                                                                                    ast::Expression::Unary(op,
-                                                                                                                                           Box::new(exp))
-                                                                                                                })
-                                                                                                    }
-                                                                                                }
-                                                                                                Failed
-                                                                                                =>
-                                                                                                Failed,
-                                                                                            }
-                                                                                        }
-                                                                                    }
-                                                                                    Failed
-                                                                                    =>
-                                                                                    Failed,
-                                                                                }
-                                                                            }
-                                                                        }
-                                                                        Failed
-                                                                        =>
-                                                                        Failed,
-                                                                    }
-                                                                }
-                                                            }
-                                                        }
-                                                    }
-                                                }
+                                                Failed => Failed,
                                             }
                                         }
+                                        Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
                         }
                     }
@@ -1709,62 +1312,2972 @@ fn parse_simple_expression<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_unary_op<'input>(input: &'input str, state: &mut ParseState,
-                          pos: usize) -> RuleResult<ast::UnaryOp> {
+
+fn __parse_try<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Statement> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
+        let __choice_res = {
+            let __start_pos = __pos;
             {
-                let start_pos = pos;
-                {
-                    let seq_res = slice_eq(input, state, pos, "+");
-                    match seq_res {
-                        Matched(pos, _) => {
-                            {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { ast::UnaryOp::Positive })
-                            }
-                        }
-                        Failed => Failed,
+                let __seq_res = slice_eq(__input, __state, __pos, "try");
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = __parse___(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = slice_eq(__input, __state, __pos, "{");
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res =
+                                            __parse_inner_block(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, b) => {
+                                                let __seq_res =
+                                                    slice_eq(__input, __state, __pos, "}");
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res =
+                                                            __parse___(__input, __state, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = __parse_catch(
+                                                                    __input, __state, __pos,
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, c) => {
+                                                                        let __seq_res = __parse___(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res = match __parse_finally ( __input , __state , __pos ) { Matched ( __newpos , __value ) => { Matched ( __newpos , Some ( __value ) ) } , Failed => { Matched ( __pos , None ) } , } ;
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        f,
+                                                                                    ) => {
+                                                                                        let __seq_res = __parse___ ( __input , __state , __pos ) ;
+                                                                                        match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::Statement::Try(b, Some(c), f)  } } ) } Failed => Failed , }
+                                                                                    }
+                                                                                    Failed => {
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __start_pos = __pos;
+                {
+                    let __seq_res = slice_eq(__input, __state, __pos, "try");
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse___(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = slice_eq(__input, __state, __pos, "{");
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                __parse_inner_block(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, b) => {
+                                                    let __seq_res =
+                                                        slice_eq(__input, __state, __pos, "}");
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res =
+                                                                __parse___(__input, __state, __pos);
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res = __parse_finally(
+                                                                        __input, __state, __pos,
+                                                                    );
+                                                                    match __seq_res {
+                                                                        Matched(__pos, f) => {
+                                                                            let __seq_res =
+                                                                                __parse___(
+                                                                                    __input,
+                                                                                    __state, __pos,
+                                                                                );
+                                                                            match __seq_res {
+                                                                                Matched(
+                                                                                    __pos,
+                                                                                    _,
+                                                                                ) => Matched(
+                                                                                    __pos,
+                                                                                    {
+                                                                                        let match_str = &__input[__start_pos..__pos];
+                                                                                        let start_pos = __start_pos;
+                                                                                        let pos =
+                                                                                            __pos;
+                                                                                        {
+                                                                                            ast::Statement::Try(b, None, Some(f))
+                                                                                        }
+                                                                                    },
+                                                                                ),
+                                                                                Failed => Failed,
+                                                                            }
+                                                                        }
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_catch<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<(ast::Identifier, ast::InnerBlock)> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = slice_eq(__input, __state, __pos, "catch");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = slice_eq(__input, __state, __pos, "(");
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                __parse_identifier(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, id) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = slice_eq(
+                                                                __input, __state, __pos, ")",
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res = __parse___(
+                                                                        __input, __state, __pos,
+                                                                    );
+                                                                    match __seq_res {
+                                                                        Matched(__pos, _) => {
+                                                                            let __seq_res =
+                                                                                slice_eq(
+                                                                                    __input,
+                                                                                    __state, __pos,
+                                                                                    "{",
+                                                                                );
+                                                                            match __seq_res {
+                                                                                Matched(
+                                                                                    __pos,
+                                                                                    _,
+                                                                                ) => {
+                                                                                    let __seq_res = __parse_inner_block ( __input , __state , __pos ) ;
+                                                                                    match __seq_res
+                                                                                    {
+                                                                                        Matched(
+                                                                                            __pos,
+                                                                                            b,
+                                                                                        ) => {
+                                                                                            let __seq_res = slice_eq ( __input , __state , __pos , "}" ) ;
+                                                                                            match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  (id, b)  } } ) } Failed => Failed , }
+                                                                                        }
+                                                                                        Failed => {
+                                                                                            Failed
+                                                                                        }
+                                                                                    }
+                                                                                }
+                                                                                Failed => Failed,
+                                                                            }
+                                                                        }
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_finally<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::InnerBlock> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = slice_eq(__input, __state, __pos, "finally");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = slice_eq(__input, __state, __pos, "{");
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = __parse_inner_block(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, b) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "}");
+                                            match __seq_res {
+                                                Matched(__pos, _) => Matched(__pos, {
+                                                    let match_str = &__input[__start_pos..__pos];
+                                                    let start_pos = __start_pos;
+                                                    let pos = __pos;
+                                                    {
+                                                        b
+                                                    }
+                                                }),
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_labelled<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Statement> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_identifier(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, label) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = slice_eq(__input, __state, __pos, ":");
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                __parse_labelled_body(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, body) => Matched(__pos, {
+                                                    let match_str = &__input[__start_pos..__pos];
+                                                    let start_pos = __start_pos;
+                                                    let pos = __pos;
+                                                    {
+                                                        ast::Statement::Labelled(
+                                                            label,
+                                                            Box::new(body),
+                                                        )
+                                                    }
+                                                }),
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_labelled_body<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Statement> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = __parse_loop(__input, __state, __pos);
+                match __seq_res {
+                    Matched(__pos, l) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            l
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
+                    {
+                        let __seq_res = __parse_labelled(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, l) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    l
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = {
+                            let __start_pos = __pos;
+                            {
+                                let __seq_res = slice_eq(__input, __state, __pos, "{");
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse___(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res =
+                                                    __parse_inner_block(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, b) => {
+                                                        let __seq_res =
+                                                            __parse___(__input, __state, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = slice_eq(
+                                                                    __input, __state, __pos, "}",
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = __parse___(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                Matched(__pos, {
+                                                                                    let match_str = &__input[__start_pos..__pos];
+                                                                                    let start_pos =
+                                                                                        __start_pos;
+                                                                                    let pos = __pos;
+                                                                                    {
+                                                                                        ast::Statement::Block(b)
+                                                                                    }
+                                                                                })
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => __parse_statement(__input, __state, __pos),
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_label<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Identifier> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = {
+                let mut __repeat_pos = __pos;
+                let mut __repeat_value = vec![];
+                loop {
+                    let __pos = __repeat_pos;
+                    let __step_res = __parse_whitespace(__input, __state, __pos);
+                    match __step_res {
+                        Matched(__newpos, __value) => {
+                            __repeat_pos = __newpos;
+                            __repeat_value.push(__value);
+                        }
+                        Failed => {
+                            break;
+                        }
+                    }
+                }
+                if __repeat_value.len() >= 1 {
+                    Matched(__repeat_pos, ())
+                } else {
+                    Failed
+                }
+            };
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse_identifier(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, i) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                i
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_for_init<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Statement> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = __parse_declaration(__input, __state, __pos);
+                match __seq_res {
+                    Matched(__pos, d) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::Statement::Declaration(d)
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __start_pos = __pos;
+                {
+                    let __seq_res = __parse_expression(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, e) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                ast::Statement::Expression(e)
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_braces<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::InnerBlock> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = __parse_statement(__input, __state, __pos);
+                match __seq_res {
+                    Matched(__pos, s) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::InnerBlock {
+                                block: vec![s],
+                                return_exp: None,
+                            }
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
+                    {
+                        let __seq_res = __parse_return(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, r) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    ast::InnerBlock {
+                                        block: vec![],
+                                        return_exp: Some(r),
+                                    }
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __start_pos = __pos;
+                        {
+                            let __seq_res = slice_eq(__input, __state, __pos, "{");
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                __parse_inner_block(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, b) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = slice_eq(
+                                                                __input, __state, __pos, "}",
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            b
+                                                                        }
+                                                                    })
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_expression<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_position(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, p) => {
+                    let __seq_res = __parse_conditional(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, e) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                e.located(p)
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_conditional<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = __parse_logical_or(__input, __state, __pos);
+                match __seq_res {
+                    Matched(__pos, cond) => {
+                        let __seq_res = __parse___(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = slice_eq(__input, __state, __pos, "?");
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse___(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res =
+                                                    __parse_expression(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, cons) => {
+                                                        let __seq_res =
+                                                            __parse___(__input, __state, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = slice_eq(
+                                                                    __input, __state, __pos, ":",
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = __parse___(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res = __parse_expression ( __input , __state , __pos ) ;
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        alt,
+                                                                                    ) => Matched(
+                                                                                        __pos,
+                                                                                        {
+                                                                                            let match_str = &__input[__start_pos..__pos];
+                                                                                            let start_pos = __start_pos;
+                                                                                            let pos = __pos;
+                                                                                            {
+                                                                                                ast::Expression::Ternary(Box::new(cond), Box::new(cons), Box::new(alt))
+                                                                                            }
+                                                                                        },
+                                                                                    ),
+                                                                                    Failed => {
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                            Failed => Failed,
+                                        }
+                                    }
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => __parse_logical_or(__input, __state, __pos),
+        }
+    }
+}
+
+fn __parse_logical_or<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_logical_and(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "||");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = __parse_logical_and(
+                                                                __input, __state, __pos,
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, r) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            r
+                                                                        }
+                                                                    })
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                let mut expr = l;
+
+                                for right in s {
+                                    expr = ast::Expression::Binary(
+                                        ast::BinaryOp::LogicalOr,
+                                        Box::new(expr),
+                                        Box::new(right),
+                                    );
+                                }
+
+                                expr
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_logical_and<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_bitwise_or(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "&&");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = __parse_bitwise_or(
+                                                                __input, __state, __pos,
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, r) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            r
+                                                                        }
+                                                                    })
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                let mut expr = l;
+
+                                for right in s {
+                                    expr = ast::Expression::Binary(
+                                        ast::BinaryOp::LogicalAnd,
+                                        Box::new(expr),
+                                        Box::new(right),
+                                    );
+                                }
+
+                                expr
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_bitwise_or<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_bitwise_xor(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "|");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = {
+                                                        __state.suppress_fail += 1;
+                                                        let __assert_res = {
+                                                            let __choice_res = slice_eq(
+                                                                __input, __state, __pos, "|",
+                                                            );
+                                                            match __choice_res {
+                                                                Matched(__pos, __value) => {
+                                                                    Matched(__pos, __value)
+                                                                }
+                                                                Failed => slice_eq(
+                                                                    __input, __state, __pos, "=",
+                                                                ),
+                                                            }
+                                                        };
+                                                        __state.suppress_fail -= 1;
+                                                        match __assert_res {
+                                                            Failed => Matched(__pos, ()),
+                                                            Matched(..) => Failed,
+                                                        }
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res =
+                                                                __parse___(__input, __state, __pos);
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res =
+                                                                        __parse_bitwise_xor(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                    match __seq_res {
+                                                                        Matched(__pos, r) => {
+                                                                            Matched(__pos, {
+                                                                                let match_str = &__input[__start_pos..__pos];
+                                                                                let start_pos =
+                                                                                    __start_pos;
+                                                                                let pos = __pos;
+                                                                                {
+                                                                                    r
+                                                                                }
+                                                                            })
+                                                                        }
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                let mut expr = l;
+
+                                for right in s {
+                                    expr = ast::Expression::Binary(
+                                        ast::BinaryOp::BitwiseOr,
+                                        Box::new(expr),
+                                        Box::new(right),
+                                    );
+                                }
+
+                                expr
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_bitwise_xor<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_bitwise_and(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "^");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = {
+                                                        __state.suppress_fail += 1;
+                                                        let __assert_res =
+                                                            slice_eq(__input, __state, __pos, "=");
+                                                        __state.suppress_fail -= 1;
+                                                        match __assert_res {
+                                                            Failed => Matched(__pos, ()),
+                                                            Matched(..) => Failed,
+                                                        }
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res =
+                                                                __parse___(__input, __state, __pos);
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res =
+                                                                        __parse_bitwise_and(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                    match __seq_res {
+                                                                        Matched(__pos, r) => {
+                                                                            Matched(__pos, {
+                                                                                let match_str = &__input[__start_pos..__pos];
+                                                                                let start_pos =
+                                                                                    __start_pos;
+                                                                                let pos = __pos;
+                                                                                {
+                                                                                    r
+                                                                                }
+                                                                            })
+                                                                        }
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                let mut expr = l;
+
+                                for right in s {
+                                    expr = ast::Expression::Binary(
+                                        ast::BinaryOp::BitwiseXor,
+                                        Box::new(expr),
+                                        Box::new(right),
+                                    );
+                                }
+
+                                expr
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_bitwise_and<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_equality(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "&");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = {
+                                                        __state.suppress_fail += 1;
+                                                        let __assert_res = {
+                                                            let __choice_res = slice_eq(
+                                                                __input, __state, __pos, "&",
+                                                            );
+                                                            match __choice_res {
+                                                                Matched(__pos, __value) => {
+                                                                    Matched(__pos, __value)
+                                                                }
+                                                                Failed => slice_eq(
+                                                                    __input, __state, __pos, "=",
+                                                                ),
+                                                            }
+                                                        };
+                                                        __state.suppress_fail -= 1;
+                                                        match __assert_res {
+                                                            Failed => Matched(__pos, ()),
+                                                            Matched(..) => Failed,
+                                                        }
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res =
+                                                                __parse___(__input, __state, __pos);
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res =
+                                                                        __parse_equality(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                    match __seq_res {
+                                                                        Matched(__pos, r) => {
+                                                                            Matched(__pos, {
+                                                                                let match_str = &__input[__start_pos..__pos];
+                                                                                let start_pos =
+                                                                                    __start_pos;
+                                                                                let pos = __pos;
+                                                                                {
+                                                                                    r
+                                                                                }
+                                                                            })
+                                                                        }
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                let mut expr = l;
+
+                                for right in s {
+                                    expr = ast::Expression::Binary(
+                                        ast::BinaryOp::BitwiseAnd,
+                                        Box::new(expr),
+                                        Box::new(right),
+                                    );
+                                }
+
+                                expr
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_equality<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_relational(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                __parse_equal_op(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, op) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = __parse_relational(
+                                                                __input, __state, __pos,
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, r) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            (op, r)
+                                                                        }
+                                                                    })
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                let mut expr = l;
+
+                                for (op, right) in s {
+                                    expr = ast::Expression::Binary(
+                                        op,
+                                        Box::new(expr),
+                                        Box::new(right),
+                                    );
+                                }
+
+                                expr
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_equal_op<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::BinaryOp> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = slice_eq(__input, __state, __pos, "===");
+                match __seq_res {
+                    Matched(__pos, _) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::BinaryOp::StrictEquals
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
+                    {
+                        let __seq_res = slice_eq(__input, __state, __pos, "!==");
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    ast::BinaryOp::StrictNotEquals
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = {
+                            let __start_pos = __pos;
+                            {
+                                let __seq_res = slice_eq(__input, __state, __pos, "==");
+                                match __seq_res {
+                                    Matched(__pos, _) => Matched(__pos, {
+                                        let match_str = &__input[__start_pos..__pos];
+                                        let start_pos = __start_pos;
+                                        let pos = __pos;
+                                        {
+                                            ast::BinaryOp::Equals
+                                        }
+                                    }),
+                                    Failed => Failed,
+                                }
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = slice_eq(__input, __state, __pos, "!=");
+                                    match __seq_res {
+                                        Matched(__pos, _) => Matched(__pos, {
+                                            let match_str = &__input[__start_pos..__pos];
+                                            let start_pos = __start_pos;
+                                            let pos = __pos;
+                                            {
+                                                ast::BinaryOp::NotEquals
+                                            }
+                                        }),
+                                        Failed => Failed,
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_relational<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_shift(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                __parse_relational_op(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, op) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = __parse_shift(
+                                                                __input, __state, __pos,
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, r) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            (op, r)
+                                                                        }
+                                                                    })
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                let mut expr = l;
+
+                                for (op, right) in s {
+                                    expr = ast::Expression::Binary(
+                                        op,
+                                        Box::new(expr),
+                                        Box::new(right),
+                                    );
+                                }
+
+                                expr
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_relational_op<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::BinaryOp> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = slice_eq(__input, __state, __pos, "<=");
+                match __seq_res {
+                    Matched(__pos, _) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::BinaryOp::LessThanOrEqual
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
+                    {
+                        let __seq_res = slice_eq(__input, __state, __pos, ">=");
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    ast::BinaryOp::GreaterThanOrEqual
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = {
+                            let __start_pos = __pos;
+                            {
+                                let __seq_res = slice_eq(__input, __state, __pos, "<");
+                                match __seq_res {
+                                    Matched(__pos, _) => Matched(__pos, {
+                                        let match_str = &__input[__start_pos..__pos];
+                                        let start_pos = __start_pos;
+                                        let pos = __pos;
+                                        {
+                                            ast::BinaryOp::LessThan
+                                        }
+                                    }),
+                                    Failed => Failed,
+                                }
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __start_pos = __pos;
+                                    {
+                                        let __seq_res = slice_eq(__input, __state, __pos, ">");
+                                        match __seq_res {
+                                            Matched(__pos, _) => Matched(__pos, {
+                                                let match_str = &__input[__start_pos..__pos];
+                                                let start_pos = __start_pos;
+                                                let pos = __pos;
+                                                {
+                                                    ast::BinaryOp::GreaterThan
+                                                }
+                                            }),
+                                            Failed => Failed,
+                                        }
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __start_pos = __pos;
+                                        {
+                                            let __seq_res =
+                                                slice_eq(__input, __state, __pos, "instanceof");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = {
+                                                        __state.suppress_fail += 1;
+                                                        let __assert_res = if __input.len() > __pos
+                                                        {
+                                                            let (__ch, __next) =
+                                                                char_range_at(__input, __pos);
+                                                            match __ch {
+                                                                'a'...'z'
+                                                                | 'A'...'Z'
+                                                                | '_'
+                                                                | '0'...'9' => Matched(__next, ()),
+                                                                _ => __state.mark_failure(
+                                                                    __pos,
+                                                                    "[a-zA-Z_0-9]",
+                                                                ),
+                                                            }
+                                                        } else {
+                                                            __state
+                                                                .mark_failure(__pos, "[a-zA-Z_0-9]")
+                                                        };
+                                                        __state.suppress_fail -= 1;
+                                                        match __assert_res {
+                                                            Failed => Matched(__pos, ()),
+                                                            Matched(..) => Failed,
+                                                        }
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => Matched(__pos, {
+                                                            let match_str =
+                                                                &__input[__start_pos..__pos];
+                                                            let start_pos = __start_pos;
+                                                            let pos = __pos;
+                                                            {
+                                                                ast::BinaryOp::InstanceOf
+                                                            }
+                                                        }),
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_shift<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_sum(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                __parse_shift_op(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, op) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = __parse_sum(
+                                                                __input, __state, __pos,
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, r) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            (op, r)
+                                                                        }
+                                                                    })
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                let mut expr = l;
+
+                                for (op, right) in s {
+                                    expr = ast::Expression::Binary(
+                                        op,
+                                        Box::new(expr),
+                                        Box::new(right),
+                                    );
+                                }
+
+                                expr
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_shift_op<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::BinaryOp> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = slice_eq(__input, __state, __pos, "<<");
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = {
+                            __state.suppress_fail += 1;
+                            let __assert_res = slice_eq(__input, __state, __pos, "=");
+                            __state.suppress_fail -= 1;
+                            match __assert_res {
+                                Failed => Matched(__pos, ()),
+                                Matched(..) => Failed,
+                            }
+                        };
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    ast::BinaryOp::LeftShift
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
+                    {
+                        let __seq_res = slice_eq(__input, __state, __pos, ">>>");
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = {
+                                    __state.suppress_fail += 1;
+                                    let __assert_res = slice_eq(__input, __state, __pos, "=");
+                                    __state.suppress_fail -= 1;
+                                    match __assert_res {
+                                        Failed => Matched(__pos, ()),
+                                        Matched(..) => Failed,
+                                    }
+                                };
+                                match __seq_res {
+                                    Matched(__pos, _) => Matched(__pos, {
+                                        let match_str = &__input[__start_pos..__pos];
+                                        let start_pos = __start_pos;
+                                        let pos = __pos;
+                                        {
+                                            ast::BinaryOp::UnsignedRightShift
+                                        }
+                                    }),
+                                    Failed => Failed,
+                                }
+                            }
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __start_pos = __pos;
+                        {
+                            let __seq_res = slice_eq(__input, __state, __pos, ">>");
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = {
+                                        __state.suppress_fail += 1;
+                                        let __assert_res = slice_eq(__input, __state, __pos, "=");
+                                        __state.suppress_fail -= 1;
+                                        match __assert_res {
+                                            Failed => Matched(__pos, ()),
+                                            Matched(..) => Failed,
+                                        }
+                                    };
+                                    match __seq_res {
+                                        Matched(__pos, _) => Matched(__pos, {
+                                            let match_str = &__input[__start_pos..__pos];
+                                            let start_pos = __start_pos;
+                                            let pos = __pos;
+                                            {
+                                                ast::BinaryOp::SignedRightShift
+                                            }
+                                        }),
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_sum<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_product(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = __parse_sum_op(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, op) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = __parse_product(
+                                                                __input, __state, __pos,
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, r) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            (op, r)
+                                                                        }
+                                                                    })
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                let mut expr = l;
+
+                                for (op, right) in s {
+                                    expr =
+                                        ast::Expression::Binary(op, Box::new(expr), Box::new(right))
+                                }
+
+                                expr
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_product<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_simple_expression(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, l) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                __parse_product_op(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, op) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res =
+                                                                __parse_simple_expression(
+                                                                    __input, __state, __pos,
+                                                                );
+                                                            match __seq_res {
+                                                                Matched(__pos, r) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            (op, r)
+                                                                        }
+                                                                    })
+                                                                }
+                                                                Failed => Failed,
+                                                            }
+                                                        }
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                let mut expr = l;
+
+                                for (op, right) in s {
+                                    expr = ast::Expression::Binary(
+                                        op,
+                                        Box::new(expr),
+                                        Box::new(right),
+                                    );
+                                }
+
+                                expr
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_simple_expression<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_position(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, p) => {
+                    let __seq_res = __parse_unlocated_simple_expression(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, e) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                e.located(p)
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_unlocated_simple_expression<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = __parse_new(__input, __state, __pos);
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
+                    {
+                        let __seq_res = __parse_literal(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, v) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    ast::Expression::Literal(v)
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = {
+                            let __start_pos = __pos;
+                            {
+                                let __seq_res = __parse_function_literal(__input, __state, __pos);
+                                match __seq_res {
+                                    Matched(__pos, f) => Matched(__pos, {
+                                        let match_str = &__input[__start_pos..__pos];
+                                        let start_pos = __start_pos;
+                                        let pos = __pos;
+                                        {
+                                            ast::Expression::Function(f)
+                                        }
+                                    }),
+                                    Failed => Failed,
+                                }
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __start_pos = __pos;
+                                    {
+                                        let __seq_res = __parse_object(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, o) => Matched(__pos, {
+                                                let match_str = &__input[__start_pos..__pos];
+                                                let start_pos = __start_pos;
+                                                let pos = __pos;
+                                                {
+                                                    ast::Expression::Object(o)
+                                                }
+                                            }),
+                                            Failed => Failed,
+                                        }
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __choice_res = {
+                                            let __start_pos = __pos;
+                                            {
+                                                let __seq_res =
+                                                    __parse_access(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, lhs) => {
+                                                        let __seq_res =
+                                                            __parse___(__input, __state, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res =
+                                                                    __parse_assignment_op(
+                                                                        __input, __state, __pos,
+                                                                    );
+                                                                match __seq_res {
+                                                                    Matched(__pos, op) => {
+                                                                        let __seq_res = __parse___(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res = __parse_expression ( __input , __state , __pos ) ;
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        rhs,
+                                                                                    ) => Matched(
+                                                                                        __pos,
+                                                                                        {
+                                                                                            let match_str = &__input[__start_pos..__pos];
+                                                                                            let start_pos = __start_pos;
+                                                                                            let pos = __pos;
+                                                                                            {
+                                                                                                ast::Expression::CompoundAssignment(op, lhs, Box::new(rhs))
+                                                                                            }
+                                                                                        },
+                                                                                    ),
+                                                                                    Failed => {
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                        };
+                                        match __choice_res {
+                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                            Failed => {
+                                                let __choice_res = {
+                                                    let __start_pos = __pos;
+                                                    {
+                                                        let __seq_res =
+                                                            __parse_access(__input, __state, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, lhs) => {
+                                                                let __seq_res = __parse___(
+                                                                    __input, __state, __pos,
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = slice_eq(
+                                                                            __input, __state,
+                                                                            __pos, "=",
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res =
+                                                                                    __parse___(
+                                                                                        __input,
+                                                                                        __state,
+                                                                                        __pos,
+                                                                                    );
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        _,
+                                                                                    ) => {
+                                                                                        let __seq_res = __parse_expression ( __input , __state , __pos ) ;
+                                                                                        match __seq_res { Matched ( __pos , rhs ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::Expression::Assignment(lhs, Box::new(rhs))  } } ) } Failed => Failed , }
+                                                                                    }
+                                                                                    Failed => {
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                };
+                                                match __choice_res {
+                                                    Matched(__pos, __value) => {
+                                                        Matched(__pos, __value)
+                                                    }
+                                                    Failed => {
+                                                        let __choice_res = {
+                                                            let __start_pos = __pos;
+                                                            {
+                                                                let __seq_res = __parse_update_op(
+                                                                    __input, __state, __pos,
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, op) => {
+                                                                        let __seq_res = __parse___(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res =
+                                                                                    __parse_access(
+                                                                                        __input,
+                                                                                        __state,
+                                                                                        __pos,
+                                                                                    );
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        a,
+                                                                                    ) => Matched(
+                                                                                        __pos,
+                                                                                        {
+                                                                                            let match_str = &__input[__start_pos..__pos];
+                                                                                            let start_pos = __start_pos;
+                                                                                            let pos = __pos;
+                                                                                            {
+                                                                                                ast::Expression::PrefixUpdate(op, a)
+                                                                                            }
+                                                                                        },
+                                                                                    ),
+                                                                                    Failed => {
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                        };
+                                                        match __choice_res {
+                                                            Matched(__pos, __value) => {
+                                                                Matched(__pos, __value)
+                                                            }
+                                                            Failed => {
+                                                                let __choice_res = {
+                                                                    let __start_pos = __pos;
+                                                                    {
+                                                                        let __seq_res =
+                                                                            __parse_access(
+                                                                                __input, __state,
+                                                                                __pos,
+                                                                            );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, a) => {
+                                                                                let __seq_res = {
+                                                                                    let mut
+                                                                                    __repeat_pos =
+                                                                                        __pos;
+                                                                                    loop {
+                                                                                        let __pos = __repeat_pos ;
+                                                                                        let __step_res = __parse_whitespace ( __input , __state , __pos ) ;
+                                                                                        match __step_res { Matched ( __newpos , __value ) => { __repeat_pos = __newpos ; } , Failed => { break ; } }
+                                                                                    }
+                                                                                    Matched ( __repeat_pos , ( ) )
+                                                                                };
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        _,
+                                                                                    ) => {
+                                                                                        let __seq_res = __parse_update_op ( __input , __state , __pos ) ;
+                                                                                        match __seq_res { Matched ( __pos , op ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::Expression::PostfixUpdate(op, a)  } } ) } Failed => Failed , }
+                                                                                    }
+                                                                                    Failed => {
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                };
+                                                                match __choice_res {
+                                                                    Matched(__pos, __value) => {
+                                                                        Matched(__pos, __value)
+                                                                    }
+                                                                    Failed => {
+                                                                        let __choice_res =
+                                                                            __parse_method_call(
+                                                                                __input, __state,
+                                                                                __pos,
+                                                                            );
+                                                                        match __choice_res {
+                                                                            Matched(
+                                                                                __pos,
+                                                                                __value,
+                                                                            ) => Matched(
+                                                                                __pos, __value,
+                                                                            ),
+                                                                            Failed => {
+                                                                                let __choice_res = __parse_atomic_expression ( __input , __state , __pos ) ;
+                                                                                match __choice_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        __value,
+                                                                                    ) => Matched(
+                                                                                        __pos,
+                                                                                        __value,
+                                                                                    ),
+                                                                                    Failed => {
+                                                                                        let __start_pos =
+                                                                                            __pos;
+                                                                                        {
+                                                                                            let __seq_res = __parse_unary_op ( __input , __state , __pos ) ;
+                                                                                            match __seq_res { Matched ( __pos , op ) => { { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse_simple_expression ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , exp ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::Expression::Unary(op, Box::new(exp))  } } ) } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , }
+                                                                                        }
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                        }
+                                                                    }
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_assignment_op<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::BinaryOp> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = slice_eq(__input, __state, __pos, "+=");
+                match __seq_res {
+                    Matched(__pos, _) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::BinaryOp::Add
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
+                    {
+                        let __seq_res = slice_eq(__input, __state, __pos, "-=");
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    ast::BinaryOp::Subtract
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = {
+                            let __start_pos = __pos;
+                            {
+                                let __seq_res = slice_eq(__input, __state, __pos, "*=");
+                                match __seq_res {
+                                    Matched(__pos, _) => Matched(__pos, {
+                                        let match_str = &__input[__start_pos..__pos];
+                                        let start_pos = __start_pos;
+                                        let pos = __pos;
+                                        {
+                                            ast::BinaryOp::Multiply
+                                        }
+                                    }),
+                                    Failed => Failed,
+                                }
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __start_pos = __pos;
+                                    {
+                                        let __seq_res = slice_eq(__input, __state, __pos, "/=");
+                                        match __seq_res {
+                                            Matched(__pos, _) => Matched(__pos, {
+                                                let match_str = &__input[__start_pos..__pos];
+                                                let start_pos = __start_pos;
+                                                let pos = __pos;
+                                                {
+                                                    ast::BinaryOp::Divide
+                                                }
+                                            }),
+                                            Failed => Failed,
+                                        }
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __choice_res = {
+                                            let __start_pos = __pos;
+                                            {
+                                                let __seq_res =
+                                                    slice_eq(__input, __state, __pos, "%=");
+                                                match __seq_res {
+                                                    Matched(__pos, _) => Matched(__pos, {
+                                                        let match_str =
+                                                            &__input[__start_pos..__pos];
+                                                        let start_pos = __start_pos;
+                                                        let pos = __pos;
+                                                        {
+                                                            ast::BinaryOp::Modulo
+                                                        }
+                                                    }),
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                        };
+                                        match __choice_res {
+                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                            Failed => {
+                                                let __choice_res = {
+                                                    let __start_pos = __pos;
+                                                    {
+                                                        let __seq_res = slice_eq(
+                                                            __input, __state, __pos, "<<=",
+                                                        );
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => Matched(__pos, {
+                                                                let match_str =
+                                                                    &__input[__start_pos..__pos];
+                                                                let start_pos = __start_pos;
+                                                                let pos = __pos;
+                                                                {
+                                                                    ast::BinaryOp::LeftShift
+                                                                }
+                                                            }),
+                                                            Failed => Failed,
+                                                        }
+                                                    }
+                                                };
+                                                match __choice_res {
+                                                    Matched(__pos, __value) => {
+                                                        Matched(__pos, __value)
+                                                    }
+                                                    Failed => {
+                                                        let __choice_res = {
+                                                            let __start_pos = __pos;
+                                                            {
+                                                                let __seq_res = slice_eq(
+                                                                    __input, __state, __pos, ">>>=",
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        Matched(__pos, {
+                                                                            let match_str =
+                                                                                &__input[__start_pos
+                                                                                    ..__pos];
+                                                                            let start_pos =
+                                                                                __start_pos;
+                                                                            let pos = __pos;
+                                                                            {
+                                                                                ast::BinaryOp::UnsignedRightShift
+                                                                            }
+                                                                        })
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                        };
+                                                        match __choice_res {
+                                                            Matched(__pos, __value) => {
+                                                                Matched(__pos, __value)
+                                                            }
+                                                            Failed => {
+                                                                let __choice_res = {
+                                                                    let __start_pos = __pos;
+                                                                    {
+                                                                        let __seq_res = slice_eq(
+                                                                            __input, __state,
+                                                                            __pos, ">>=",
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                Matched(__pos, {
+                                                                                    let match_str = &__input[__start_pos..__pos];
+                                                                                    let start_pos =
+                                                                                        __start_pos;
+                                                                                    let pos = __pos;
+                                                                                    {
+                                                                                        ast::BinaryOp::SignedRightShift
+                                                                                    }
+                                                                                })
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                };
+                                                                match __choice_res {
+                                                                    Matched(__pos, __value) => {
+                                                                        Matched(__pos, __value)
+                                                                    }
+                                                                    Failed => {
+                                                                        let __choice_res = {
+                                                                            let __start_pos = __pos;
+                                                                            {
+                                                                                let __seq_res =
+                                                                                    slice_eq(
+                                                                                        __input,
+                                                                                        __state,
+                                                                                        __pos,
+                                                                                        "&=",
+                                                                                    );
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        _,
+                                                                                    ) => Matched(
+                                                                                        __pos,
+                                                                                        {
+                                                                                            let match_str = &__input[__start_pos..__pos];
+                                                                                            let start_pos = __start_pos;
+                                                                                            let pos = __pos;
+                                                                                            {
+                                                                                                ast::BinaryOp::BitwiseAnd
+                                                                                            }
+                                                                                        },
+                                                                                    ),
+                                                                                    Failed => {
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                        };
+                                                                        match __choice_res {
+                                                                            Matched(
+                                                                                __pos,
+                                                                                __value,
+                                                                            ) => Matched(
+                                                                                __pos, __value,
+                                                                            ),
+                                                                            Failed => {
+                                                                                let __choice_res = {
+                                                                                    let __start_pos =
+                                                                                        __pos;
+                                                                                    {
+                                                                                        let __seq_res = slice_eq ( __input , __state , __pos , "|=" ) ;
+                                                                                        match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::BinaryOp::BitwiseOr  } } ) } Failed => Failed , }
+                                                                                    }
+                                                                                };
+                                                                                match __choice_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        __value,
+                                                                                    ) => Matched(
+                                                                                        __pos,
+                                                                                        __value,
+                                                                                    ),
+                                                                                    Failed => {
+                                                                                        let __start_pos =
+                                                                                            __pos;
+                                                                                        {
+                                                                                            let __seq_res = slice_eq ( __input , __state , __pos , "^=" ) ;
+                                                                                            match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  ast::BinaryOp::BitwiseXor  } } ) } Failed => Failed , }
+                                                                                        }
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                        }
+                                                                    }
+                                                                }
+                                                            }
+                                                        }
+                                                    }
+                                                }
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_update_op<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::UpdateOp> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = slice_eq(__input, __state, __pos, "++");
+                match __seq_res {
+                    Matched(__pos, _) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::UpdateOp::Increment
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __start_pos = __pos;
+                {
+                    let __seq_res = slice_eq(__input, __state, __pos, "--");
+                    match __seq_res {
+                        Matched(__pos, _) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                ast::UpdateOp::Decrement
+                            }
+                        }),
+                        Failed => Failed,
                     }
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+            }
+        }
+    }
+}
+
+fn __parse_unary_op<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::UnaryOp> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = slice_eq(__input, __state, __pos, "+");
+                match __seq_res {
+                    Matched(__pos, _) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::UnaryOp::Positive
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let choice_res =
+                let __choice_res = {
+                    let __start_pos = __pos;
                     {
-                        let start_pos = pos;
-                        {
-                            let seq_res = slice_eq(input, state, pos, "-");
-                            match seq_res {
-                                Matched(pos, _) => {
-                                    {
-                                        let match_str =
-                                            &input[start_pos..pos];
-                                        Matched(pos,
-                                                { ast::UnaryOp::Negative })
-                                    }
+                        let __seq_res = slice_eq(__input, __state, __pos, "-");
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    ast::UnaryOp::Negative
                                 }
-                                Failed => Failed,
-                            }
+                            }),
+                            Failed => Failed,
                         }
-                    };
-                match choice_res {
-                    Matched(pos, value) => Matched(pos, value),
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
                     Failed => {
-                        let start_pos = pos;
-                        {
-                            let seq_res = slice_eq(input, state, pos, "!");
-                            match seq_res {
-                                Matched(pos, _) => {
-                                    {
-                                        let match_str =
-                                            &input[start_pos..pos];
-                                        Matched(pos,
-                                                { ast::UnaryOp::LogicalNot })
+                        let __choice_res = {
+                            let __start_pos = __pos;
+                            {
+                                let __seq_res = slice_eq(__input, __state, __pos, "!");
+                                match __seq_res {
+                                    Matched(__pos, _) => Matched(__pos, {
+                                        let match_str = &__input[__start_pos..__pos];
+                                        let start_pos = __start_pos;
+                                        let pos = __pos;
+                                        {
+                                            ast::UnaryOp::LogicalNot
+                                        }
+                                    }),
+                                    Failed => Failed,
+                                }
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = slice_eq(__input, __state, __pos, "~");
+                                    match __seq_res {
+                                        Matched(__pos, _) => Matched(__pos, {
+                                            let match_str = &__input[__start_pos..__pos];
+                                            let start_pos = __start_pos;
+                                            let pos = __pos;
+                                            {
+                                                ast::UnaryOp::BitwiseNot
+                                            }
+                                        }),
+                                        Failed => Failed,
                                     }
                                 }
-                                Failed => Failed,
                             }
                         }
                     }
@@ -1773,131 +4286,413 @@ fn parse_unary_op<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_sum_op<'input>(input: &'input str, state: &mut ParseState,
-                        pos: usize) -> RuleResult<ast::BinaryOp> {
+
+fn __parse_sum_op<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::BinaryOp> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
+        let __choice_res = {
+            let __start_pos = __pos;
             {
-                let start_pos = pos;
+                let __seq_res = slice_eq(__input, __state, __pos, "+");
+                match __seq_res {
+                    Matched(__pos, _) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::BinaryOp::Add
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __start_pos = __pos;
                 {
-                    let seq_res = slice_eq(input, state, pos, "+");
-                    match seq_res {
-                        Matched(pos, _) => {
+                    let __seq_res = slice_eq(__input, __state, __pos, "-");
+                    match __seq_res {
+                        Matched(__pos, _) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
                             {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { ast::BinaryOp::Add })
+                                ast::BinaryOp::Subtract
                             }
-                        }
+                        }),
                         Failed => Failed,
                     }
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+            }
+        }
+    }
+}
+
+fn __parse_product_op<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::BinaryOp> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = slice_eq(__input, __state, __pos, "*");
+                match __seq_res {
+                    Matched(__pos, _) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::BinaryOp::Multiply
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let choice_res =
+                let __choice_res = {
+                    let __start_pos = __pos;
                     {
-                        let start_pos = pos;
+                        let __seq_res = slice_eq(__input, __state, __pos, "/");
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    ast::BinaryOp::Divide
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __start_pos = __pos;
                         {
-                            let seq_res = slice_eq(input, state, pos, "-");
-                            match seq_res {
-                                Matched(pos, _) => {
+                            let __seq_res = slice_eq(__input, __state, __pos, "%");
+                            match __seq_res {
+                                Matched(__pos, _) => Matched(__pos, {
+                                    let match_str = &__input[__start_pos..__pos];
+                                    let start_pos = __start_pos;
+                                    let pos = __pos;
                                     {
-                                        let match_str =
-                                            &input[start_pos..pos];
-                                        Matched(pos,
-                                                { ast::BinaryOp::Subtract })
+                                        ast::BinaryOp::Modulo
                                     }
-                                }
+                                }),
                                 Failed => Failed,
                             }
                         }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_method_call<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse_access(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, f) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = __parse_called(__input, __state, __pos);
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
                     };
-                match choice_res {
-                    Matched(pos, value) => Matched(pos, value),
-                    Failed => {
-                        let choice_res =
+                    match __seq_res {
+                        Matched(__pos, calls) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
                             {
-                                let start_pos = pos;
-                                {
-                                    let seq_res =
-                                        slice_eq(input, state, pos, "&&");
-                                    match seq_res {
-                                        Matched(pos, _) => {
-                                            {
-                                                let match_str =
-                                                    &input[start_pos..pos];
-                                                Matched(pos,
-                                                        {
-                                                            ast::BinaryOp::LogicalAnd
-                                                        })
-                                            }
-                                        }
-                                        Failed => Failed,
-                                    }
+                                let mut func = ast::Expression::Access(f);
+                                for el in calls {
+                                    func = ast::Expression::Call(Box::new(func), el)
                                 }
-                            };
-                        match choice_res {
-                            Matched(pos, value) => Matched(pos, value),
-                            Failed => {
-                                let start_pos = pos;
-                                {
-                                    let seq_res =
-                                        slice_eq(input, state, pos, "||");
-                                    match seq_res {
-                                        Matched(pos, _) => {
-                                            {
-                                                let match_str =
-                                                    &input[start_pos..pos];
-                                                Matched(pos,
-                                                        {
-                                                            ast::BinaryOp::LogicalOr
-                                                        })
+                                func
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_new<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = slice_eq(__input, __state, __pos, "new");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse_access(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, a) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                match __parse_called(__input, __state, __pos) {
+                                                    Matched(__newpos, __value) => {
+                                                        Matched(__newpos, Some(__value))
+                                                    }
+                                                    Failed => Matched(__pos, None),
+                                                };
+                                            match __seq_res {
+                                                Matched(__pos, list) => Matched(__pos, {
+                                                    let match_str = &__input[__start_pos..__pos];
+                                                    let start_pos = __start_pos;
+                                                    let pos = __pos;
+                                                    {
+                                                        ast::Expression::New(
+                                                            Box::new(ast::Expression::Access(a)),
+                                                            match list {
+                                                                Some(l) => l,
+                                                                None => vec![],
+                                                            },
+                                                        )
+                                                    }
+                                                }),
+                                                Failed => Failed,
                                             }
                                         }
                                         Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
                         }
+                        Failed => Failed,
                     }
                 }
+                Failed => Failed,
             }
         }
     }
 }
-fn parse_product_op<'input>(input: &'input str, state: &mut ParseState,
-                            pos: usize) -> RuleResult<ast::BinaryOp> {
+
+fn __parse_access<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Access> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
+        let __choice_res = {
+            let __start_pos = __pos;
             {
-                let start_pos = pos;
+                let __seq_res = __parse_atomic_expression(__input, __state, __pos);
+                match __seq_res {
+                    Matched(__pos, p) => {
+                        let __seq_res = {
+                            let mut __repeat_pos = __pos;
+                            let mut __repeat_value = vec![];
+                            loop {
+                                let __pos = __repeat_pos;
+                                let __step_res = __parse_call_access(__input, __state, __pos);
+                                match __step_res {
+                                    Matched(__newpos, __value) => {
+                                        __repeat_pos = __newpos;
+                                        __repeat_value.push(__value);
+                                    }
+                                    Failed => {
+                                        break;
+                                    }
+                                }
+                            }
+                            if __repeat_value.len() >= 1 {
+                                Matched(__repeat_pos, __repeat_value)
+                            } else {
+                                Failed
+                            }
+                        };
+                        match __seq_res {
+                            Matched(__pos, c) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    let mut iter = c.into_iter();
+                                    let (first_calls, first_ac) = iter.next().unwrap();
+                                    let mut expr = p;
+                                    for call in first_calls {
+                                        expr = ast::Expression::Call(Box::new(expr), call)
+                                    }
+                                    let mut ret = ast::Access::Member(Box::new(expr), first_ac);
+
+                                    for (calls, ac) in iter {
+                                        let mut expr = ast::Expression::Access(ret);
+                                        for call in calls {
+                                            expr = ast::Expression::Call(Box::new(expr), call)
+                                        }
+                                        ret = ast::Access::Member(Box::new(expr), ac)
+                                    }
+
+                                    ret
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __start_pos = __pos;
                 {
-                    let seq_res = slice_eq(input, state, pos, "*");
-                    match seq_res {
-                        Matched(pos, _) => {
+                    let __seq_res = __parse_identifier(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, i) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
                             {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { ast::BinaryOp::Multiply })
+                                ast::Access::Identifier(i)
                             }
-                        }
+                        }),
                         Failed => Failed,
                     }
                 }
+            }
+        }
+    }
+}
+
+fn __parse_call_access<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<(Vec<ast::ExpressionList>, ast::Accessor)> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = {
+                let mut __repeat_pos = __pos;
+                let mut __repeat_value = vec![];
+                loop {
+                    let __pos = __repeat_pos;
+                    let __step_res = __parse_called(__input, __state, __pos);
+                    match __step_res {
+                        Matched(__newpos, __value) => {
+                            __repeat_pos = __newpos;
+                            __repeat_value.push(__value);
+                        }
+                        Failed => {
+                            break;
+                        }
+                    }
+                }
+                Matched(__repeat_pos, __repeat_value)
             };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+            match __seq_res {
+                Matched(__pos, c) => {
+                    let __seq_res = __parse_accessor(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, a) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                (c, a)
+                            }
+                        }),
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_accessor<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Accessor> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = __parse_dotted(__input, __state, __pos);
+                match __seq_res {
+                    Matched(__pos, d) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::Accessor::Identifier(d)
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let start_pos = pos;
+                let __start_pos = __pos;
                 {
-                    let seq_res = slice_eq(input, state, pos, "/");
-                    match seq_res {
-                        Matched(pos, _) => {
+                    let __seq_res = __parse_subscripted(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, s) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
                             {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { ast::BinaryOp::Divide })
+                                ast::Accessor::Expression(Box::new(s))
                             }
-                        }
+                        }),
                         Failed => Failed,
                     }
                 }
@@ -1905,52 +4700,99 @@ fn parse_product_op<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_method_call<'input>(input: &'input str, state: &mut ParseState,
-                             pos: usize) -> RuleResult<ast::Expression> {
+
+fn __parse_dotted<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Identifier> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res = parse_access(input, state, pos);
-            match seq_res {
-                Matched(pos, f) => {
-                    {
-                        let seq_res =
-                            {
-                                let mut repeat_pos = pos;
-                                let mut repeat_value = vec!();
-                                loop  {
-                                    let pos = repeat_pos;
-                                    let step_res =
-                                        parse_called(input, state, pos);
-                                    match step_res {
-                                        Matched(newpos, value) => {
-                                            repeat_pos = newpos;
-                                            repeat_value.push(value);
-                                        }
-                                        Failed => { break ; }
+            let __seq_res = __parse___(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = slice_eq(__input, __state, __pos, ".");
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse___(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = __parse_identifier(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, i) => Matched(__pos, {
+                                            let match_str = &__input[__start_pos..__pos];
+                                            let start_pos = __start_pos;
+                                            let pos = __pos;
+                                            {
+                                                i
+                                            }
+                                        }),
+                                        Failed => Failed,
                                     }
                                 }
-                                Matched(repeat_pos, repeat_value)
-                            };
-                        match seq_res {
-                            Matched(pos, calls) => {
-                                {
-                                    let match_str = &input[start_pos..pos];
-                                    Matched(pos,
-                                            {
-                                                let mut func =
-                                                    ast::Expression::Access(f);
-                                                for el in calls {
-                                                    func =
-                                                        ast::Expression::Call(Box::new(func),
-                                                                              el)
+                                Failed => Failed,
+                            }
+                        }
+                        Failed => Failed,
+                    }
+                }
+                Failed => Failed,
+            }
+        }
+    }
+}
+
+fn __parse_subscripted<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse___(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = slice_eq(__input, __state, __pos, "[");
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse___(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = __parse_expression(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, e) => {
+                                            let __seq_res = __parse___(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res =
+                                                        slice_eq(__input, __state, __pos, "]");
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => Matched(__pos, {
+                                                            let match_str =
+                                                                &__input[__start_pos..__pos];
+                                                            let start_pos = __start_pos;
+                                                            let pos = __pos;
+                                                            {
+                                                                e
+                                                            }
+                                                        }),
+                                                        Failed => Failed,
+                                                    }
                                                 }
-                                                func
-                                            })
+                                                Failed => Failed,
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
                                 }
+                                Failed => Failed,
                             }
-                            Failed => Failed,
                         }
+                        Failed => Failed,
                     }
                 }
                 Failed => Failed,
@@ -1958,84 +4800,57 @@ fn parse_method_call<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_new<'input>(input: &'input str, state: &mut ParseState, pos: usize)
- -> RuleResult<ast::Expression> {
+
+fn __parse_called<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::ExpressionList> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res = slice_eq(input, state, pos, "new");
-            match seq_res {
-                Matched(pos, _) => {
-                    {
-                        let seq_res = parse___(input, state, pos);
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res =
-                                        parse_access(input, state, pos);
-                                    match seq_res {
-                                        Matched(pos, a) => {
-                                            {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let seq_res =
-                                                                match parse_called(input,
-                                                                                   state,
-                                                                                   pos)
-                                                                    {
-                                                                    Matched(newpos,
-                                                                            value)
-                                                                    => {
-                                                                        Matched(newpos,
-                                                                                Some(value))
-                                                                    }
-                                                                    Failed =>
-                                                                    {
-                                                                        Matched(pos,
-                                                                                None)
-                                                                    }
-                                                                };
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        list)
-                                                                => {
-                                                                    {
-                                                                        let match_str =
-                                                                            &input[start_pos..pos];
-                                                                        Matched(pos,
-                                                                                {
-                                                                                    ast::Expression::New(Box::new(ast::Expression::Access(a)),
-                                                                                                         match list
-                                                                                                             {
-                                                                                                             Some(l)
-                                                                                                             =>
-                                                                                                             l,
-                                                                                                             None
-                                                                                                             =>
-                                                                                                             vec!(),
-                                                                                                         })
-                                                                                })
-                                                                    }
-                                                                }
-                                                                Failed =>
-                                                                Failed,
+            let __seq_res = __parse___(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = slice_eq(__input, __state, __pos, "(");
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse___(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res =
+                                        __parse_expression_list(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, el) => {
+                                            let __seq_res = __parse___(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res =
+                                                        slice_eq(__input, __state, __pos, ")");
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => Matched(__pos, {
+                                                            let match_str =
+                                                                &__input[__start_pos..__pos];
+                                                            let start_pos = __start_pos;
+                                                            let pos = __pos;
+                                                            {
+                                                                el
                                                             }
-                                                        }
+                                                        }),
+                                                        Failed => Failed,
                                                     }
-                                                    Failed => Failed,
                                                 }
+                                                Failed => Failed,
                                             }
                                         }
                                         Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
-                            Failed => Failed,
                         }
+                        Failed => Failed,
                     }
                 }
                 Failed => Failed,
@@ -2043,312 +4858,479 @@ fn parse_new<'input>(input: &'input str, state: &mut ParseState, pos: usize)
         }
     }
 }
-fn parse_access<'input>(input: &'input str, state: &mut ParseState,
-                        pos: usize) -> RuleResult<ast::Access> {
+
+fn __parse_atomic_expression<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Expression> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
+        let __choice_res = {
+            let __start_pos = __pos;
             {
-                let start_pos = pos;
-                {
-                    let seq_res = parse_atomic_expression(input, state, pos);
-                    match seq_res {
-                        Matched(pos, p) => {
+                let __seq_res = slice_eq(__input, __state, __pos, "this");
+                match __seq_res {
+                    Matched(__pos, _) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            ast::Expression::This
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
+                    {
+                        let __seq_res = __parse_literal(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, v) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    ast::Expression::Literal(v)
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = {
+                            let __start_pos = __pos;
                             {
-                                let seq_res =
+                                let __seq_res = __parse_function_literal(__input, __state, __pos);
+                                match __seq_res {
+                                    Matched(__pos, f) => Matched(__pos, {
+                                        let match_str = &__input[__start_pos..__pos];
+                                        let start_pos = __start_pos;
+                                        let pos = __pos;
+                                        {
+                                            ast::Expression::Function(f)
+                                        }
+                                    }),
+                                    Failed => Failed,
+                                }
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __start_pos = __pos;
                                     {
-                                        let mut repeat_pos = pos;
-                                        let mut repeat_value = vec!();
-                                        loop  {
-                                            let pos = repeat_pos;
-                                            let step_res =
-                                                parse_call_access(input,
-                                                                  state, pos);
-                                            match step_res {
-                                                Matched(newpos, value) => {
-                                                    repeat_pos = newpos;
-                                                    repeat_value.push(value);
+                                        let __seq_res = __parse_identifier(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, i) => Matched(__pos, {
+                                                let match_str = &__input[__start_pos..__pos];
+                                                let start_pos = __start_pos;
+                                                let pos = __pos;
+                                                {
+                                                    ast::Expression::Access(
+                                                        ast::Access::Identifier(i),
+                                                    )
                                                 }
-                                                Failed => { break ; }
-                                            }
+                                            }),
+                                            Failed => Failed,
                                         }
-                                        if repeat_value.len() >= 1usize {
-                                            Matched(repeat_pos, repeat_value)
-                                        } else { Failed }
-                                    };
-                                match seq_res {
-                                    Matched(pos, c) => {
-                                        {
-                                            let match_str =
-                                                &input[start_pos..pos];
-                                            Matched(pos,
-                                                    {
-                                                        let mut iter =
-                                                            c.into_iter();
-                                                        let (first_calls,
-                                                             first_ac) =
-                                                            iter.next().unwrap();
-                                                        let mut expr = p;
-                                                        for call in
-                                                            first_calls {
-                                                            expr =
-                                                                ast::Expression::Call(Box::new(expr),
-                                                                                      call)
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __choice_res = {
+                                            let __start_pos = __pos;
+                                            {
+                                                let __seq_res =
+                                                    __parse_array(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, a) => Matched(__pos, {
+                                                        let match_str =
+                                                            &__input[__start_pos..__pos];
+                                                        let start_pos = __start_pos;
+                                                        let pos = __pos;
+                                                        {
+                                                            ast::Expression::Array(a)
                                                         }
-                                                        let mut ret =
-                                                            ast::Access::Member(Box::new(expr),
-                                                                                first_ac);
-                                                        for (calls, ac) in
-                                                            iter {
-                                                            let mut expr =
-                                                                ast::Expression::Access(ret);
-                                                            for call in calls
-                                                                {
-                                                                expr =
-                                                                    ast::Expression::Call(Box::new(expr),
-                                                                                          call)
+                                                    }),
+                                                    Failed => Failed,
+                                                }
+                                            }
+                                        };
+                                        match __choice_res {
+                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                            Failed => {
+                                                let __start_pos = __pos;
+                                                {
+                                                    let __seq_res =
+                                                        slice_eq(__input, __state, __pos, "(");
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res =
+                                                                __parse___(__input, __state, __pos);
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res =
+                                                                        __parse_expression(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                    match __seq_res {
+                                                                        Matched(__pos, e) => {
+                                                                            let __seq_res =
+                                                                                __parse___(
+                                                                                    __input,
+                                                                                    __state, __pos,
+                                                                                );
+                                                                            match __seq_res {
+                                                                                Matched(
+                                                                                    __pos,
+                                                                                    _,
+                                                                                ) => {
+                                                                                    let __seq_res =
+                                                                                        slice_eq(
+                                                                                            __input,
+                                                                                            __state,
+                                                                                            __pos,
+                                                                                            ")",
+                                                                                        );
+                                                                                    match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {  e  } } ) } Failed => Failed , }
+                                                                                }
+                                                                                Failed => Failed,
+                                                                            }
+                                                                        }
+                                                                        Failed => Failed,
+                                                                    }
+                                                                }
+                                                                Failed => Failed,
                                                             }
-                                                            ret =
-                                                                ast::Access::Member(Box::new(expr),
-                                                                                    ac)
                                                         }
-                                                        ret
-                                                    })
+                                                        Failed => Failed,
+                                                    }
+                                                }
+                                            }
                                         }
                                     }
-                                    Failed => Failed,
                                 }
                             }
                         }
-                        Failed => Failed,
-                    }
-                }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
-            Failed => {
-                let start_pos = pos;
-                {
-                    let seq_res = parse_identifier(input, state, pos);
-                    match seq_res {
-                        Matched(pos, i) => {
-                            {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { ast::Access::Identifier(i) })
-                            }
-                        }
-                        Failed => Failed,
                     }
                 }
             }
         }
     }
 }
-fn parse_call_access<'input>(input: &'input str, state: &mut ParseState,
-                             pos: usize)
- -> RuleResult<(Vec<ast::ExpressionList>, ast::Accessor)> {
-    {
-        let start_pos = pos;
+
+fn __parse_position<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Position> {
+    #![allow(non_snake_case, unused)]
+    if let Some(entry) = __state.position_cache.get(&__pos) {
+        return entry.clone();
+    }
+    let __rule_result = {
+        let __start_pos = __pos;
         {
-            let seq_res =
-                {
-                    let mut repeat_pos = pos;
-                    let mut repeat_value = vec!();
-                    loop  {
-                        let pos = repeat_pos;
-                        let step_res = parse_called(input, state, pos);
-                        match step_res {
-                            Matched(newpos, value) => {
-                                repeat_pos = newpos;
-                                repeat_value.push(value);
-                            }
-                            Failed => { break ; }
-                        }
-                    }
-                    Matched(repeat_pos, repeat_value)
-                };
-            match seq_res {
-                Matched(pos, c) => {
+            let __seq_res = slice_eq(__input, __state, __pos, "");
+            match __seq_res {
+                Matched(__pos, _) => Matched(__pos, {
+                    let match_str = &__input[__start_pos..__pos];
+                    let start_pos = __start_pos;
+                    let pos = __pos;
                     {
-                        let seq_res = parse_accessor(input, state, pos);
-                        match seq_res {
-                            Matched(pos, a) => {
-                                {
-                                    let match_str = &input[start_pos..pos];
-                                    Matched(pos, { (c, a) })
-                                }
-                            }
-                            Failed => Failed,
-                        }
+                        super::position(__input, start_pos)
                     }
-                }
+                }),
                 Failed => Failed,
             }
         }
-    }
+    };
+    __state.position_cache.insert(__pos, __rule_result.clone());
+    __rule_result
 }
-fn parse_accessor<'input>(input: &'input str, state: &mut ParseState,
-                          pos: usize) -> RuleResult<ast::Accessor> {
+
+fn __parse_expression_list<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::ExpressionList> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
-            {
-                let start_pos = pos;
-                {
-                    let seq_res = parse_dotted(input, state, pos);
-                    match seq_res {
-                        Matched(pos, d) => {
-                            {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { ast::Accessor::Identifier(d) })
-                            }
-                        }
-                        Failed => Failed,
-                    }
+        let mut __repeat_pos = __pos;
+        let mut __repeat_value = vec![];
+        loop {
+            let __pos = __repeat_pos;
+            let __pos = if __repeat_value.len() > 0 {
+                let __sep_res = slice_eq(__input, __state, __pos, ",");
+                match __sep_res {
+                    Matched(__newpos, _) => __newpos,
+                    Failed => break,
                 }
+            } else {
+                __pos
             };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
-            Failed => {
-                let start_pos = pos;
+            let __step_res = {
+                let __start_pos = __pos;
                 {
-                    let seq_res = parse_subscripted(input, state, pos);
-                    match seq_res {
-                        Matched(pos, s) => {
-                            {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos,
-                                        {
-                                            ast::Accessor::Expression(Box::new(s))
-                                        })
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse_expression(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, e) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => Matched(__pos, {
+                                            let match_str = &__input[__start_pos..__pos];
+                                            let start_pos = __start_pos;
+                                            let pos = __pos;
+                                            {
+                                                e
+                                            }
+                                        }),
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
                             }
                         }
                         Failed => Failed,
                     }
                 }
+            };
+            match __step_res {
+                Matched(__newpos, __value) => {
+                    __repeat_pos = __newpos;
+                    __repeat_value.push(__value);
+                }
+                Failed => {
+                    break;
+                }
             }
         }
+        Matched(__repeat_pos, __repeat_value)
     }
 }
-fn parse_dotted<'input>(input: &'input str, state: &mut ParseState,
-                        pos: usize) -> RuleResult<ast::Identifier> {
+
+fn __parse_literal<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<interpret::Value> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
-        {
-            let seq_res = parse___(input, state, pos);
-            match seq_res {
-                Matched(pos, _) => {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = __parse_number(__input, __state, __pos);
+                match __seq_res {
+                    Matched(__pos, n) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            interpret::Value::Number(n)
+                        }
+                    }),
+                    Failed => Failed,
+                }
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
                     {
-                        let seq_res = slice_eq(input, state, pos, ".");
-                        match seq_res {
-                            Matched(pos, _) => {
+                        let __seq_res = __parse_boolean(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, b) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
                                 {
-                                    let seq_res = parse___(input, state, pos);
-                                    match seq_res {
-                                        Matched(pos, _) => {
-                                            {
-                                                let seq_res =
-                                                    parse_identifier(input,
-                                                                     state,
-                                                                     pos);
-                                                match seq_res {
-                                                    Matched(pos, i) => {
-                                                        {
-                                                            let match_str =
-                                                                &input[start_pos..pos];
-                                                            Matched(pos,
-                                                                    { i })
-                                                        }
-                                                    }
-                                                    Failed => Failed,
+                                    interpret::Value::Boolean(b)
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = {
+                            let __start_pos = __pos;
+                            {
+                                let __seq_res = __parse_string(__input, __state, __pos);
+                                match __seq_res {
+                                    Matched(__pos, s) => Matched(__pos, {
+                                        let match_str = &__input[__start_pos..__pos];
+                                        let start_pos = __start_pos;
+                                        let pos = __pos;
+                                        {
+                                            interpret::Value::String(s)
+                                        }
+                                    }),
+                                    Failed => Failed,
+                                }
+                            }
+                        };
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = {
+                                    let __start_pos = __pos;
+                                    {
+                                        let __seq_res = slice_eq(__input, __state, __pos, "null");
+                                        match __seq_res {
+                                            Matched(__pos, _) => Matched(__pos, {
+                                                let match_str = &__input[__start_pos..__pos];
+                                                let start_pos = __start_pos;
+                                                let pos = __pos;
+                                                {
+                                                    interpret::Value::Object(
+                                                        interpret::Object::Null,
+                                                    )
                                                 }
+                                            }),
+                                            Failed => Failed,
+                                        }
+                                    }
+                                };
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __start_pos = __pos;
+                                        {
+                                            let __seq_res =
+                                                slice_eq(__input, __state, __pos, "undefined");
+                                            match __seq_res {
+                                                Matched(__pos, _) => Matched(__pos, {
+                                                    let match_str = &__input[__start_pos..__pos];
+                                                    let start_pos = __start_pos;
+                                                    let pos = __pos;
+                                                    {
+                                                        interpret::Value::Undefined
+                                                    }
+                                                }),
+                                                Failed => Failed,
                                             }
                                         }
-                                        Failed => Failed,
                                     }
                                 }
                             }
-                            Failed => Failed,
                         }
                     }
                 }
-                Failed => Failed,
             }
         }
     }
 }
-fn parse_subscripted<'input>(input: &'input str, state: &mut ParseState,
-                             pos: usize) -> RuleResult<ast::Expression> {
+
+fn __parse_function_literal<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Function> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res = parse___(input, state, pos);
-            match seq_res {
-                Matched(pos, _) => {
-                    {
-                        let seq_res = slice_eq(input, state, pos, "[");
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res = parse___(input, state, pos);
-                                    match seq_res {
-                                        Matched(pos, _) => {
-                                            {
-                                                let seq_res =
-                                                    parse_expression(input,
-                                                                     state,
-                                                                     pos);
-                                                match seq_res {
-                                                    Matched(pos, e) => {
-                                                        {
-                                                            let seq_res =
-                                                                parse___(input,
-                                                                         state,
-                                                                         pos);
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        _) =>
-                                                                {
-                                                                    {
-                                                                        let seq_res =
-                                                                            slice_eq(input,
-                                                                                     state,
-                                                                                     pos,
-                                                                                     "]");
-                                                                        match seq_res
-                                                                            {
-                                                                            Matched(pos,
-                                                                                    _)
-                                                                            =>
-                                                                            {
-                                                                                {
-                                                                                    let match_str =
-                                                                                        &input[start_pos..pos];
-                                                                                    Matched(pos,
-                                                                                            {
-                                                                                                e
-                                                                                            })
+            let __seq_res = slice_eq(__input, __state, __pos, "function");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = match __parse_identifier(__input, __state, __pos) {
+                                Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                Failed => Matched(__pos, None),
+                            };
+                            match __seq_res {
+                                Matched(__pos, i) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "(");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res =
+                                                        __parse_parameters(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, p) => {
+                                                            let __seq_res = slice_eq(
+                                                                __input, __state, __pos, ")",
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res = __parse___(
+                                                                        __input, __state, __pos,
+                                                                    );
+                                                                    match __seq_res {
+                                                                        Matched(__pos, _) => {
+                                                                            let __seq_res =
+                                                                                slice_eq(
+                                                                                    __input,
+                                                                                    __state, __pos,
+                                                                                    "{",
+                                                                                );
+                                                                            match __seq_res {
+                                                                                Matched(
+                                                                                    __pos,
+                                                                                    _,
+                                                                                ) => {
+                                                                                    let __seq_res = __parse_inner_block ( __input , __state , __pos ) ;
+                                                                                    match __seq_res
+                                                                                    {
+                                                                                        Matched(
+                                                                                            __pos,
+                                                                                            ib,
+                                                                                        ) => {
+                                                                                            let __seq_res = slice_eq ( __input , __state , __pos , "}" ) ;
+                                                                                            match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {
+        let (parameters, rest) = p;
+        ast::Function {id: i, parameters: parameters, rest: rest, body: ib, source: match_str.to_string()}
+     } } ) } Failed => Failed , }
+                                                                                        }
+                                                                                        Failed => {
+                                                                                            Failed
+                                                                                        }
+                                                                                    }
                                                                                 }
+                                                                                Failed => Failed,
                                                                             }
-                                                                            Failed
-                                                                            =>
-                                                                            Failed,
                                                                         }
+                                                                        Failed => Failed,
                                                                     }
                                                                 }
-                                                                Failed =>
-                                                                Failed,
+                                                                Failed => Failed,
                                                             }
                                                         }
+                                                        Failed => Failed,
                                                     }
-                                                    Failed => Failed,
                                                 }
+                                                Failed => Failed,
                                             }
                                         }
                                         Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
-                            Failed => Failed,
                         }
+                        Failed => Failed,
                     }
                 }
                 Failed => Failed,
@@ -2356,80 +5338,99 @@ fn parse_subscripted<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_called<'input>(input: &'input str, state: &mut ParseState,
-                        pos: usize) -> RuleResult<ast::ExpressionList> {
+
+fn __parse_function_declaration<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Declaration> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res = parse___(input, state, pos);
-            match seq_res {
-                Matched(pos, _) => {
-                    {
-                        let seq_res = slice_eq(input, state, pos, "(");
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res = parse___(input, state, pos);
-                                    match seq_res {
-                                        Matched(pos, _) => {
-                                            {
-                                                let seq_res =
-                                                    parse_expression_list(input,
-                                                                          state,
-                                                                          pos);
-                                                match seq_res {
-                                                    Matched(pos, el) => {
-                                                        {
-                                                            let seq_res =
-                                                                parse___(input,
-                                                                         state,
-                                                                         pos);
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        _) =>
-                                                                {
-                                                                    {
-                                                                        let seq_res =
-                                                                            slice_eq(input,
-                                                                                     state,
-                                                                                     pos,
-                                                                                     ")");
-                                                                        match seq_res
-                                                                            {
-                                                                            Matched(pos,
-                                                                                    _)
-                                                                            =>
-                                                                            {
-                                                                                {
-                                                                                    let match_str =
-                                                                                        &input[start_pos..pos];
-                                                                                    Matched(pos,
-                                                                                            {
-                                                                                                el
-                                                                                            })
+            let __seq_res = slice_eq(__input, __state, __pos, "function");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse_identifier(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, i) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "(");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res =
+                                                        __parse_parameters(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, p) => {
+                                                            let __seq_res = slice_eq(
+                                                                __input, __state, __pos, ")",
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    let __seq_res = __parse___(
+                                                                        __input, __state, __pos,
+                                                                    );
+                                                                    match __seq_res {
+                                                                        Matched(__pos, _) => {
+                                                                            let __seq_res =
+                                                                                slice_eq(
+                                                                                    __input,
+                                                                                    __state, __pos,
+                                                                                    "{",
+                                                                                );
+                                                                            match __seq_res {
+                                                                                Matched(
+                                                                                    __pos,
+                                                                                    _,
+                                                                                ) => {
+                                                                                    let __seq_res = __parse_inner_block ( __input , __state , __pos ) ;
+                                                                                    match __seq_res
+                                                                                    {
+                                                                                        Matched(
+                                                                                            __pos,
+                                                                                            ib,
+                                                                                        ) => {
+                                                                                            let __seq_res = slice_eq ( __input , __state , __pos , "}" ) ;
+                                                                                            match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {
+        let (parameters, rest) = p;
+        ast::Declaration::Function(
+            i.clone(),
+            ast::Function {id: Some(i), parameters: parameters, rest: rest, body: ib, source: match_str.to_string()}
+        )
+     } } ) } Failed => Failed , }
+                                                                                        }
+                                                                                        Failed => {
+                                                                                            Failed
+                                                                                        }
+                                                                                    }
                                                                                 }
+                                                                                Failed => Failed,
                                                                             }
-                                                                            Failed
-                                                                            =>
-                                                                            Failed,
                                                                         }
+                                                                        Failed => Failed,
                                                                     }
                                                                 }
-                                                                Failed =>
-                                                                Failed,
+                                                                Failed => Failed,
                                                             }
                                                         }
+                                                        Failed => Failed,
                                                     }
-                                                    Failed => Failed,
                                                 }
+                                                Failed => Failed,
                                             }
                                         }
                                         Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
-                            Failed => Failed,
                         }
+                        Failed => Failed,
                     }
                 }
                 Failed => Failed,
@@ -2437,558 +5438,346 @@ fn parse_called<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_atomic_expression<'input>(input: &'input str, state: &mut ParseState,
-                                   pos: usize)
- -> RuleResult<ast::Expression> {
+
+fn __parse_inner_block<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::InnerBlock> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
-            {
-                let start_pos = pos;
-                {
-                    let seq_res = slice_eq(input, state, pos, "this");
-                    match seq_res {
-                        Matched(pos, _) => {
-                            {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { ast::Expression::This })
-                            }
-                        }
-                        Failed => Failed,
-                    }
-                }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
-            Failed => {
-                let choice_res =
-                    {
-                        let start_pos = pos;
-                        {
-                            let seq_res = parse_literal(input, state, pos);
-                            match seq_res {
-                                Matched(pos, v) => {
-                                    {
-                                        let match_str =
-                                            &input[start_pos..pos];
-                                        Matched(pos,
-                                                {
-                                                    ast::Expression::Literal(v)
-                                                })
-                                    }
+        let __start_pos = __pos;
+        {
+            let __seq_res = __parse___(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = __parse_statement(__input, __state, __pos);
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
                                 }
-                                Failed => Failed,
                             }
                         }
+                        Matched(__repeat_pos, __repeat_value)
                     };
-                match choice_res {
-                    Matched(pos, value) => Matched(pos, value),
-                    Failed => {
-                        let choice_res =
-                            {
-                                let start_pos = pos;
-                                {
-                                    let seq_res =
-                                        parse_function_literal(input, state,
-                                                               pos);
-                                    match seq_res {
-                                        Matched(pos, f) => {
-                                            {
-                                                let match_str =
-                                                    &input[start_pos..pos];
-                                                Matched(pos,
-                                                        {
-                                                            ast::Expression::Function(f)
-                                                        })
-                                            }
-                                        }
-                                        Failed => Failed,
-                                    }
-                                }
-                            };
-                        match choice_res {
-                            Matched(pos, value) => Matched(pos, value),
-                            Failed => {
-                                let choice_res =
-                                    {
-                                        let start_pos = pos;
-                                        {
-                                            let seq_res =
-                                                parse_identifier(input, state,
-                                                                 pos);
-                                            match seq_res {
-                                                Matched(pos, i) => {
-                                                    {
-                                                        let match_str =
-                                                            &input[start_pos..pos];
-                                                        Matched(pos,
-                                                                {
-                                                                    ast::Expression::Access(ast::Access::Identifier(i))
-                                                                })
-                                                    }
-                                                }
-                                                Failed => Failed,
-                                            }
+                    match __seq_res {
+                        Matched(__pos, s) => {
+                            let __seq_res = __parse___(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = match __parse_return(__input, __state, __pos) {
+                                        Matched(__newpos, __value) => {
+                                            Matched(__newpos, Some(__value))
                                         }
+                                        Failed => Matched(__pos, None),
                                     };
-                                match choice_res {
-                                    Matched(pos, value) =>
-                                    Matched(pos, value),
-                                    Failed => {
-                                        let start_pos = pos;
-                                        {
-                                            let seq_res =
-                                                slice_eq(input, state, pos,
-                                                         "(");
-                                            match seq_res {
-                                                Matched(pos, _) => {
+                                    match __seq_res {
+                                        Matched(__pos, r) => {
+                                            let __seq_res = __parse___(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, _) => Matched(__pos, {
+                                                    let match_str = &__input[__start_pos..__pos];
+                                                    let start_pos = __start_pos;
+                                                    let pos = __pos;
                                                     {
-                                                        let seq_res =
-                                                            parse___(input,
-                                                                     state,
-                                                                     pos);
-                                                        match seq_res {
-                                                            Matched(pos, _) =>
-                                                            {
-                                                                {
-                                                                    let seq_res =
-                                                                        parse_expression(input,
-                                                                                         state,
-                                                                                         pos);
-                                                                    match seq_res
-                                                                        {
-                                                                        Matched(pos,
-                                                                                e)
-                                                                        => {
-                                                                            {
-                                                                                let seq_res =
-                                                                                    parse___(input,
-                                                                                             state,
-                                                                                             pos);
-                                                                                match seq_res
-                                                                                    {
-                                                                                    Matched(pos,
-                                                                                            _)
-                                                                                    =>
-                                                                                    {
-                                                                                        {
-                                                                                            let seq_res =
-                                                                                                slice_eq(input,
-                                                                                                         state,
-                                                                                                         pos,
-                                                                                                         ")");
-                                                                                            match seq_res
-                                                                                                {
-                                                                                                Matched(pos,
-                                                                                                        _)
-                                                                                                =>
-                                                                                                {
-                                                                                                    {
-                                                                                                        let match_str =
-                                                                                                            &input[start_pos..pos];
-                                                                                                        Matched(pos,
-                                                                                                                {
-                                                                                                                    e
-                                                                                                                })
-                                                                                                    }
-                                                                                                }
-                                                                                                Failed
-                                                                                                =>
-                                                                                                Failed,
-                                                                                            }
-                                                                                        }
-                                                                                    }
-                                                                                    Failed
-                                                                                    =>
-                                                                                    Failed,
-                                                                                }
-                                                                            }
-                                                                        }
-                                                                        Failed
-                                                                        =>
-                                                                        Failed,
-                                                                    }
-                                                                }
-                                                            }
-                                                            Failed => Failed,
+                                                        ast::InnerBlock {
+                                                            block: s,
+                                                            return_exp: r,
                                                         }
                                                     }
-                                                }
+                                                }),
                                                 Failed => Failed,
                                             }
                                         }
+                                        Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
                         }
+                        Failed => Failed,
                     }
                 }
+                Failed => Failed,
             }
         }
     }
 }
-fn parse_expression_list<'input>(input: &'input str, state: &mut ParseState,
-                                 pos: usize)
- -> RuleResult<ast::ExpressionList> {
+
+fn __parse_return<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<Box<ast::Expression>> {
+    #![allow(non_snake_case, unused)]
     {
-        let mut repeat_pos = pos;
-        let mut repeat_value = vec!();
-        loop  {
-            let pos = repeat_pos;
-            let pos =
-                if repeat_value.len() > 0 {
-                    let sep_res = slice_eq(input, state, pos, ",");
-                    match sep_res {
-                        Matched(newpos, _) => { newpos }
-                        Failed => break ,
-                    }
-                } else { pos };
-            let step_res =
-                {
-                    let start_pos = pos;
-                    {
-                        let seq_res = parse___(input, state, pos);
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res =
-                                        parse_expression(input, state, pos);
-                                    match seq_res {
-                                        Matched(pos, e) => {
-                                            {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let match_str =
-                                                                &input[start_pos..pos];
-                                                            Matched(pos,
-                                                                    { e })
-                                                        }
+        let __start_pos = __pos;
+        {
+            let __seq_res = slice_eq(__input, __state, __pos, "return");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse_expression(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, e) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                match slice_eq(__input, __state, __pos, ";") {
+                                                    Matched(__newpos, _) => Matched(__newpos, ()),
+                                                    Failed => Matched(__pos, ()),
+                                                };
+                                            match __seq_res {
+                                                Matched(__pos, _) => Matched(__pos, {
+                                                    let match_str = &__input[__start_pos..__pos];
+                                                    let start_pos = __start_pos;
+                                                    let pos = __pos;
+                                                    {
+                                                        Box::new(e)
                                                     }
-                                                    Failed => Failed,
-                                                }
+                                                }),
+                                                Failed => Failed,
                                             }
                                         }
                                         Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
-                            Failed => Failed,
                         }
+                        Failed => Failed,
                     }
-                };
-            match step_res {
-                Matched(newpos, value) => {
-                    repeat_pos = newpos;
-                    repeat_value.push(value);
                 }
-                Failed => { break ; }
+                Failed => Failed,
             }
         }
-        Matched(repeat_pos, repeat_value)
     }
 }
-fn parse_literal<'input>(input: &'input str, state: &mut ParseState,
-                         pos: usize) -> RuleResult<interpret::Value> {
+
+fn __parse_parameters<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<(Vec<ast::Parameter>, Option<ast::Identifier>)> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
+        let __choice_res = {
+            let __start_pos = __pos;
             {
-                let start_pos = pos;
-                {
-                    let seq_res = parse_number(input, state, pos);
-                    match seq_res {
-                        Matched(pos, n) => {
-                            {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { interpret::Value::Number(n) })
-                            }
+                let __seq_res = __parse___(__input, __state, __pos);
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = __parse_rest_parameter(__input, __state, __pos);
+                        match __seq_res {
+                            Matched(__pos, r) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    (vec![], Some(r))
+                                }
+                            }),
+                            Failed => Failed,
                         }
-                        Failed => Failed,
                     }
+                    Failed => Failed,
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let choice_res =
-                    {
-                        let start_pos = pos;
-                        {
-                            let seq_res = parse_boolean(input, state, pos);
-                            match seq_res {
-                                Matched(pos, b) => {
-                                    {
-                                        let match_str =
-                                            &input[start_pos..pos];
-                                        Matched(pos,
-                                                {
-                                                    interpret::Value::Boolean(b)
-                                                })
-                                    }
-                                }
-                                Failed => Failed,
-                            }
-                        }
-                    };
-                match choice_res {
-                    Matched(pos, value) => Matched(pos, value),
-                    Failed => {
-                        let choice_res =
-                            {
-                                let start_pos = pos;
-                                {
-                                    let seq_res =
-                                        parse_string(input, state, pos);
-                                    match seq_res {
-                                        Matched(pos, s) => {
-                                            {
-                                                let match_str =
-                                                    &input[start_pos..pos];
-                                                Matched(pos,
-                                                        {
-                                                            interpret::Value::String(s)
-                                                        })
-                                            }
-                                        }
-                                        Failed => Failed,
-                                    }
+                let __start_pos = __pos;
+                {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __pos = if __repeat_value.len() > 0 {
+                                let __sep_res = slice_eq(__input, __state, __pos, ",");
+                                match __sep_res {
+                                    Matched(__newpos, _) => __newpos,
+                                    Failed => break,
                                 }
+                            } else {
+                                __pos
                             };
-                        match choice_res {
-                            Matched(pos, value) => Matched(pos, value),
-                            Failed => {
-                                let choice_res =
-                                    {
-                                        let start_pos = pos;
-                                        {
-                                            let seq_res =
-                                                slice_eq(input, state, pos,
-                                                         "null");
-                                            match seq_res {
-                                                Matched(pos, _) => {
-                                                    {
-                                                        let match_str =
-                                                            &input[start_pos..pos];
-                                                        Matched(pos,
-                                                                {
-                                                                    interpret::Value::Object(interpret::Object::Null)
-                                                                })
+                            let __step_res = {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res =
+                                                __parse_parameter(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, p) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => Matched(__pos, {
+                                                            let match_str =
+                                                                &__input[__start_pos..__pos];
+                                                            let start_pos = __start_pos;
+                                                            let pos = __pos;
+                                                            {
+                                                                p
+                                                            }
+                                                        }),
+                                                        Failed => Failed,
                                                     }
                                                 }
                                                 Failed => Failed,
                                             }
                                         }
-                                    };
-                                match choice_res {
-                                    Matched(pos, value) =>
-                                    Matched(pos, value),
-                                    Failed => {
-                                        let start_pos = pos;
-                                        {
-                                            let seq_res =
-                                                slice_eq(input, state, pos,
-                                                         "undefined");
-                                            match seq_res {
-                                                Matched(pos, _) => {
-                                                    {
-                                                        let match_str =
-                                                            &input[start_pos..pos];
-                                                        Matched(pos,
-                                                                {
-                                                                    interpret::Value::Undefined
-                                                                })
+                                        Failed => Failed,
+                                    }
+                                }
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        Matched(__repeat_pos, __repeat_value)
+                    };
+                    match __seq_res {
+                        Matched(__pos, ps) => {
+                            let __seq_res = match {
+                                let __start_pos = __pos;
+                                {
+                                    let __seq_res = slice_eq(__input, __state, __pos, ",");
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = __parse___(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res = __parse_rest_parameter(
+                                                        __input, __state, __pos,
+                                                    );
+                                                    match __seq_res {
+                                                        Matched(__pos, r) => Matched(__pos, {
+                                                            let match_str =
+                                                                &__input[__start_pos..__pos];
+                                                            let start_pos = __start_pos;
+                                                            let pos = __pos;
+                                                            {
+                                                                r
+                                                            }
+                                                        }),
+                                                        Failed => Failed,
                                                     }
                                                 }
                                                 Failed => Failed,
                                             }
                                         }
+                                        Failed => Failed,
                                     }
                                 }
+                            } {
+                                Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                                Failed => Matched(__pos, None),
+                            };
+                            match __seq_res {
+                                Matched(__pos, r) => Matched(__pos, {
+                                    let match_str = &__input[__start_pos..__pos];
+                                    let start_pos = __start_pos;
+                                    let pos = __pos;
+                                    {
+                                        (ps, r)
+                                    }
+                                }),
+                                Failed => Failed,
                             }
                         }
+                        Failed => Failed,
                     }
                 }
             }
         }
     }
 }
-fn parse_function_literal<'input>(input: &'input str, state: &mut ParseState,
-                                  pos: usize) -> RuleResult<ast::Function> {
+
+fn __parse_parameter<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Parameter> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res = slice_eq(input, state, pos, "function");
-            match seq_res {
-                Matched(pos, _) => {
-                    {
-                        let seq_res = parse___(input, state, pos);
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res =
-                                        match parse_identifier(input, state,
-                                                               pos) {
-                                            Matched(newpos, value) => {
-                                                Matched(newpos, Some(value))
-                                            }
-                                            Failed => { Matched(pos, None) }
-                                        };
-                                    match seq_res {
-                                        Matched(pos, i) => {
-                                            {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let seq_res =
-                                                                slice_eq(input,
-                                                                         state,
-                                                                         pos,
-                                                                         "(");
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        _) =>
-                                                                {
-                                                                    {
-                                                                        let seq_res =
-                                                                            parse_parameters(input,
-                                                                                             state,
-                                                                                             pos);
-                                                                        match seq_res
-                                                                            {
-                                                                            Matched(pos,
-                                                                                    p)
-                                                                            =>
-                                                                            {
-                                                                                {
-                                                                                    let seq_res =
-                                                                                        slice_eq(input,
-                                                                                                 state,
-                                                                                                 pos,
-                                                                                                 ")");
-                                                                                    match seq_res
-                                                                                        {
-                                                                                        Matched(pos,
-                                                                                                _)
-                                                                                        =>
-                                                                                        {
-                                                                                            {
-                                                                                                let seq_res =
-                                                                                                    parse___(input,
-                                                                                                             state,
-                                                                                                             pos);
-                                                                                                match seq_res
-                                                                                                    {
-                                                                                                    Matched(pos,
-                                                                                                            _)
-                                                                                                    =>
-                                                                                                    {
-                                                                                                        {
-                                                                                                            let seq_res =
-                                                                                                                slice_eq(input,
-                                                                                                                         state,
-                                                                                                                         pos,
-                                                                                                                         "{");
-                                                                                                            match seq_res
-                                                                                                                {
-                                                                                                                Matched(pos,
-                                                                                                                        _)
-                                                                                                                =>
-                                                                                                                {
-                                                                                                                    {
-                                                                                                                        let seq_res =
-                                                                                                                            parse_inner_block(input,
-                                                                                                                                              state,
-                                                                                                                                              pos);
-                                                                                                                        match seq_res
-                                                                                                                            {
-                                                                                                                            Matched(pos,
-                                                                                                                                    ib)
-                                                                                                                            =>
-                                                                                                                            {
-                                                                                                                                {
-                                                                                                                                    let seq_res =
-                                                                                                                                        slice_eq(input,
-                                                                                                                                                 state,
-                                                                                                                                                 pos,
-                                                                                                                                                 "}");
-                                                                                                                                    match seq_res
-                                                                                                                                        {
-                                                                                                                                        Matched(pos,
-                                                                                                                                                _)
-                                                                                                                                        =>
-                                                                                                                                        {
-                                                                                                                                            {
-                                                                                                                                                let match_str =
-                                                                                                                                                    &input[start_pos..pos];
-                                                                                                                                                Matched(pos,
-                                                                                                                                                        {
-                                                                                                                                                            ast::Function{id:
-                                                                                                                                                                              i,
-                                                                                                                                                                          parameters:
-                                                                                                                                                                              p,
-                                                                                                                                                                          body:
-                                                                                                                                                                              ib,
-                                                                                                                                                                          source:
-                                                                                                                                                                              match_str.to_string(),}
-                                                                                                                                                        })
-                                                                                                                                            }
-                                                                                                                                        }
-                                                                                                                                        Failed
-                                                                                                                                        =>
-                                                                                                                                        Failed,
-                                                                                                                                    }
-                                                                                                                                }
-                                                                                                                            }
-                                                                                                                            Failed
-                                                                                                                            =>
-                                                                                                                            Failed,
-                                                                                                                        }
-                                                                                                                    }
-                                                                                                                }
-                                                                                                                Failed
-                                                                                                                =>
-                                                                                                                Failed,
-                                                                                                            }
-                                                                                                        }
-                                                                                                    }
-                                                                                                    Failed
-                                                                                                    =>
-                                                                                                    Failed,
-                                                                                                }
-                                                                                            }
-                                                                                        }
-                                                                                        Failed
-                                                                                        =>
-                                                                                        Failed,
-                                                                                    }
-                                                                                }
-                                                                            }
-                                                                            Failed
-                                                                            =>
-                                                                            Failed,
-                                                                        }
-                                                                    }
-                                                                }
-                                                                Failed =>
-                                                                Failed,
+            let __seq_res = __parse_identifier(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, id) => {
+                    let __seq_res = match {
+                        let __start_pos = __pos;
+                        {
+                            let __seq_res = __parse___(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = slice_eq(__input, __state, __pos, "=");
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = __parse___(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res =
+                                                        __parse_expression(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, e) => Matched(__pos, {
+                                                            let match_str =
+                                                                &__input[__start_pos..__pos];
+                                                            let start_pos = __start_pos;
+                                                            let pos = __pos;
+                                                            {
+                                                                e
                                                             }
-                                                        }
+                                                        }),
+                                                        Failed => Failed,
                                                     }
-                                                    Failed => Failed,
                                                 }
+                                                Failed => Failed,
                                             }
                                         }
                                         Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
-                            Failed => Failed,
                         }
+                    } {
+                        Matched(__newpos, __value) => Matched(__newpos, Some(__value)),
+                        Failed => Matched(__pos, None),
+                    };
+                    match __seq_res {
+                        Matched(__pos, d) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                ast::Parameter { id: id, default: d }
+                            }
+                        }),
+                        Failed => Failed,
                     }
                 }
                 Failed => Failed,
@@ -2996,177 +5785,42 @@ fn parse_function_literal<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_function_declaration<'input>(input: &'input str,
-                                      state: &mut ParseState, pos: usize)
- -> RuleResult<ast::Declaration> {
+
+fn __parse_rest_parameter<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Identifier> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res = slice_eq(input, state, pos, "function");
-            match seq_res {
-                Matched(pos, _) => {
-                    {
-                        let seq_res = parse___(input, state, pos);
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res =
-                                        parse_identifier(input, state, pos);
-                                    match seq_res {
-                                        Matched(pos, i) => {
+            let __seq_res = slice_eq(__input, __state, __pos, "...");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = __parse_identifier(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, id) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => Matched(__pos, {
+                                            let match_str = &__input[__start_pos..__pos];
+                                            let start_pos = __start_pos;
+                                            let pos = __pos;
                                             {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let seq_res =
-                                                                slice_eq(input,
-                                                                         state,
-                                                                         pos,
-                                                                         "(");
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        _) =>
-                                                                {
-                                                                    {
-                                                                        let seq_res =
-                                                                            parse_parameters(input,
-                                                                                             state,
-                                                                                             pos);
-                                                                        match seq_res
-                                                                            {
-                                                                            Matched(pos,
-                                                                                    p)
-                                                                            =>
-                                                                            {
-                                                                                {
-                                                                                    let seq_res =
-                                                                                        slice_eq(input,
-                                                                                                 state,
-                                                                                                 pos,
-                                                                                                 ")");
-                                                                                    match seq_res
-                                                                                        {
-                                                                                        Matched(pos,
-                                                                                                _)
-                                                                                        =>
-                                                                                        {
-                                                                                            {
-                                                                                                let seq_res =
-                                                                                                    parse___(input,
-                                                                                                             state,
-                                                                                                             pos);
-                                                                                                match seq_res
-                                                                                                    {
-                                                                                                    Matched(pos,
-                                                                                                            _)
-                                                                                                    =>
-                                                                                                    {
-                                                                                                        {
-                                                                                                            let seq_res =
-                                                                                                                slice_eq(input,
-                                                                                                                         state,
-                                                                                                                         pos,
-                                                                                                                         "{");
-                                                                                                            match seq_res
-                                                                                                                {
-                                                                                                                Matched(pos,
-                                                                                                                        _)
-                                                                                                                =>
-                                                                                                                {
-                                                                                                                    {
-                                                                                                                        let seq_res =
-                                                                                                                            parse_inner_block(input,
-                                                                                                                                              state,
-                                                                                                                                              pos);
-                                                                                                                        match seq_res
-                                                                                                                            {
-                                                                                                                            Matched(pos,
-                                                                                                                                    ib)
-                                                                                                                            =>
-                                                                                                                            {
-                                                                                                                                {
-                                                                                                                                    let seq_res =
-                                                                                                                                        slice_eq(input,
-                                                                                                                                                 state,
-                                                                                                                                                 pos,
-                                                                                                                                                 "}");
-                                                                                                                                    match seq_res
-                                                                                                                                        {
-                                                                                                                                        Matched(pos,
-                                                                                                                                                _)
-                                                                                                                                        =>
-                                                                                                                                        {
-                                                                                                                                            {
-                                                                                                                                                let match_str =
-                                                                                                                                                    &input[start_pos..pos];
-                                                                                                                                                Matched(pos,
-                                                                                                                                                        {
-                                                                                                                                                            ast::Declaration::Function(i.clone(),
-                                                                                                                                                                                       ast::Function{id:
-                                                                                                                                                                                                         Some(i),
-                                                                                                                                                                                                     parameters:
-                                                                                                                                                                                                         p,
-                                                                                                                                                                                                     body:
-                                                                                                                                                                                                         ib,
-                                                                                                                                                                                                     source:
-                                                                                                                                                                                                         match_str.to_string(),})
-                                                                                                                                                        })
-                                                                                                                                            }
-                                                                                                                                        }
-                                                                                                                                        Failed
-                                                                                                                                        =>
-                                                                                                                                        Failed,
-                                                                                                                                    }
-                                                                                                                                }
-                                                                                                                            }
-                                                                                                                            Failed
-                                                                                                                            =>
-                                                                                                                            Failed,
-                                                                                                                        }
-                                                                                                                    }
-                                                                                                                }
-                                                                                                                Failed
-                                                                                                                =>
-                                                                                                                Failed,
-                                                                                                            }
-                                                                                                        }
-                                                                                                    }
-                                                                                                    Failed
-                                                                                                    =>
-                                                                                                    Failed,
-                                                                                                }
-                                                                                            }
-                                                                                        }
-                                                                                        Failed
-                                                                                        =>
-                                                                                        Failed,
-                                                                                    }
-                                                                                }
-                                                                            }
-                                                                            Failed
-                                                                            =>
-                                                                            Failed,
-                                                                        }
-                                                                    }
-                                                                }
-                                                                Failed =>
-                                                                Failed,
-                                                            }
-                                                        }
-                                                    }
-                                                    Failed => Failed,
-                                                }
+                                                id
                                             }
-                                        }
+                                        }),
                                         Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
-                            Failed => Failed,
                         }
+                        Failed => Failed,
                     }
                 }
                 Failed => Failed,
@@ -3174,91 +5828,137 @@ fn parse_function_declaration<'input>(input: &'input str,
         }
     }
 }
-fn parse_inner_block<'input>(input: &'input str, state: &mut ParseState,
-                             pos: usize) -> RuleResult<ast::InnerBlock> {
+
+fn __parse___<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let mut __repeat_pos = __pos;
+        loop {
+            let __pos = __repeat_pos;
+            let __step_res = {
+                let __choice_res = __parse_whitespace(__input, __state, __pos);
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => __parse_newline(__input, __state, __pos),
+                }
+            };
+            match __step_res {
+                Matched(__newpos, __value) => {
+                    __repeat_pos = __newpos;
+                }
+                Failed => {
+                    break;
+                }
+            }
+        }
+        Matched(__repeat_pos, ())
+    }
+}
+
+fn __parse_object<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<Vec<ast::Property>> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __start_pos = __pos;
         {
-            let seq_res = parse___(input, state, pos);
-            match seq_res {
-                Matched(pos, _) => {
-                    {
-                        let seq_res =
-                            {
-                                let mut repeat_pos = pos;
-                                let mut repeat_value = vec!();
-                                loop  {
-                                    let pos = repeat_pos;
-                                    let step_res =
-                                        parse_statement(input, state, pos);
-                                    match step_res {
-                                        Matched(newpos, value) => {
-                                            repeat_pos = newpos;
-                                            repeat_value.push(value);
+            let __seq_res = slice_eq(__input, __state, __pos, "{");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse___(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = {
+                                let mut __repeat_pos = __pos;
+                                let mut __repeat_value = vec![];
+                                loop {
+                                    let __pos = __repeat_pos;
+                                    let __pos = if __repeat_value.len() > 0 {
+                                        let __sep_res = slice_eq(__input, __state, __pos, ",");
+                                        match __sep_res {
+                                            Matched(__newpos, _) => __newpos,
+                                            Failed => break,
                                         }
-                                        Failed => { break ; }
-                                    }
-                                }
-                                Matched(repeat_pos, repeat_value)
-                            };
-                        match seq_res {
-                            Matched(pos, s) => {
-                                {
-                                    let seq_res = parse___(input, state, pos);
-                                    match seq_res {
-                                        Matched(pos, _) => {
-                                            {
-                                                let seq_res =
-                                                    match parse_return(input,
-                                                                       state,
-                                                                       pos) {
-                                                        Matched(newpos, value)
-                                                        => {
-                                                            Matched(newpos,
-                                                                    Some(value))
-                                                        }
-                                                        Failed => {
-                                                            Matched(pos, None)
-                                                        }
-                                                    };
-                                                match seq_res {
-                                                    Matched(pos, r) => {
-                                                        {
-                                                            let seq_res =
-                                                                parse___(input,
-                                                                         state,
-                                                                         pos);
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        _) =>
-                                                                {
-                                                                    {
-                                                                        let match_str =
-                                                                            &input[start_pos..pos];
-                                                                        Matched(pos,
-                                                                                {
-                                                                                    ast::InnerBlock{block:
-                                                                                                        s,
-                                                                                                    return_exp:
-                                                                                                        r,}
-                                                                                })
-                                                                    }
+                                    } else {
+                                        __pos
+                                    };
+                                    let __step_res = {
+                                        let __start_pos = __pos;
+                                        {
+                                            let __seq_res = __parse___(__input, __state, __pos);
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res =
+                                                        __parse_pair(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, p) => {
+                                                            let __seq_res =
+                                                                __parse___(__input, __state, __pos);
+                                                            match __seq_res {
+                                                                Matched(__pos, _) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            p
+                                                                        }
+                                                                    })
                                                                 }
-                                                                Failed =>
-                                                                Failed,
+                                                                Failed => Failed,
                                                             }
                                                         }
+                                                        Failed => Failed,
                                                     }
-                                                    Failed => Failed,
                                                 }
+                                                Failed => Failed,
+                                            }
+                                        }
+                                    };
+                                    match __step_res {
+                                        Matched(__newpos, __value) => {
+                                            __repeat_pos = __newpos;
+                                            __repeat_value.push(__value);
+                                        }
+                                        Failed => {
+                                            break;
+                                        }
+                                    }
+                                }
+                                Matched(__repeat_pos, __repeat_value)
+                            };
+                            match __seq_res {
+                                Matched(__pos, ps) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, "}");
+                                            match __seq_res {
+                                                Matched(__pos, _) => Matched(__pos, {
+                                                    let match_str = &__input[__start_pos..__pos];
+                                                    let start_pos = __start_pos;
+                                                    let pos = __pos;
+                                                    {
+                                                        ps
+                                                    }
+                                                }),
+                                                Failed => Failed,
                                             }
                                         }
                                         Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
-                            Failed => Failed,
                         }
+                        Failed => Failed,
                     }
                 }
                 Failed => Failed,
@@ -3266,69 +5966,104 @@ fn parse_inner_block<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_return<'input>(input: &'input str, state: &mut ParseState,
-                        pos: usize) -> RuleResult<Box<ast::Expression>> {
+
+fn __parse_array<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<Vec<Option<ast::Expression>>> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res = slice_eq(input, state, pos, "return");
-            match seq_res {
-                Matched(pos, _) => {
+            let __seq_res = slice_eq(__input, __state, __pos, "[");
+            match __seq_res {
+                Matched(__pos, _) => {
                     {
-                        let seq_res = parse___(input, state, pos);
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res =
-                                        parse_expression(input, state, pos);
-                                    match seq_res {
-                                        Matched(pos, e) => {
-                                            {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let seq_res =
-                                                                match slice_eq(input,
-                                                                               state,
-                                                                               pos,
-                                                                               ";")
-                                                                    {
-                                                                    Matched(newpos,
-                                                                            value)
-                                                                    => {
-                                                                        Matched(newpos,
-                                                                                Some(value))
-                                                                    }
-                                                                    Failed =>
-                                                                    {
-                                                                        Matched(pos,
-                                                                                None)
-                                                                    }
-                                                                };
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        _) =>
+                        let __seq_res = {
+                            let mut __repeat_pos = __pos;
+                            let mut __repeat_value = vec![];
+                            loop {
+                                let __pos = __repeat_pos;
+                                let __pos = if __repeat_value.len() > 0 {
+                                    let __sep_res = slice_eq(__input, __state, __pos, ",");
+                                    match __sep_res {
+                                        Matched(__newpos, _) => __newpos,
+                                        Failed => break,
+                                    }
+                                } else {
+                                    __pos
+                                };
+                                let __step_res = {
+                                    let __start_pos = __pos;
+                                    {
+                                        let __seq_res = __parse___(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res = match __parse_expression(
+                                                    __input, __state, __pos,
+                                                ) {
+                                                    Matched(__newpos, __value) => {
+                                                        Matched(__newpos, Some(__value))
+                                                    }
+                                                    Failed => Matched(__pos, None),
+                                                };
+                                                match __seq_res {
+                                                    Matched(__pos, e) => {
+                                                        let __seq_res =
+                                                            __parse___(__input, __state, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => Matched(__pos, {
+                                                                let match_str =
+                                                                    &__input[__start_pos..__pos];
+                                                                let start_pos = __start_pos;
+                                                                let pos = __pos;
                                                                 {
-                                                                    {
-                                                                        let match_str =
-                                                                            &input[start_pos..pos];
-                                                                        Matched(pos,
-                                                                                {
-                                                                                    Box::new(e)
-                                                                                })
-                                                                    }
+                                                                    e
                                                                 }
-                                                                Failed =>
-                                                                Failed,
-                                                            }
+                                                            }),
+                                                            Failed => Failed,
                                                         }
                                                     }
                                                     Failed => Failed,
                                                 }
                                             }
+                                            Failed => Failed,
+                                        }
+                                    }
+                                };
+                                match __step_res {
+                                    Matched(__newpos, __value) => {
+                                        __repeat_pos = __newpos;
+                                        __repeat_value.push(__value);
+                                    }
+                                    Failed => {
+                                        break;
+                                    }
+                                }
+                            }
+                            Matched(__repeat_pos, __repeat_value)
+                        };
+                        match __seq_res {
+                            Matched(__pos, es) => {
+                                {
+                                    let __seq_res = slice_eq(__input, __state, __pos, "]");
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            Matched(__pos, {
+                                                let match_str = &__input[__start_pos..__pos];
+                                                let start_pos = __start_pos;
+                                                let pos = __pos;
+                                                {
+                                                    // A trailing comma doesn't add a hole
+                                                    let mut es = es;
+                                                    if let Some(&None) = es.last() {
+                                                        es.pop();
+                                                    }
+
+                                                    es
+                                                }
+                                            })
                                         }
                                         Failed => Failed,
                                     }
@@ -3343,354 +6078,299 @@ fn parse_return<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_parameters<'input>(input: &'input str, state: &mut ParseState,
-                            pos: usize) -> RuleResult<Vec<ast::Identifier>> {
+
+fn __parse_pair<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<ast::Property> {
+    #![allow(non_snake_case, unused)]
     {
-        let mut repeat_pos = pos;
-        let mut repeat_value = vec!();
-        loop  {
-            let pos = repeat_pos;
-            let pos =
-                if repeat_value.len() > 0 {
-                    let sep_res = slice_eq(input, state, pos, ",");
-                    match sep_res {
-                        Matched(newpos, _) => { newpos }
-                        Failed => break ,
-                    }
-                } else { pos };
-            let step_res =
-                {
-                    let start_pos = pos;
-                    {
-                        let seq_res = parse___(input, state, pos);
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res =
-                                        parse_identifier(input, state, pos);
-                                    match seq_res {
-                                        Matched(pos, p) => {
-                                            {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let match_str =
-                                                                &input[start_pos..pos];
-                                                            Matched(pos,
-                                                                    { p })
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = slice_eq(__input, __state, __pos, "get");
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = {
+                            __state.suppress_fail += 1;
+                            let __assert_res = if __input.len() > __pos {
+                                let (__ch, __next) = char_range_at(__input, __pos);
+                                match __ch {
+                                    'a'...'z' | 'A'...'Z' | '_' | '0'...'9' => Matched(__next, ()),
+                                    _ => __state.mark_failure(__pos, "[a-zA-Z_0-9]"),
+                                }
+                            } else {
+                                __state.mark_failure(__pos, "[a-zA-Z_0-9]")
+                            };
+                            __state.suppress_fail -= 1;
+                            match __assert_res {
+                                Failed => Matched(__pos, ()),
+                                Matched(..) => Failed,
+                            }
+                        };
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = __parse___(__input, __state, __pos);
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse_identifier(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, id) => {
+                                                let __seq_res = __parse___(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, _) => {
+                                                        let __seq_res =
+                                                            slice_eq(__input, __state, __pos, "(");
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = __parse___(
+                                                                    __input, __state, __pos,
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = slice_eq(
+                                                                            __input, __state,
+                                                                            __pos, ")",
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res =
+                                                                                    __parse___(
+                                                                                        __input,
+                                                                                        __state,
+                                                                                        __pos,
+                                                                                    );
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        _,
+                                                                                    ) => {
+                                                                                        let __seq_res = slice_eq ( __input , __state , __pos , "{" ) ;
+                                                                                        match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse_inner_block ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , ib ) => { { let __seq_res = slice_eq ( __input , __state , __pos , "}" ) ; match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {
+        ast::Property::Getter(id, ast::Function {id: None, parameters: vec![], rest: None, body: ib, source: match_str.to_string()})
+     } } ) } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , }
+                                                                                    }
+                                                                                    Failed => {
+                                                                                        Failed
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                            Failed => Failed,
+                                                                        }
+                                                                    }
+                                                                    Failed => Failed,
+                                                                }
+                                                            }
+                                                            Failed => Failed,
                                                         }
                                                     }
                                                     Failed => Failed,
                                                 }
                                             }
+                                            Failed => Failed,
                                         }
-                                        Failed => Failed,
                                     }
+                                    Failed => Failed,
                                 }
                             }
                             Failed => Failed,
                         }
                     }
-                };
-            match step_res {
-                Matched(newpos, value) => {
-                    repeat_pos = newpos;
-                    repeat_value.push(value);
+                    Failed => Failed,
                 }
-                Failed => { break ; }
-            }
-        }
-        Matched(repeat_pos, repeat_value)
-    }
-}
-fn parse___<'input>(input: &'input str, state: &mut ParseState, pos: usize)
- -> RuleResult<()> {
-    {
-        let mut repeat_pos = pos;
-        loop  {
-            let pos = repeat_pos;
-            let step_res =
-                {
-                    let choice_res = parse_whitespace(input, state, pos);
-                    match choice_res {
-                        Matched(pos, value) => Matched(pos, value),
-                        Failed => parse_newline(input, state, pos),
-                    }
-                };
-            match step_res {
-                Matched(newpos, value) => { repeat_pos = newpos; }
-                Failed => { break ; }
             }
-        }
-        Matched(repeat_pos, ())
-    }
-}
-fn parse_object<'input>(input: &'input str, state: &mut ParseState,
-                        pos: usize)
- -> RuleResult<Vec<(String, ast::Expression)>> {
-    {
-        let start_pos = pos;
-        {
-            let seq_res = slice_eq(input, state, pos, "{");
-            match seq_res {
-                Matched(pos, _) => {
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = {
+                    let __start_pos = __pos;
                     {
-                        let seq_res = parse___(input, state, pos);
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res =
-                                        {
-                                            let mut repeat_pos = pos;
-                                            let mut repeat_value = vec!();
-                                            loop  {
-                                                let pos = repeat_pos;
-                                                let pos =
-                                                    if repeat_value.len() > 0
-                                                       {
-                                                        let sep_res =
-                                                            slice_eq(input,
-                                                                     state,
-                                                                     pos,
-                                                                     ",");
-                                                        match sep_res {
-                                                            Matched(newpos, _)
-                                                            => {
-                                                                newpos
-                                                            }
-                                                            Failed => break ,
-                                                        }
-                                                    } else { pos };
-                                                let step_res =
-                                                    {
-                                                        let start_pos = pos;
-                                                        {
-                                                            let seq_res =
-                                                                parse___(input,
-                                                                         state,
-                                                                         pos);
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        _) =>
-                                                                {
-                                                                    {
-                                                                        let seq_res =
-                                                                            parse_pair(input,
-                                                                                       state,
-                                                                                       pos);
-                                                                        match seq_res
-                                                                            {
-                                                                            Matched(pos,
-                                                                                    p)
-                                                                            =>
-                                                                            {
-                                                                                {
-                                                                                    let seq_res =
-                                                                                        parse___(input,
-                                                                                                 state,
-                                                                                                 pos);
-                                                                                    match seq_res
-                                                                                        {
-                                                                                        Matched(pos,
-                                                                                                _)
-                                                                                        =>
-                                                                                        {
-                                                                                            {
-                                                                                                let match_str =
-                                                                                                    &input[start_pos..pos];
-                                                                                                Matched(pos,
-                                                                                                        {
-                                                                                                            p
-                                                                                                        })
-                                                                                            }
-                                                                                        }
+                        let __seq_res = slice_eq(__input, __state, __pos, "set");
+                        match __seq_res {
+                            Matched(__pos, _) => {
+                                let __seq_res = {
+                                    __state.suppress_fail += 1;
+                                    let __assert_res = if __input.len() > __pos {
+                                        let (__ch, __next) = char_range_at(__input, __pos);
+                                        match __ch {
+                                            'a'...'z' | 'A'...'Z' | '_' | '0'...'9' => {
+                                                Matched(__next, ())
+                                            }
+                                            _ => __state.mark_failure(__pos, "[a-zA-Z_0-9]"),
+                                        }
+                                    } else {
+                                        __state.mark_failure(__pos, "[a-zA-Z_0-9]")
+                                    };
+                                    __state.suppress_fail -= 1;
+                                    match __assert_res {
+                                        Failed => Matched(__pos, ()),
+                                        Matched(..) => Failed,
+                                    }
+                                };
+                                match __seq_res {
+                                    Matched(__pos, _) => {
+                                        let __seq_res = __parse___(__input, __state, __pos);
+                                        match __seq_res {
+                                            Matched(__pos, _) => {
+                                                let __seq_res =
+                                                    __parse_identifier(__input, __state, __pos);
+                                                match __seq_res {
+                                                    Matched(__pos, id) => {
+                                                        let __seq_res =
+                                                            __parse___(__input, __state, __pos);
+                                                        match __seq_res {
+                                                            Matched(__pos, _) => {
+                                                                let __seq_res = slice_eq(
+                                                                    __input, __state, __pos, "(",
+                                                                );
+                                                                match __seq_res {
+                                                                    Matched(__pos, _) => {
+                                                                        let __seq_res = __parse___(
+                                                                            __input, __state, __pos,
+                                                                        );
+                                                                        match __seq_res {
+                                                                            Matched(__pos, _) => {
+                                                                                let __seq_res = __parse_parameter ( __input , __state , __pos ) ;
+                                                                                match __seq_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        p,
+                                                                                    ) => {
+                                                                                        let __seq_res = __parse___ ( __input , __state , __pos ) ;
+                                                                                        match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = slice_eq ( __input , __state , __pos , ")" ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse___ ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = slice_eq ( __input , __state , __pos , "{" ) ; match __seq_res { Matched ( __pos , _ ) => { { let __seq_res = __parse_inner_block ( __input , __state , __pos ) ; match __seq_res { Matched ( __pos , ib ) => { { let __seq_res = slice_eq ( __input , __state , __pos , "}" ) ; match __seq_res { Matched ( __pos , _ ) => { Matched ( __pos , { let match_str = &__input[__start_pos..__pos]; let start_pos = __start_pos; let pos = __pos; {
+        ast::Property::Setter(id, ast::Function {id: None, parameters: vec![p], rest: None, body: ib, source: match_str.to_string()})
+     } } ) } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , } } } Failed => Failed , }
+                                                                                    }
+                                                                                    Failed => {
                                                                                         Failed
-                                                                                        =>
-                                                                                        Failed,
                                                                                     }
                                                                                 }
                                                                             }
-                                                                            Failed
-                                                                            =>
-                                                                            Failed,
+                                                                            Failed => Failed,
                                                                         }
                                                                     }
+                                                                    Failed => Failed,
                                                                 }
-                                                                Failed =>
-                                                                Failed,
-                                                            }
-                                                        }
-                                                    };
-                                                match step_res {
-                                                    Matched(newpos, value) =>
-                                                    {
-                                                        repeat_pos = newpos;
-                                                        repeat_value.push(value);
-                                                    }
-                                                    Failed => { break ; }
-                                                }
-                                            }
-                                            Matched(repeat_pos, repeat_value)
-                                        };
-                                    match seq_res {
-                                        Matched(pos, ps) => {
-                                            {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let seq_res =
-                                                                slice_eq(input,
-                                                                         state,
-                                                                         pos,
-                                                                         "}");
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        _) =>
-                                                                {
-                                                                    {
-                                                                        let match_str =
-                                                                            &input[start_pos..pos];
-                                                                        Matched(pos,
-                                                                                {
-                                                                                    ps
-                                                                                })
-                                                                    }
-                                                                }
-                                                                Failed =>
-                                                                Failed,
                                                             }
+                                                            Failed => Failed,
                                                         }
                                                     }
                                                     Failed => Failed,
                                                 }
                                             }
+                                            Failed => Failed,
                                         }
-                                        Failed => Failed,
                                     }
+                                    Failed => Failed,
                                 }
                             }
                             Failed => Failed,
                         }
                     }
-                }
-                Failed => Failed,
-            }
-        }
-    }
-}
-fn parse_pair<'input>(input: &'input str, state: &mut ParseState, pos: usize)
- -> RuleResult<(String, ast::Expression)> {
-    {
-        let start_pos = pos;
-        {
-            let seq_res = parse_identifier(input, state, pos);
-            match seq_res {
-                Matched(pos, id) => {
-                    {
-                        let seq_res = parse___(input, state, pos);
-                        match seq_res {
-                            Matched(pos, _) => {
-                                {
-                                    let seq_res =
-                                        slice_eq(input, state, pos, ":");
-                                    match seq_res {
-                                        Matched(pos, _) => {
-                                            {
-                                                let seq_res =
-                                                    parse___(input, state,
-                                                             pos);
-                                                match seq_res {
-                                                    Matched(pos, _) => {
-                                                        {
-                                                            let seq_res =
-                                                                parse_expression(input,
-                                                                                 state,
-                                                                                 pos);
-                                                            match seq_res {
-                                                                Matched(pos,
-                                                                        e) =>
-                                                                {
-                                                                    {
-                                                                        let match_str =
-                                                                            &input[start_pos..pos];
-                                                                        Matched(pos,
-                                                                                {
-                                                                                    (id,
-                                                                                     e)
-                                                                                })
-                                                                    }
+                };
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __start_pos = __pos;
+                        {
+                            let __seq_res = __parse_identifier(__input, __state, __pos);
+                            match __seq_res {
+                                Matched(__pos, id) => {
+                                    let __seq_res = __parse___(__input, __state, __pos);
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let __seq_res = slice_eq(__input, __state, __pos, ":");
+                                            match __seq_res {
+                                                Matched(__pos, _) => {
+                                                    let __seq_res =
+                                                        __parse___(__input, __state, __pos);
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => {
+                                                            let __seq_res = __parse_expression(
+                                                                __input, __state, __pos,
+                                                            );
+                                                            match __seq_res {
+                                                                Matched(__pos, e) => {
+                                                                    Matched(__pos, {
+                                                                        let match_str = &__input
+                                                                            [__start_pos..__pos];
+                                                                        let start_pos = __start_pos;
+                                                                        let pos = __pos;
+                                                                        {
+                                                                            ast::Property::Value(
+                                                                                id, e,
+                                                                            )
+                                                                        }
+                                                                    })
                                                                 }
-                                                                Failed =>
-                                                                Failed,
+                                                                Failed => Failed,
                                                             }
                                                         }
+                                                        Failed => Failed,
                                                     }
-                                                    Failed => Failed,
                                                 }
+                                                Failed => Failed,
                                             }
                                         }
                                         Failed => Failed,
                                     }
                                 }
+                                Failed => Failed,
                             }
-                            Failed => Failed,
                         }
                     }
                 }
-                Failed => Failed,
             }
         }
     }
 }
-fn parse_whitespace<'input>(input: &'input str, state: &mut ParseState,
-                            pos: usize) -> RuleResult<()> {
+
+fn __parse_whitespace<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
-            if input.len() > pos {
-                let (ch, next) = char_range_at(input, pos);
-                match ch {
-                    ' ' | '\t' => Matched(next, ()),
-                    _ => state.mark_failure(pos, "[ \t]"),
-                }
-            } else { state.mark_failure(pos, "[ \t]") };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+        let __choice_res = if __input.len() > __pos {
+            let (__ch, __next) = char_range_at(__input, __pos);
+            match __ch {
+                ' ' | '\t' => Matched(__next, ()),
+                _ => __state.mark_failure(__pos, "[ \t]"),
+            }
+        } else {
+            __state.mark_failure(__pos, "[ \t]")
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let seq_res = slice_eq(input, state, pos, "//");
-                match seq_res {
-                    Matched(pos, _) => {
-                        {
-                            let mut repeat_pos = pos;
-                            loop  {
-                                let pos = repeat_pos;
-                                let step_res =
-                                    if input.len() > pos {
-                                        let (ch, next) =
-                                            char_range_at(input, pos);
-                                        match ch {
-                                            '\n' | '\r' =>
-                                            state.mark_failure(pos,
-                                                               "[^\n\r]"),
-                                            _ => Matched(next, ()),
-                                        }
-                                    } else {
-                                        state.mark_failure(pos, "[^\n\r]")
-                                    };
-                                match step_res {
-                                    Matched(newpos, value) => {
-                                        repeat_pos = newpos;
-                                    }
-                                    Failed => { break ; }
+                let __seq_res = slice_eq(__input, __state, __pos, "//");
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let mut __repeat_pos = __pos;
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = if __input.len() > __pos {
+                                let (__ch, __next) = char_range_at(__input, __pos);
+                                match __ch {
+                                    '\n' | '\r' => __state.mark_failure(__pos, "[^\n\r]"),
+                                    _ => Matched(__next, ()),
+                                }
+                            } else {
+                                __state.mark_failure(__pos, "[^\n\r]")
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                }
+                                Failed => {
+                                    break;
                                 }
                             }
-                            Matched(repeat_pos, ())
                         }
+                        Matched(__repeat_pos, ())
                     }
                     Failed => Failed,
                 }
@@ -3698,53 +6378,106 @@ fn parse_whitespace<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_newline<'input>(input: &'input str, state: &mut ParseState,
-                         pos: usize) -> RuleResult<()> {
-    if input.len() > pos {
-        let (ch, next) = char_range_at(input, pos);
-        match ch {
-            '\n' | '\r' => Matched(next, ()),
-            _ => state.mark_failure(pos, "[\n\r]"),
+
+fn __parse_newline<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
+    if __input.len() > __pos {
+        let (__ch, __next) = char_range_at(__input, __pos);
+        match __ch {
+            '\n' | '\r' => Matched(__next, ()),
+            _ => __state.mark_failure(__pos, "[\n\r]"),
         }
-    } else { state.mark_failure(pos, "[\n\r]") }
+    } else {
+        __state.mark_failure(__pos, "[\n\r]")
+    }
 }
-fn parse_identifier<'input>(input: &'input str, state: &mut ParseState,
-                            pos: usize) -> RuleResult<String> {
+
+fn __parse_identifier<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<String> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res =
-                {
-                    let mut repeat_pos = pos;
-                    let mut repeat_value = vec!();
-                    loop  {
-                        let pos = repeat_pos;
-                        let step_res =
-                            if input.len() > pos {
-                                let (ch, next) = char_range_at(input, pos);
-                                match ch {
-                                    'a' ...'z' | 'A' ...'Z' | '_' =>
-                                    Matched(next, ()),
-                                    _ => state.mark_failure(pos, "[a-zA-Z_]"),
-                                }
-                            } else { state.mark_failure(pos, "[a-zA-Z_]") };
-                        match step_res {
-                            Matched(newpos, value) => {
-                                repeat_pos = newpos;
-                                repeat_value.push(value);
+            let __seq_res = {
+                __state.suppress_fail += 1;
+                let __assert_res = {
+                    let __seq_res = __parse_keyword(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            __state.suppress_fail += 1;
+                            let __assert_res = if __input.len() > __pos {
+                                let (__ch, __next) = char_range_at(__input, __pos);
+                                match __ch {
+                                    'a'...'z' | 'A'...'Z' | '_' | '0'...'9' => Matched(__next, ()),
+                                    _ => __state.mark_failure(__pos, "[a-zA-Z_0-9]"),
+                                }
+                            } else {
+                                __state.mark_failure(__pos, "[a-zA-Z_0-9]")
+                            };
+                            __state.suppress_fail -= 1;
+                            match __assert_res {
+                                Failed => Matched(__pos, ()),
+                                Matched(..) => Failed,
                             }
-                            Failed => { break ; }
                         }
+                        Failed => Failed,
                     }
-                    if repeat_value.len() >= 1usize {
-                        Matched(repeat_pos, ())
-                    } else { Failed }
                 };
-            match seq_res {
-                Matched(pos, _) => {
-                    {
-                        let match_str = &input[start_pos..pos];
-                        Matched(pos, { match_str.to_string() })
+                __state.suppress_fail -= 1;
+                match __assert_res {
+                    Failed => Matched(__pos, ()),
+                    Matched(..) => Failed,
+                }
+            };
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = {
+                        let mut __repeat_pos = __pos;
+                        let mut __repeat_value = vec![];
+                        loop {
+                            let __pos = __repeat_pos;
+                            let __step_res = if __input.len() > __pos {
+                                let (__ch, __next) = char_range_at(__input, __pos);
+                                match __ch {
+                                    'a'...'z' | 'A'...'Z' | '_' => Matched(__next, ()),
+                                    _ => __state.mark_failure(__pos, "[a-zA-Z_]"),
+                                }
+                            } else {
+                                __state.mark_failure(__pos, "[a-zA-Z_]")
+                            };
+                            match __step_res {
+                                Matched(__newpos, __value) => {
+                                    __repeat_pos = __newpos;
+                                    __repeat_value.push(__value);
+                                }
+                                Failed => {
+                                    break;
+                                }
+                            }
+                        }
+                        if __repeat_value.len() >= 1 {
+                            Matched(__repeat_pos, ())
+                        } else {
+                            Failed
+                        }
+                    };
+                    match __seq_res {
+                        Matched(__pos, _) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                match_str.to_string()
+                            }
+                        }),
+                        Failed => Failed,
                     }
                 }
                 Failed => Failed,
@@ -3752,231 +6485,257 @@ fn parse_identifier<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_number<'input>(input: &'input str, state: &mut ParseState,
-                        pos: usize) -> RuleResult<f64> {
+
+fn __parse_keyword<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
-            {
-                let start_pos = pos;
-                {
-                    let seq_res =
-                        {
-                            let choice_res = parse_frac(input, state, pos);
-                            match choice_res {
-                                Matched(pos, value) => Matched(pos, value),
-                                Failed => {
-                                    let seq_res =
-                                        {
-                                            let mut repeat_pos = pos;
-                                            let mut repeat_value = vec!();
-                                            loop  {
-                                                let pos = repeat_pos;
-                                                let step_res =
-                                                    if input.len() > pos {
-                                                        let (ch, next) =
-                                                            char_range_at(input,
-                                                                          pos);
-                                                        match ch {
-                                                            '0' ...'9' =>
-                                                            Matched(next, ()),
-                                                            _ =>
-                                                            state.mark_failure(pos,
-                                                                               "[0-9]"),
+        let __choice_res = slice_eq(__input, __state, __pos, "break");
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
+            Failed => {
+                let __choice_res = slice_eq(__input, __state, __pos, "case");
+                match __choice_res {
+                    Matched(__pos, __value) => Matched(__pos, __value),
+                    Failed => {
+                        let __choice_res = slice_eq(__input, __state, __pos, "catch");
+                        match __choice_res {
+                            Matched(__pos, __value) => Matched(__pos, __value),
+                            Failed => {
+                                let __choice_res = slice_eq(__input, __state, __pos, "const");
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => {
+                                        let __choice_res =
+                                            slice_eq(__input, __state, __pos, "continue");
+                                        match __choice_res {
+                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                            Failed => {
+                                                let __choice_res =
+                                                    slice_eq(__input, __state, __pos, "default");
+                                                match __choice_res {
+                                                    Matched(__pos, __value) => {
+                                                        Matched(__pos, __value)
+                                                    }
+                                                    Failed => {
+                                                        let __choice_res = slice_eq(
+                                                            __input, __state, __pos, "delete",
+                                                        );
+                                                        match __choice_res {
+                                                            Matched(__pos, __value) => {
+                                                                Matched(__pos, __value)
+                                                            }
+                                                            Failed => {
+                                                                let __choice_res = slice_eq(
+                                                                    __input, __state, __pos, "do",
+                                                                );
+                                                                match __choice_res {
+                                                                    Matched(__pos, __value) => {
+                                                                        Matched(__pos, __value)
+                                                                    }
+                                                                    Failed => {
+                                                                        let __choice_res = slice_eq(
+                                                                            __input, __state,
+                                                                            __pos, "else",
+                                                                        );
+                                                                        match __choice_res {
+                                                                            Matched(
+                                                                                __pos,
+                                                                                __value,
+                                                                            ) => Matched(
+                                                                                __pos, __value,
+                                                                            ),
+                                                                            Failed => {
+                                                                                let __choice_res =
+                                                                                    slice_eq(
+                                                                                        __input,
+                                                                                        __state,
+                                                                                        __pos,
+                                                                                        "finally",
+                                                                                    );
+                                                                                match __choice_res {
+                                                                                    Matched(
+                                                                                        __pos,
+                                                                                        __value,
+                                                                                    ) => Matched(
+                                                                                        __pos,
+                                                                                        __value,
+                                                                                    ),
+                                                                                    Failed => {
+                                                                                        let __choice_res = slice_eq ( __input , __state , __pos , "for" ) ;
+                                                                                        match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "function" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "if" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "in" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "instanceof" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "let" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "new" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "return" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "switch" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "this" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "throw" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "try" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "typeof" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "var" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "void" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "while" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "with" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "null" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => { let __choice_res = slice_eq ( __input , __state , __pos , "true" ) ; match __choice_res { Matched ( __pos , __value ) => Matched ( __pos , __value ) , Failed => slice_eq ( __input , __state , __pos , "false" ) } } } } } } } } } } } } } } } } } } } } } } } } } } } } } } } } } } } } }
+                                                                                    }
+                                                                                }
+                                                                            }
+                                                                        }
+                                                                    }
+                                                                }
+                                                            }
                                                         }
-                                                    } else {
-                                                        state.mark_failure(pos,
-                                                                           "[0-9]")
-                                                    };
-                                                match step_res {
-                                                    Matched(newpos, value) =>
-                                                    {
-                                                        repeat_pos = newpos;
-                                                        repeat_value.push(value);
                                                     }
-                                                    Failed => { break ; }
                                                 }
                                             }
-                                            if repeat_value.len() >= 1usize {
-                                                Matched(repeat_pos, ())
-                                            } else { Failed }
-                                        };
-                                    match seq_res {
-                                        Matched(pos, _) => {
-                                            parse_frac_opt(input, state, pos)
                                         }
-                                        Failed => Failed,
                                     }
                                 }
                             }
-                        };
-                    match seq_res {
-                        Matched(pos, _) => {
-                            {
-                                let seq_res =
-                                    match {
-                                              let seq_res =
-                                                  {
-                                                      let choice_res =
-                                                          slice_eq(input,
-                                                                   state, pos,
-                                                                   "e");
-                                                      match choice_res {
-                                                          Matched(pos, value)
-                                                          =>
-                                                          Matched(pos, value),
-                                                          Failed =>
-                                                          slice_eq(input,
-                                                                   state, pos,
-                                                                   "E"),
-                                                      }
-                                                  };
-                                              match seq_res {
-                                                  Matched(pos, _) => {
-                                                      {
-                                                          let seq_res =
-                                                              match {
-                                                                        let choice_res =
-                                                                            slice_eq(input,
-                                                                                     state,
-                                                                                     pos,
-                                                                                     "+");
-                                                                        match choice_res
-                                                                            {
-                                                                            Matched(pos,
-                                                                                    value)
-                                                                            =>
-                                                                            Matched(pos,
-                                                                                    value),
-                                                                            Failed
-                                                                            =>
-                                                                            slice_eq(input,
-                                                                                     state,
-                                                                                     pos,
-                                                                                     "-"),
-                                                                        }
-                                                                    } {
-                                                                  Matched(newpos,
-                                                                          value)
-                                                                  => {
-                                                                      Matched(newpos,
-                                                                              Some(value))
-                                                                  }
-                                                                  Failed => {
-                                                                      Matched(pos,
-                                                                              None)
-                                                                  }
-                                                              };
-                                                          match seq_res {
-                                                              Matched(pos, _)
-                                                              => {
-                                                                  {
-                                                                      let mut repeat_pos =
-                                                                          pos;
-                                                                      let mut repeat_value =
-                                                                          vec!();
-                                                                      loop  {
-                                                                          let pos =
-                                                                              repeat_pos;
-                                                                          let step_res =
-                                                                              if input.len()
-                                                                                     >
-                                                                                     pos
-                                                                                 {
-                                                                                  let (ch,
-                                                                                       next) =
-                                                                                      char_range_at(input,
-                                                                                                    pos);
-                                                                                  match ch
-                                                                                      {
-                                                                                      '0'
-                                                                                      ...'9'
-                                                                                      =>
-                                                                                      Matched(next,
-                                                                                              ()),
-                                                                                      _
-                                                                                      =>
-                                                                                      state.mark_failure(pos,
-                                                                                                         "[0-9]"),
-                                                                                  }
-                                                                              } else {
-                                                                                  state.mark_failure(pos,
-                                                                                                     "[0-9]")
-                                                                              };
-                                                                          match step_res
-                                                                              {
-                                                                              Matched(newpos,
-                                                                                      value)
-                                                                              =>
-                                                                              {
-                                                                                  repeat_pos
-                                                                                      =
-                                                                                      newpos;
-                                                                                  repeat_value.push(value);
-                                                                              }
-                                                                              Failed
-                                                                              =>
-                                                                              {
-                                                                                  break
-                                                                                      ;
-                                                                              }
-                                                                          }
-                                                                      }
-                                                                      if repeat_value.len()
-                                                                             >=
-                                                                             1usize
-                                                                         {
-                                                                          Matched(repeat_pos,
-                                                                                  ())
-                                                                      } else {
-                                                                          Failed
-                                                                      }
-                                                                  }
-                                                              }
-                                                              Failed =>
-                                                              Failed,
-                                                          }
-                                                      }
-                                                  }
-                                                  Failed => Failed,
-                                              }
-                                          } {
-                                        Matched(newpos, value) => {
-                                            Matched(newpos, Some(value))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn __parse_number<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<f64> {
+    #![allow(non_snake_case, unused)]
+    {
+        let __choice_res = {
+            let __start_pos = __pos;
+            {
+                let __seq_res = {
+                    let __choice_res = __parse_frac(__input, __state, __pos);
+                    match __choice_res {
+                        Matched(__pos, __value) => Matched(__pos, __value),
+                        Failed => {
+                            let __seq_res = {
+                                let mut __repeat_pos = __pos;
+                                let mut __repeat_value = vec![];
+                                loop {
+                                    let __pos = __repeat_pos;
+                                    let __step_res = if __input.len() > __pos {
+                                        let (__ch, __next) = char_range_at(__input, __pos);
+                                        match __ch {
+                                            '0'...'9' => Matched(__next, ()),
+                                            _ => __state.mark_failure(__pos, "[0-9]"),
                                         }
-                                        Failed => { Matched(pos, None) }
+                                    } else {
+                                        __state.mark_failure(__pos, "[0-9]")
                                     };
-                                match seq_res {
-                                    Matched(pos, _) => {
-                                        {
-                                            let match_str =
-                                                &input[start_pos..pos];
-                                            Matched(pos,
-                                                    {
-                                                        match_str.parse().unwrap()
-                                                    })
+                                    match __step_res {
+                                        Matched(__newpos, __value) => {
+                                            __repeat_pos = __newpos;
+                                            __repeat_value.push(__value);
+                                        }
+                                        Failed => {
+                                            break;
                                         }
                                     }
-                                    Failed => Failed,
                                 }
+                                if __repeat_value.len() >= 1 {
+                                    Matched(__repeat_pos, ())
+                                } else {
+                                    Failed
+                                }
+                            };
+                            match __seq_res {
+                                Matched(__pos, _) => __parse_frac_opt(__input, __state, __pos),
+                                Failed => Failed,
                             }
                         }
-                        Failed => Failed,
                     }
+                };
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = match {
+                            let __seq_res = {
+                                let __choice_res = slice_eq(__input, __state, __pos, "e");
+                                match __choice_res {
+                                    Matched(__pos, __value) => Matched(__pos, __value),
+                                    Failed => slice_eq(__input, __state, __pos, "E"),
+                                }
+                            };
+                            match __seq_res {
+                                Matched(__pos, _) => {
+                                    let __seq_res = match {
+                                        let __choice_res = slice_eq(__input, __state, __pos, "+");
+                                        match __choice_res {
+                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                            Failed => slice_eq(__input, __state, __pos, "-"),
+                                        }
+                                    } {
+                                        Matched(__newpos, _) => Matched(__newpos, ()),
+                                        Failed => Matched(__pos, ()),
+                                    };
+                                    match __seq_res {
+                                        Matched(__pos, _) => {
+                                            let mut __repeat_pos = __pos;
+                                            let mut __repeat_value = vec![];
+                                            loop {
+                                                let __pos = __repeat_pos;
+                                                let __step_res = if __input.len() > __pos {
+                                                    let (__ch, __next) =
+                                                        char_range_at(__input, __pos);
+                                                    match __ch {
+                                                        '0'...'9' => Matched(__next, ()),
+                                                        _ => __state.mark_failure(__pos, "[0-9]"),
+                                                    }
+                                                } else {
+                                                    __state.mark_failure(__pos, "[0-9]")
+                                                };
+                                                match __step_res {
+                                                    Matched(__newpos, __value) => {
+                                                        __repeat_pos = __newpos;
+                                                        __repeat_value.push(__value);
+                                                    }
+                                                    Failed => {
+                                                        break;
+                                                    }
+                                                }
+                                            }
+                                            if __repeat_value.len() >= 1 {
+                                                Matched(__repeat_pos, ())
+                                            } else {
+                                                Failed
+                                            }
+                                        }
+                                        Failed => Failed,
+                                    }
+                                }
+                                Failed => Failed,
+                            }
+                        } {
+                            Matched(__newpos, _) => Matched(__newpos, ()),
+                            Failed => Matched(__pos, ()),
+                        };
+                        match __seq_res {
+                            Matched(__pos, _) => Matched(__pos, {
+                                let match_str = &__input[__start_pos..__pos];
+                                let start_pos = __start_pos;
+                                let pos = __pos;
+                                {
+                                    match_str.parse().unwrap()
+                                }
+                            }),
+                            Failed => Failed,
+                        }
+                    }
+                    Failed => Failed,
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let start_pos = pos;
+                let __start_pos = __pos;
                 {
-                    let seq_res = slice_eq(input, state, pos, "NaN");
-                    match seq_res {
-                        Matched(pos, _) => {
+                    let __seq_res = slice_eq(__input, __state, __pos, "NaN");
+                    match __seq_res {
+                        Matched(__pos, _) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
                             {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { f64::NAN })
+                                f64::NAN
                             }
-                        }
+                        }),
                         Failed => Failed,
                     }
                 }
@@ -3984,38 +6743,46 @@ fn parse_number<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_boolean<'input>(input: &'input str, state: &mut ParseState,
-                         pos: usize) -> RuleResult<bool> {
+
+fn __parse_boolean<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<bool> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
+        let __choice_res = {
+            let __start_pos = __pos;
             {
-                let start_pos = pos;
-                {
-                    let seq_res = slice_eq(input, state, pos, "true");
-                    match seq_res {
-                        Matched(pos, _) => {
-                            {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { true })
-                            }
+                let __seq_res = slice_eq(__input, __state, __pos, "true");
+                match __seq_res {
+                    Matched(__pos, _) => Matched(__pos, {
+                        let match_str = &__input[__start_pos..__pos];
+                        let start_pos = __start_pos;
+                        let pos = __pos;
+                        {
+                            true
                         }
-                        Failed => Failed,
-                    }
+                    }),
+                    Failed => Failed,
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let start_pos = pos;
+                let __start_pos = __pos;
                 {
-                    let seq_res = slice_eq(input, state, pos, "false");
-                    match seq_res {
-                        Matched(pos, _) => {
+                    let __seq_res = slice_eq(__input, __state, __pos, "false");
+                    match __seq_res {
+                        Matched(__pos, _) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
                             {
-                                let match_str = &input[start_pos..pos];
-                                Matched(pos, { false })
+                                false
                             }
-                        }
+                        }),
                         Failed => Failed,
                     }
                 }
@@ -4023,296 +6790,248 @@ fn parse_boolean<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_frac<'input>(input: &'input str, state: &mut ParseState, pos: usize)
- -> RuleResult<()> {
+
+fn __parse_frac<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
     {
-        let seq_res = slice_eq(input, state, pos, ".");
-        match seq_res {
-            Matched(pos, _) => {
-                {
-                    let mut repeat_pos = pos;
-                    let mut repeat_value = vec!();
-                    loop  {
-                        let pos = repeat_pos;
-                        let step_res =
-                            if input.len() > pos {
-                                let (ch, next) = char_range_at(input, pos);
-                                match ch {
-                                    '0' ...'9' => Matched(next, ()),
-                                    _ => state.mark_failure(pos, "[0-9]"),
-                                }
-                            } else { state.mark_failure(pos, "[0-9]") };
-                        match step_res {
-                            Matched(newpos, value) => {
-                                repeat_pos = newpos;
-                                repeat_value.push(value);
-                            }
-                            Failed => { break ; }
+        let __seq_res = slice_eq(__input, __state, __pos, ".");
+        match __seq_res {
+            Matched(__pos, _) => {
+                let mut __repeat_pos = __pos;
+                let mut __repeat_value = vec![];
+                loop {
+                    let __pos = __repeat_pos;
+                    let __step_res = if __input.len() > __pos {
+                        let (__ch, __next) = char_range_at(__input, __pos);
+                        match __ch {
+                            '0'...'9' => Matched(__next, ()),
+                            _ => __state.mark_failure(__pos, "[0-9]"),
+                        }
+                    } else {
+                        __state.mark_failure(__pos, "[0-9]")
+                    };
+                    match __step_res {
+                        Matched(__newpos, __value) => {
+                            __repeat_pos = __newpos;
+                            __repeat_value.push(__value);
+                        }
+                        Failed => {
+                            break;
                         }
                     }
-                    if repeat_value.len() >= 1usize {
-                        Matched(repeat_pos, ())
-                    } else { Failed }
+                }
+                if __repeat_value.len() >= 1 {
+                    Matched(__repeat_pos, ())
+                } else {
+                    Failed
                 }
             }
             Failed => Failed,
         }
     }
 }
-fn parse_frac_opt<'input>(input: &'input str, state: &mut ParseState,
-                          pos: usize) -> RuleResult<()> {
+
+fn __parse_frac_opt<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<()> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res =
-                match parse_frac(input, state, pos) {
-                    Matched(newpos, value) => { Matched(newpos, Some(value)) }
-                    Failed => { Matched(pos, None) }
-                };
-            match seq_res {
-                Matched(pos, _) => {
+            let __seq_res = match __parse_frac(__input, __state, __pos) {
+                Matched(__newpos, _) => Matched(__newpos, ()),
+                Failed => Matched(__pos, ()),
+            };
+            match __seq_res {
+                Matched(__pos, _) => Matched(__pos, {
+                    let match_str = &__input[__start_pos..__pos];
+                    let start_pos = __start_pos;
+                    let pos = __pos;
                     {
-                        let match_str = &input[start_pos..pos];
-                        Matched(pos, { () })
+                        ()
                     }
-                }
+                }),
                 Failed => Failed,
             }
         }
     }
 }
-fn parse_string<'input>(input: &'input str, state: &mut ParseState,
-                        pos: usize) -> RuleResult<String> {
+
+fn __parse_string<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<String> {
+    #![allow(non_snake_case, unused)]
     {
-        let choice_res =
+        let __choice_res = {
+            let __start_pos = __pos;
             {
-                let start_pos = pos;
-                {
-                    let seq_res = slice_eq(input, state, pos, "\"");
-                    match seq_res {
-                        Matched(pos, _) => {
-                            {
-                                let seq_res =
-                                    {
-                                        let mut repeat_pos = pos;
-                                        let mut repeat_value = vec!();
-                                        loop  {
-                                            let pos = repeat_pos;
-                                            let step_res =
-                                                {
-                                                    let choice_res =
-                                                        parse_escape(input,
-                                                                     state,
-                                                                     pos);
-                                                    match choice_res {
-                                                        Matched(pos, value) =>
-                                                        Matched(pos, value),
-                                                        Failed => {
-                                                            let start_pos =
-                                                                pos;
-                                                            {
-                                                                let seq_res =
-                                                                    if input.len()
-                                                                           >
-                                                                           pos
-                                                                       {
-                                                                        let (ch,
-                                                                             next) =
-                                                                            char_range_at(input,
-                                                                                          pos);
-                                                                        match ch
-                                                                            {
-                                                                            '\"'
-                                                                            =>
-                                                                            state.mark_failure(pos,
-                                                                                               "[^\"]"),
-                                                                            _
-                                                                            =>
-                                                                            Matched(next,
-                                                                                    ()),
-                                                                        }
-                                                                    } else {
-                                                                        state.mark_failure(pos,
-                                                                                           "[^\"]")
-                                                                    };
-                                                                match seq_res
-                                                                    {
-                                                                    Matched(pos,
-                                                                            _)
-                                                                    => {
-                                                                        {
-                                                                            let match_str =
-                                                                                &input[start_pos..pos];
-                                                                            Matched(pos,
-                                                                                    {
-                                                                                        match_str.chars().next().unwrap()
-                                                                                    })
-                                                                        }
-                                                                    }
-                                                                    Failed =>
-                                                                    Failed,
-                                                                }
-                                                            }
-                                                        }
+                let __seq_res = slice_eq(__input, __state, __pos, "\"");
+                match __seq_res {
+                    Matched(__pos, _) => {
+                        let __seq_res = {
+                            let mut __repeat_pos = __pos;
+                            let mut __repeat_value = vec![];
+                            loop {
+                                let __pos = __repeat_pos;
+                                let __step_res = {
+                                    let __choice_res = __parse_escape(__input, __state, __pos);
+                                    match __choice_res {
+                                        Matched(__pos, __value) => Matched(__pos, __value),
+                                        Failed => {
+                                            let __start_pos = __pos;
+                                            {
+                                                let __seq_res = if __input.len() > __pos {
+                                                    let (__ch, __next) =
+                                                        char_range_at(__input, __pos);
+                                                    match __ch {
+                                                        '"' => __state.mark_failure(__pos, "[^\"]"),
+                                                        _ => Matched(__next, ()),
                                                     }
+                                                } else {
+                                                    __state.mark_failure(__pos, "[^\"]")
                                                 };
-                                            match step_res {
-                                                Matched(newpos, value) => {
-                                                    repeat_pos = newpos;
-                                                    repeat_value.push(value);
+                                                match __seq_res {
+                                                    Matched(__pos, _) => Matched(__pos, {
+                                                        let match_str =
+                                                            &__input[__start_pos..__pos];
+                                                        let start_pos = __start_pos;
+                                                        let pos = __pos;
+                                                        {
+                                                            match_str.chars().next().unwrap()
+                                                        }
+                                                    }),
+                                                    Failed => Failed,
                                                 }
-                                                Failed => { break ; }
                                             }
                                         }
-                                        Matched(repeat_pos, repeat_value)
-                                    };
-                                match seq_res {
-                                    Matched(pos, s) => {
+                                    }
+                                };
+                                match __step_res {
+                                    Matched(__newpos, __value) => {
+                                        __repeat_pos = __newpos;
+                                        __repeat_value.push(__value);
+                                    }
+                                    Failed => {
+                                        break;
+                                    }
+                                }
+                            }
+                            Matched(__repeat_pos, __repeat_value)
+                        };
+                        match __seq_res {
+                            Matched(__pos, s) => {
+                                let __seq_res = slice_eq(__input, __state, __pos, "\"");
+                                match __seq_res {
+                                    Matched(__pos, _) => Matched(__pos, {
+                                        let match_str = &__input[__start_pos..__pos];
+                                        let start_pos = __start_pos;
+                                        let pos = __pos;
                                         {
-                                            let seq_res =
-                                                slice_eq(input, state, pos,
-                                                         "\"");
-                                            match seq_res {
-                                                Matched(pos, _) => {
-                                                    {
-                                                        let match_str =
-                                                            &input[start_pos..pos];
-                                                        Matched(pos,
-                                                                {
-                                                                    let mut result =
-                                                                        String::new();
-                                                                    for c in s
-                                                                        {
-                                                                        result.push(c);
-                                                                    }
-                                                                    result
-                                                                })
-                                                    }
-                                                }
-                                                Failed => Failed,
+                                            let mut result = String::new();
+                                            for c in s {
+                                                result.push(c);
                                             }
+                                            result
                                         }
-                                    }
+                                    }),
                                     Failed => Failed,
                                 }
                             }
+                            Failed => Failed,
                         }
-                        Failed => Failed,
                     }
+                    Failed => Failed,
                 }
-            };
-        match choice_res {
-            Matched(pos, value) => Matched(pos, value),
+            }
+        };
+        match __choice_res {
+            Matched(__pos, __value) => Matched(__pos, __value),
             Failed => {
-                let start_pos = pos;
+                let __start_pos = __pos;
                 {
-                    let seq_res = slice_eq(input, state, pos, "\'");
-                    match seq_res {
-                        Matched(pos, _) => {
-                            {
-                                let seq_res =
-                                    {
-                                        let mut repeat_pos = pos;
-                                        let mut repeat_value = vec!();
-                                        loop  {
-                                            let pos = repeat_pos;
-                                            let step_res =
+                    let __seq_res = slice_eq(__input, __state, __pos, "'");
+                    match __seq_res {
+                        Matched(__pos, _) => {
+                            let __seq_res = {
+                                let mut __repeat_pos = __pos;
+                                let mut __repeat_value = vec![];
+                                loop {
+                                    let __pos = __repeat_pos;
+                                    let __step_res = {
+                                        let __choice_res = __parse_escape(__input, __state, __pos);
+                                        match __choice_res {
+                                            Matched(__pos, __value) => Matched(__pos, __value),
+                                            Failed => {
+                                                let __start_pos = __pos;
                                                 {
-                                                    let choice_res =
-                                                        parse_escape(input,
-                                                                     state,
-                                                                     pos);
-                                                    match choice_res {
-                                                        Matched(pos, value) =>
-                                                        Matched(pos, value),
-                                                        Failed => {
-                                                            let start_pos =
-                                                                pos;
-                                                            {
-                                                                let seq_res =
-                                                                    if input.len()
-                                                                           >
-                                                                           pos
-                                                                       {
-                                                                        let (ch,
-                                                                             next) =
-                                                                            char_range_at(input,
-                                                                                          pos);
-                                                                        match ch
-                                                                            {
-                                                                            '\''
-                                                                            =>
-                                                                            state.mark_failure(pos,
-                                                                                               "[^\']"),
-                                                                            _
-                                                                            =>
-                                                                            Matched(next,
-                                                                                    ()),
-                                                                        }
-                                                                    } else {
-                                                                        state.mark_failure(pos,
-                                                                                           "[^\']")
-                                                                    };
-                                                                match seq_res
-                                                                    {
-                                                                    Matched(pos,
-                                                                            _)
-                                                                    => {
-                                                                        {
-                                                                            let match_str =
-                                                                                &input[start_pos..pos];
-                                                                            Matched(pos,
-                                                                                    {
-                                                                                        match_str.chars().next().unwrap()
-                                                                                    })
-                                                                        }
-                                                                    }
-                                                                    Failed =>
-                                                                    Failed,
-                                                                }
+                                                    let __seq_res = if __input.len() > __pos {
+                                                        let (__ch, __next) =
+                                                            char_range_at(__input, __pos);
+                                                        match __ch {
+                                                            '\'' => {
+                                                                __state.mark_failure(__pos, "[^']")
                                                             }
+                                                            _ => Matched(__next, ()),
                                                         }
+                                                    } else {
+                                                        __state.mark_failure(__pos, "[^']")
+                                                    };
+                                                    match __seq_res {
+                                                        Matched(__pos, _) => Matched(__pos, {
+                                                            let match_str =
+                                                                &__input[__start_pos..__pos];
+                                                            let start_pos = __start_pos;
+                                                            let pos = __pos;
+                                                            {
+                                                                match_str.chars().next().unwrap()
+                                                            }
+                                                        }),
+                                                        Failed => Failed,
                                                     }
-                                                };
-                                            match step_res {
-                                                Matched(newpos, value) => {
-                                                    repeat_pos = newpos;
-                                                    repeat_value.push(value);
                                                 }
-                                                Failed => { break ; }
                                             }
                                         }
-                                        Matched(repeat_pos, repeat_value)
                                     };
-                                match seq_res {
-                                    Matched(pos, s) => {
-                                        {
-                                            let seq_res =
-                                                slice_eq(input, state, pos,
-                                                         "\'");
-                                            match seq_res {
-                                                Matched(pos, _) => {
-                                                    {
-                                                        let match_str =
-                                                            &input[start_pos..pos];
-                                                        Matched(pos,
-                                                                {
-                                                                    let mut result =
-                                                                        String::new();
-                                                                    for c in s
-                                                                        {
-                                                                        result.push(c);
-                                                                    }
-                                                                    result
-                                                                })
-                                                    }
+                                    match __step_res {
+                                        Matched(__newpos, __value) => {
+                                            __repeat_pos = __newpos;
+                                            __repeat_value.push(__value);
+                                        }
+                                        Failed => {
+                                            break;
+                                        }
+                                    }
+                                }
+                                Matched(__repeat_pos, __repeat_value)
+                            };
+                            match __seq_res {
+                                Matched(__pos, s) => {
+                                    let __seq_res = slice_eq(__input, __state, __pos, "'");
+                                    match __seq_res {
+                                        Matched(__pos, _) => Matched(__pos, {
+                                            let match_str = &__input[__start_pos..__pos];
+                                            let start_pos = __start_pos;
+                                            let pos = __pos;
+                                            {
+                                                let mut result = String::new();
+                                                for c in s {
+                                                    result.push(c);
                                                 }
-                                                Failed => Failed,
+                                                result
                                             }
-                                        }
+                                        }),
+                                        Failed => Failed,
                                     }
-                                    Failed => Failed,
                                 }
+                                Failed => Failed,
                             }
                         }
                         Failed => Failed,
@@ -4322,37 +7041,39 @@ fn parse_string<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_escape<'input>(input: &'input str, state: &mut ParseState,
-                        pos: usize) -> RuleResult<char> {
+
+fn __parse_escape<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<char> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res = slice_eq(input, state, pos, "\\");
-            match seq_res {
-                Matched(pos, _) => {
-                    {
-                        let seq_res = parse_any_char(input, state, pos);
-                        match seq_res {
-                            Matched(pos, c) => {
-                                {
-                                    let match_str = &input[start_pos..pos];
-                                    Matched(pos,
-                                            {
-                                                match c {
-                                                    'b' => '\u{8}',
-                                                    'f' => '\u{c}',
-                                                    'n' => '\n',
-                                                    'r' => '\r',
-                                                    't' => '\t',
-                                                    'v' => '\u{b}',
-                                                    '0' => '\u{0}',
-                                                    _ => c,
-                                                }
-                                            })
+            let __seq_res = slice_eq(__input, __state, __pos, "\\");
+            match __seq_res {
+                Matched(__pos, _) => {
+                    let __seq_res = __parse_any_char(__input, __state, __pos);
+                    match __seq_res {
+                        Matched(__pos, c) => Matched(__pos, {
+                            let match_str = &__input[__start_pos..__pos];
+                            let start_pos = __start_pos;
+                            let pos = __pos;
+                            {
+                                match c {
+                                    'b' => '\x08',
+                                    'f' => '\x0c',
+                                    'n' => '\x0a',
+                                    'r' => '\x0d',
+                                    't' => '\x09',
+                                    'v' => '\x0b',
+                                    '0' => '\0',
+                                    _ => c,
                                 }
                             }
-                            Failed => Failed,
-                        }
+                        }),
+                        Failed => Failed,
                     }
                 }
                 Failed => Failed,
@@ -4360,33 +7081,48 @@ fn parse_escape<'input>(input: &'input str, state: &mut ParseState,
         }
     }
 }
-fn parse_any_char<'input>(input: &'input str, state: &mut ParseState,
-                          pos: usize) -> RuleResult<char> {
+
+fn __parse_any_char<'input>(
+    __input: &'input str,
+    __state: &mut ParseState<'input>,
+    __pos: usize,
+) -> RuleResult<char> {
+    #![allow(non_snake_case, unused)]
     {
-        let start_pos = pos;
+        let __start_pos = __pos;
         {
-            let seq_res = any_char(input, state, pos);
-            match seq_res {
-                Matched(pos, _) => {
+            let __seq_res = any_char(__input, __state, __pos);
+            match __seq_res {
+                Matched(__pos, _) => Matched(__pos, {
+                    let match_str = &__input[__start_pos..__pos];
+                    let start_pos = __start_pos;
+                    let pos = __pos;
                     {
-                        let match_str = &input[start_pos..pos];
-                        Matched(pos, { match_str.chars().next().unwrap() })
+                        match_str.chars().next().unwrap()
                     }
-                }
+                }),
                 Failed => Failed,
             }
         }
     }
 }
-pub fn parse<'input>(input: &'input str) -> ParseResult<ast::Block> {
-    let mut state = ParseState::new();
-    match parse_parse(input, &mut state, 0) {
-        Matched(pos, value) => { if pos == input.len() { return Ok(value) } }
-        _ => { }
+
+pub fn parse<'input>(__input: &'input str) -> ParseResult<ast::Block> {
+    #![allow(non_snake_case, unused)]
+    let mut __state = ParseState::new();
+    match __parse_parse(__input, &mut __state, 0) {
+        Matched(__pos, __value) => {
+            if __pos == __input.len() {
+                return Ok(__value);
+            }
+        }
+        _ => {}
     }
-    let (line, col) = pos_to_line(input, state.max_err_pos);
-    Err(ParseError{line: line,
-                   column: col,
-                   offset: state.max_err_pos,
-                   expected: state.expected,})
+    let (__line, __col) = pos_to_line(__input, __state.max_err_pos);
+    Err(ParseError {
+        line: __line,
+        column: __col,
+        offset: __state.max_err_pos,
+        expected: __state.expected,
+    })
 }
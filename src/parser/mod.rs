@@ -1,7 +1,113 @@
 mod grammar;
 mod complete;
 
-pub use self::grammar::parse;
+use std::{error, fmt};
+use std::cell::RefCell;
+
+use super::ast;
+
+/// A script that couldn't be parsed
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxError {
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+    /// Descriptions of the tokens that could have come next, sorted and without duplicates
+    pub expected: Vec<String>,
+    /// The line of source with the error, followed by a caret under the column
+    pub excerpt: String,
+    found: Option<char>
+}
+
+impl SyntaxError {
+    fn new(source: &str, error: grammar::ParseError) -> SyntaxError {
+        let mut expected: Vec<String> = error.expected.iter().filter_map(|token| describe_token(token)).collect();
+        expected.sort();
+        expected.dedup();
+
+        let line = source.lines().nth(error.line - 1).unwrap_or("");
+        let caret: String = line.chars().take(error.column - 1)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+
+        SyntaxError {
+            line: error.line,
+            column: error.column,
+            offset: error.offset,
+            expected: expected,
+            excerpt: format!("{}\n{}^", line, caret),
+            found: source[error.offset..].chars().next()
+        }
+    }
+
+    /// Describes what was found and what was expected instead
+    pub fn message(&self) -> String {
+        let found = match self.found {
+            Some('\n') | Some('\r') => "unexpected end of line".to_string(),
+            Some(c) => format!("unexpected '{}'", c),
+            None => "unexpected end of input".to_string()
+        };
+
+        match self.expected.len() {
+            0 => found,
+            1 => format!("{}, expected {}", found, self.expected[0]),
+            n => format!("{}, expected {} or {}", found, self.expected[..n - 1].join(", "), self.expected[n - 1])
+        }
+    }
+}
+
+impl fmt::Display for SyntaxError {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        write!(fmt, "{} at line {}, column {}\n{}", self.message(), self.line, self.column, self.excerpt)
+    }
+}
+
+impl error::Error for SyntaxError {
+    fn description(&self) -> &str {
+        "syntax error"
+    }
+}
+
+/// Turns a token from the grammar into something readable, leaving out whitespace and comments
+fn describe_token(token: &str) -> Option<String> {
+    match token {
+        "[ \t]" | "[\n\r]" | "//" => None,
+        "[a-zA-Z_]" | "[a-zA-Z_0-9]" => Some("identifier".to_string()),
+        "[0-9]" => Some("number".to_string()),
+        "\"" | "'" => Some("string".to_string()),
+        token => Some(format!("'{}'", token))
+    }
+}
+
+thread_local! {
+    /// Where each line of the source being parsed starts, keyed by the source's address and length
+    static LINE_STARTS: RefCell<(usize, usize, Vec<usize>)> = const { RefCell::new((0, 0, Vec::new())) };
+}
+
+/// Finds the line and column of a byte offset, indexing the source's line starts the first time it's seen
+fn position(source: &str, offset: usize) -> ast::Position {
+    LINE_STARTS.with(|starts| {
+        let mut starts = starts.borrow_mut();
+        let key = (source.as_ptr() as usize, source.len());
+        if (starts.0, starts.1) != key || starts.2.is_empty() {
+            let lines = Some(0).into_iter().chain(source.match_indices('\n').map(|(i, _)| i + 1)).collect();
+            *starts = (key.0, key.1, lines);
+        }
+
+        let line = match starts.2.binary_search(&offset) {
+            Ok(line) => line,
+            Err(line) => line - 1
+        };
+        let column = source[starts.2[line]..offset].chars().count() + 1;
+        ast::Position {offset: offset, line: line + 1, column: column}
+    })
+}
+
+pub fn parse(source: &str) -> Result<ast::Block, SyntaxError> {
+    let result = grammar::parse(source).map_err(|e| SyntaxError::new(source, e));
+    LINE_STARTS.with(|starts| *starts.borrow_mut() = (0, 0, Vec::new()));
+    result
+}
 
 pub fn is_complete(source: &str) -> bool {
     complete::complete(source).is_ok()
@@ -0,0 +1,298 @@
+//! Storage for the own properties of objects, which keeps them in a predictable order.
+
+use std::collections::{BTreeMap, HashMap};
+use std::iter::FromIterator;
+use std::mem;
+
+use super::interpret::Value;
+
+/// Parses `key` as an array index, which is a canonical integer below 2^32 - 1
+pub fn array_index(key: &str) -> Option<u32> {
+    match key.parse::<u32>() {
+        Ok(index) if index != u32::MAX && index.to_string() == key => Some(index),
+        _ => None
+    }
+}
+
+/// What a property holds
+#[derive(Debug, Clone, PartialEq)]
+pub enum Slot {
+    /// A stored value, which assignments can only change if it's writable
+    Data { value: Value, writable: bool },
+    /// Functions that are called to read and assign the property, either of which can be `undefined`
+    Accessor { get: Value, set: Value }
+}
+
+/// A property, along with the attributes that control how scripts can change it
+#[derive(Debug, Clone, PartialEq)]
+pub struct Property {
+    pub slot: Slot,
+    /// Whether the property is listed by `Object.keys` and `JSON.stringify`
+    pub enumerable: bool,
+    /// Whether the property can be deleted or have its attributes changed
+    pub configurable: bool
+}
+
+impl Property {
+    /// A property like the ones created by assignment, with every attribute set
+    pub fn new(value: Value) -> Property {
+        Property {
+            slot: Slot::Data {value: value, writable: true},
+            enumerable: true,
+            configurable: true
+        }
+    }
+
+    /// Creates a property from the fields of `descriptor`, where missing attributes are unset
+    pub fn from_descriptor(descriptor: PropertyDescriptor) -> Property {
+        let slot = if descriptor.is_accessor() {
+            Slot::Accessor {
+                get: descriptor.get.unwrap_or(Value::Undefined),
+                set: descriptor.set.unwrap_or(Value::Undefined)
+            }
+        } else {
+            Slot::Data {
+                value: descriptor.value.unwrap_or(Value::Undefined),
+                writable: descriptor.writable.unwrap_or(false)
+            }
+        };
+
+        Property {
+            slot: slot,
+            enumerable: descriptor.enumerable.unwrap_or(false),
+            configurable: descriptor.configurable.unwrap_or(false)
+        }
+    }
+
+    /// The value of a data property
+    pub fn value(&self) -> Option<&Value> {
+        match self.slot {
+            Slot::Data {ref value, ..} => Some(value),
+            Slot::Accessor {..} => None
+        }
+    }
+
+    /// Whether assignments can change a data property, which is never true of accessors
+    pub fn is_writable(&self) -> bool {
+        match self.slot {
+            Slot::Data {writable, ..} => writable,
+            Slot::Accessor {..} => false
+        }
+    }
+
+    /// Whether `descriptor` can be applied to this property
+    ///
+    /// Properties that aren't configurable can only be made read-only, or have their value set to
+    /// the one they already have if they're already read-only. Their getters and setters can't change.
+    pub fn allows(&self, descriptor: &PropertyDescriptor) -> bool {
+        if self.configurable {
+            return true;
+        }
+
+        if descriptor.configurable == Some(true) || descriptor.enumerable.is_some_and(|e| e != self.enumerable) {
+            return false;
+        }
+
+        let unchanged = |current: &Value, new: &Option<Value>| new.as_ref().is_none_or(|new| new.same_value(current));
+        match self.slot {
+            Slot::Data {..} if descriptor.is_accessor() => false,
+            Slot::Accessor {..} if descriptor.is_data() => false,
+            Slot::Data {ref value, writable} => writable || (descriptor.writable != Some(true) && unchanged(value, &descriptor.value)),
+            Slot::Accessor {ref get, ref set} => unchanged(get, &descriptor.get) && unchanged(set, &descriptor.set)
+        }
+    }
+
+    /// Changes the fields that `descriptor` has, switching between a data property and an accessor if needed
+    pub fn update(self, descriptor: PropertyDescriptor) -> Property {
+        let slot = match self.slot {
+            Slot::Data {..} if descriptor.is_accessor() => Slot::Accessor {
+                get: descriptor.get.unwrap_or(Value::Undefined),
+                set: descriptor.set.unwrap_or(Value::Undefined)
+            },
+            Slot::Accessor {..} if descriptor.is_data() => Slot::Data {
+                value: descriptor.value.unwrap_or(Value::Undefined),
+                writable: descriptor.writable.unwrap_or(false)
+            },
+            Slot::Data {value, writable} => Slot::Data {
+                value: descriptor.value.unwrap_or(value),
+                writable: descriptor.writable.unwrap_or(writable)
+            },
+            Slot::Accessor {get, set} => Slot::Accessor {
+                get: descriptor.get.unwrap_or(get),
+                set: descriptor.set.unwrap_or(set)
+            }
+        };
+
+        Property {
+            slot: slot,
+            enumerable: descriptor.enumerable.unwrap_or(self.enumerable),
+            configurable: descriptor.configurable.unwrap_or(self.configurable)
+        }
+    }
+}
+
+/// The parts of a property that `Object.defineProperty` changes, where `None` leaves them as they are
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PropertyDescriptor {
+    pub value: Option<Value>,
+    pub writable: Option<bool>,
+    pub get: Option<Value>,
+    pub set: Option<Value>,
+    pub enumerable: Option<bool>,
+    pub configurable: Option<bool>
+}
+
+impl PropertyDescriptor {
+    /// Whether this describes a data property, by having a value or being writable
+    pub fn is_data(&self) -> bool {
+        self.value.is_some() || self.writable.is_some()
+    }
+
+    /// Whether this describes an accessor, by having a getter or a setter
+    pub fn is_accessor(&self) -> bool {
+        self.get.is_some() || self.set.is_some()
+    }
+}
+
+/// An object's own properties
+///
+/// Properties are listed with array indices first, in ascending order, followed by every other
+/// key in the order it was first added.
+#[derive(Debug, Clone, Default)]
+pub struct PropertyMap {
+    indices: BTreeMap<u32, Property>,
+    names: Vec<(String, Property)>,
+    /// Where each of `names` is stored
+    positions: HashMap<String, usize>
+}
+
+impl PropertyMap {
+    pub fn new() -> PropertyMap {
+        PropertyMap::default()
+    }
+
+    /// The value of a data property
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.property(key).and_then(Property::value)
+    }
+
+    pub fn property(&self, key: &str) -> Option<&Property> {
+        match array_index(key) {
+            Some(index) => self.indices.get(&index),
+            None => self.positions.get(key).map(|&position| &self.names[position].1)
+        }
+    }
+
+    pub fn property_mut(&mut self, key: &str) -> Option<&mut Property> {
+        match array_index(key) {
+            Some(index) => self.indices.get_mut(&index),
+            None => match self.positions.get(key) {
+                Some(&position) => Some(&mut self.names[position].1),
+                None => None
+            }
+        }
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.property(key).is_some()
+    }
+
+    /// Sets the value of a property, keeping its place and attributes if it already exists
+    ///
+    /// This doesn't check whether the property is writable, and replaces accessors with a writable value.
+    pub fn insert(&mut self, key: String, value: Value) -> Option<Value> {
+        if let Some(property) = self.property_mut(&key) {
+            return match property.slot {
+                Slot::Data {value: ref mut current, ..} => Some(mem::replace(current, value)),
+                ref mut slot => {
+                    *slot = Slot::Data {value: value, writable: true};
+                    None
+                }
+            };
+        }
+
+        self.define(key, Property::new(value));
+        None
+    }
+
+    /// Replaces a property along with its attributes, keeping its place if it already exists
+    pub fn define(&mut self, key: String, property: Property) -> Option<Property> {
+        if let Some(index) = array_index(&key) {
+            return self.indices.insert(index, property);
+        }
+
+        if let Some(&position) = self.positions.get(&key) {
+            return Some(mem::replace(&mut self.names[position].1, property));
+        }
+
+        self.positions.insert(key.clone(), self.names.len());
+        self.names.push((key, property));
+        None
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Property> {
+        if let Some(index) = array_index(key) {
+            return self.indices.remove(&index);
+        }
+
+        let position = match self.positions.remove(key) {
+            Some(position) => position,
+            None => return None
+        };
+
+        let (_, value) = self.names.remove(position);
+        for &mut (ref name, _) in &mut self.names[position..] {
+            *self.positions.get_mut(name).unwrap() -= 1;
+        }
+
+        Some(value)
+    }
+
+    /// Removes every array index that is `length` or above
+    ///
+    /// Indices that aren't configurable can't be removed, so this stops above the last of them and
+    /// returns where it stopped.
+    pub fn truncate_indices(&mut self, length: u32) -> u32 {
+        let length = match self.indices.range(length..).rev().find(|&(_, property)| !property.configurable) {
+            Some((&index, _)) => index + 1,
+            None => length
+        };
+
+        self.indices.split_off(&length);
+        length
+    }
+
+    /// The properties that are array indices below `length`, in order
+    pub fn indices<'a>(&'a self, length: u32) -> impl Iterator<Item = (u32, &'a Property)> + 'a {
+        self.indices.range(..length).map(|(&index, property)| (index, property))
+    }
+
+    pub fn len(&self) -> usize {
+        self.indices.len() + self.names.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn keys(&self) -> Vec<String> {
+        self.iter().map(|(key, _)| key).collect()
+    }
+
+    pub fn iter<'a>(&'a self) -> impl Iterator<Item = (String, &'a Property)> + 'a {
+        let indices = self.indices.iter().map(|(index, value)| (index.to_string(), value));
+        let names = self.names.iter().map(|&(ref name, ref value)| (name.clone(), value));
+        indices.chain(names)
+    }
+}
+
+impl FromIterator<(String, Value)> for PropertyMap {
+    fn from_iter<I: IntoIterator<Item = (String, Value)>>(iter: I) -> PropertyMap {
+        let mut properties = PropertyMap::new();
+        for (key, value) in iter {
+            properties.insert(key, value);
+        }
+
+        properties
+    }
+}
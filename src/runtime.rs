@@ -1,15 +1,21 @@
 use super::parser;
+use super::ast;
 
 use super::interpret;
-use super::interpret::{JSResult, Context};
+use super::convert;
+use super::json;
+use super::interpret::Context;
+use super::properties::{Property, PropertyDescriptor, Slot};
 
 use std::ops::Deref;
+use std::rc::Rc;
+use std::{cmp, error, fmt};
 
 macro_rules! object {
     ( $proto:expr, $($key:ident => $value:expr),* ) => {
-        Object::from_map(hashmap! {
-            $(stringify!($key).to_string() => $value.to_value()),*
-        }, $proto)
+        Object::from_map(vec![
+            $((stringify!($key).to_string(), Value::from($value))),*
+        ], $proto)
     }
 }
 
@@ -17,31 +23,30 @@ macro_rules! object {
 macro_rules! function {
     ( $f:ident ($context:ident ; $( $x:ident ),* ; $args:ident ) $body:block , $prototype:expr ) => {
         {
+            #[allow(unused_mut)]
             fn native(arguments: Vec<interpret::Value>, mut $context: interpret::Context) -> interpret::JSResult {
                 let mut arguments = arguments.into_iter();
                 $(
                     let $x = arguments.next().unwrap_or(Value::Undefined);
                 )*
-                let $args = arguments.collect();
+                let $args: Vec<interpret::Value> = arguments.collect();
                 $body
             }
-            Value::from_function(Function::Native(stringify!($f).to_string(), native), $prototype)
+            Value::from_function(Function::native(stringify!($f), native), $prototype)
         }
     };
     ( $f:ident ($context:ident ; $( $t:ident $x:ident ),* ; $args:ident ) $body:block , $prototype:expr ) => {
         {
+            #[allow(unused_mut)]
             fn native(arguments: Vec<interpret::Value>, mut $context: interpret::Context) -> interpret::JSResult {
                 let mut arguments = arguments.into_iter();
                 $(
-                    let $x = match arguments.next().unwrap_or(Value::Undefined) {
-                        Value::$t(b) => b,
-                        o@_ => return interpret::throw_string(format!("{:?} is not a {}", o, stringify!($t)))
-                    };
+                    let $x: $t = try!(convert::FromValue::from_value(arguments.next().unwrap_or(Value::Undefined), &$context));
                 )*
                 let $args: Vec<interpret::Value> = arguments.collect();
                 $body
             }
-            Value::from_function(Function::Native(stringify!($f).to_string(), native), $prototype)
+            Value::from_function(Function::native(stringify!($f), native), $prototype)
         }
     };
     ( $f:ident ($context:ident ; $args:ident ) $body:block , $prototype:expr ) => {
@@ -50,13 +55,97 @@ macro_rules! function {
                 let $args = arguments;
                 $body
             }
-            Value::from_function(Function::Native(stringify!($f).to_string(), native), $prototype)
+            Value::from_function(Function::native(stringify!($f), native), $prototype)
         }
     };
 }
 
 pub type Yuk = interpret::Context;
 
+/// An error from evaluating a script with `Yuk::eval`
+pub enum Error {
+    /// The source couldn't be parsed
+    Syntax(parser::SyntaxError),
+    /// The script threw a value that it didn't catch
+    Thrown(interpret::Value),
+    /// The script was stopped for going past one of the interpreter's limits
+    ResourceLimit(String),
+    /// The interpreter failed, rather than the script
+    Internal(String)
+}
+
+impl Error {
+    /// The value thrown by the script, if it threw one
+    pub fn thrown(&self) -> Option<&interpret::Value> {
+        match self {
+            &Error::Thrown(ref value) => Some(value),
+            _ => None
+        }
+    }
+
+    /// Converts the error into a value that scripts can catch
+    ///
+    /// Syntax errors are located in `context.file`.
+    pub fn into_value(self, context: &Context) -> interpret::Value {
+        let (kind, message) = match self {
+            Error::Thrown(value) => return value,
+            Error::Syntax(e) => {
+                let position = ast::Position {offset: e.offset, line: e.line, column: e.column};
+                let error = construct_error(interpret::ErrorKind::SyntaxError, e.message(), context.global.clone());
+
+                // The source never started running, so it gets a frame just for the location
+                let depth = context.stack.depth();
+                if context.stack.push(interpret::Frame {function: None, file: context.file.clone(), native: false, position: Some(position)}).is_ok() {
+                    interpret::locate_error(&error, position, context);
+                }
+                context.stack.truncate(depth);
+
+                return error;
+            },
+            Error::ResourceLimit(message) => (interpret::ErrorKind::RangeError, message),
+            Error::Internal(message) => (interpret::ErrorKind::Error, message)
+        };
+
+        let error = construct_error(kind, message, context.global.clone());
+        interpret::capture_stack(&error, &context.stack, 0);
+        error
+    }
+}
+
+// Thrown objects can refer to themselves through their prototypes, so they aren't printed in full
+impl fmt::Debug for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &Error::Syntax(ref e) => fmt.debug_tuple("Syntax").field(e).finish(),
+            &Error::Thrown(ref value) => fmt.debug_tuple("Thrown").field(&value.debug_string()).finish(),
+            &Error::ResourceLimit(ref message) => fmt.debug_tuple("ResourceLimit").field(message).finish(),
+            &Error::Internal(ref message) => fmt.debug_tuple("Internal").field(message).finish()
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &Error::Syntax(ref e) => write!(fmt, "SyntaxError: {}", e),
+            &Error::Thrown(ref value) => write!(fmt, "{}", value.debug_string()),
+            &Error::ResourceLimit(ref message) => write!(fmt, "evaluation aborted: {}", message),
+            &Error::Internal(ref message) => write!(fmt, "internal error: {}", message)
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            &Error::Syntax(_) => "syntax error",
+            &Error::Thrown(_) => "uncaught exception",
+            &Error::ResourceLimit(_) => "resource limit exceeded",
+            &Error::Internal(_) => "internal error"
+        }
+    }
+}
+
 /// A high-level interface for the interpreter
 impl Yuk {
     /// Create a context with the JavaScript standard library
@@ -64,20 +153,279 @@ impl Yuk {
         Context::new(create_stdlib())
     }
 
+    /// Makes a Rust function available to scripts as the global `name`
+    ///
+    /// Arguments are converted with `FromValue`, and a TypeError is thrown if that fails. Registering
+    /// fails if a script has stopped the global from being written, by freezing or sealing it.
+    pub fn register<F, Args>(&mut self, name: &str, function: F) -> Result<(), Error> where F: convert::IntoNative<Args> {
+        let function_prototype = match self.global.get("Function").and_then(|f| f.get("prototype", self.global.clone())) {
+            Ok(interpret::Value::Object(o)) => o,
+            _ => interpret::Object::Null
+        };
+
+        let function = interpret::Value::from_function(function.into_native(name), function_prototype);
+        match self.global.set(name, function) {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                interpret::attach_error_prototype(&e, self.global.clone());
+                Err(Error::Thrown(e))
+            }
+        }
+    }
+
     /// Parse and evaluate `source` in this context
-    pub fn eval(&mut self, source: &str) -> JSResult {
-        let parsed = parser::parse(source);
+    ///
+    /// `file` is the name reported in the location of any errors.
+    pub fn eval(&mut self, source: &str, file: Option<&str>) -> Result<interpret::Value, Error> {
+        let parsed = try!(parser::parse(source).map_err(Error::Syntax));
 
         // println!("");
         // println!("AST: {:?}", parsed);
 
-        match parsed {
-            Ok(ast) => match interpret::eval_block(&ast, self.clone()) {
-                interpret::Tri::Continue(v) => Ok(v),
-                interpret::Tri::Return(v) => Ok(v),
-                interpret::Tri::Error(e) => Err(e)
-            },
-            Err(e) => interpret::throw_string(format!("SyntaxError: {:?}", e))
+        let context = Context {
+            file: file.map(|f| Rc::new(f.to_string())),
+            strict: self.strict || ast::is_strict(&parsed),
+            .. self.clone()
+        };
+        let depth = context.stack.depth();
+        if depth == 0 {
+            context.stack.reset_overflow();
+        }
+
+        let result = context.stack.push(interpret::Frame {function: None, file: context.file.clone(), native: false, position: None})
+            .and_then(|()| interpret::eval_block(&parsed, context.clone()).into_result());
+
+        // Every call pops its own frame, so anything else left above this evaluation's frame is a bug
+        let left = context.stack.depth();
+        context.stack.truncate(depth);
+        if left > depth + 1 {
+            return Err(Error::Internal(format!("{} call frames were left on the stack", left - depth - 1)));
+        }
+
+        match result {
+            Ok(value) => Ok(value),
+            // Scripts can catch an overflow, but one that reaches the host stopped the whole evaluation.
+            // Evaluations nested in a script's `eval` leave it to the script.
+            Err(ref e) if depth == 0 && context.stack.is_overflow(e) =>
+                Err(Error::ResourceLimit(format!("more than {} nested calls", interpret::MAX_CALL_DEPTH))),
+            Err(e) => {
+                interpret::capture_stack(&e, &context.stack, 0);
+                interpret::attach_error_prototype(&e, self.global.clone());
+                Err(Error::Thrown(e))
+            }
+        }
+    }
+}
+
+/// Reads the elements of an array-like object, treating holes as `undefined`
+fn array_elements(array: &interpret::Value, context: &interpret::Context) -> Result<Vec<interpret::Value>, interpret::Value> {
+    let length = try!(array.get_in("length", context)).to_uint32();
+    if length > interpret::MAX_ELEMENTS {
+        return interpret::throw_error(interpret::ErrorKind::RangeError, format!("array of length {} is too long to copy", length));
+    }
+
+    let mut elements = vec![];
+    for index in 0..length {
+        elements.push(try!(array.get_in(&index.to_string(), context)));
+    }
+
+    Ok(elements)
+}
+
+/// Overwrites the elements of the array-like `this` of `context`, updating its `length`
+///
+/// Setters are called, and elements that can't be written throw a TypeError even outside strict mode.
+fn set_array_elements(elements: Vec<interpret::Value>, context: &interpret::Context) -> Result<(), interpret::Value> {
+    let length = elements.len();
+    let context = interpret::Context {strict: true, .. context.clone()};
+
+    for (index, element) in elements.into_iter().enumerate() {
+        try!(context.this.put(&index.to_string(), element, &context));
+    }
+
+    try!(context.this.put("length", interpret::Value::Number(length as f64), &context));
+    Ok(())
+}
+
+/// Sorts `elements` stably with a comparison that can throw, stopping at the first error
+///
+/// Script comparators don't have to be consistent, which `slice::sort_by` can't cope with, so
+/// this is a plain merge sort that only ever takes the result of each comparison at face value.
+fn merge_sort<F>(mut elements: Vec<interpret::Value>, compare: &mut F) -> Result<Vec<interpret::Value>, interpret::Value>
+    where F: FnMut(&interpret::Value, &interpret::Value) -> Result<cmp::Ordering, interpret::Value> {
+    if elements.len() <= 1 {
+        return Ok(elements);
+    }
+
+    let right = elements.split_off(elements.len() / 2);
+    let left = try!(merge_sort(elements, compare));
+    let right = try!(merge_sort(right, compare));
+
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+
+    loop {
+        let take_right = match (left.peek(), right.peek()) {
+            (Some(l), Some(r)) => try!(compare(l, r)) == cmp::Ordering::Greater,
+            (Some(_), None) => false,
+            (None, Some(_)) => true,
+            (None, None) => break
+        };
+
+        merged.extend(if take_right { right.next() } else { left.next() });
+    }
+
+    Ok(merged)
+}
+
+/// Resolves a possibly negative index argument against `length`, clamping it to the array
+fn relative_index(index: Option<&interpret::Value>, length: usize, default: usize) -> usize {
+    let index = match index {
+        Some(&interpret::Value::Undefined) | None => return default,
+        Some(v) => v.to_number().trunc()
+    };
+
+    if index.is_nan() {
+        0
+    } else if index < 0.0 {
+        (length as f64 + index).max(0.0) as usize
+    } else {
+        index.min(length as f64) as usize
+    }
+}
+
+/// Converts an array element to a string for `join`, where `null` and `undefined` are empty
+fn element_to_string(element: &interpret::Value, context: &interpret::Context) -> Result<String, interpret::Value> {
+    match element {
+        &interpret::Value::Undefined | &interpret::Value::Object(interpret::Object::Null) => Ok(String::new()),
+        v => v.js_to_string(context)
+    }
+}
+
+/// Checks that the callback passed to an array method can be called
+fn check_callback(callback: &interpret::Value) -> Result<(), interpret::Value> {
+    if callback.is_function() {
+        Ok(())
+    } else {
+        interpret::throw_error(interpret::ErrorKind::TypeError, format!("{} is not a function", callback.debug_string()))
+    }
+}
+
+/// Calls the callback of an array method with an element, its index and the array itself
+///
+/// `reduce` passes its accumulator before the element, so the arguments up to the element are given.
+fn call_element_callback(callback: &interpret::Value, this: &interpret::Value, mut arguments: Vec<interpret::Value>, index: u32, context: &interpret::Context) -> interpret::JSResult {
+    arguments.push(interpret::Value::Number(index as f64));
+    arguments.push(context.this.clone());
+
+    callback.apply(
+        arguments,
+        interpret::Context {this: this.clone(), local: interpret::Scope::new(context.global.clone()), .. context.clone()}
+    )
+}
+
+/// Whether the array-like `this` of `context` has an element at `index`, which holes don't
+fn has_element(index: u32, context: &interpret::Context) -> bool {
+    match context.this {
+        interpret::Value::Object(ref o) => o.has_property(&index.to_string()),
+        _ => false
+    }
+}
+
+/// Creates an error of the given kind, linked to its constructor's prototype
+fn construct_error(kind: interpret::ErrorKind, message: String, global: interpret::Object) -> interpret::Value {
+    let error = interpret::Value::Object(interpret::Object::from_error(kind, &message, interpret::Object::Null));
+    interpret::attach_error_prototype(&error, global);
+
+    error
+}
+
+/// Reads a property descriptor, as passed to `Object.defineProperty`
+///
+/// Fields that the descriptor doesn't have, including through its prototypes, are left unchanged.
+fn to_descriptor(descriptor: &interpret::Value, context: &interpret::Context) -> Result<PropertyDescriptor, interpret::Value> {
+    let object = match descriptor {
+        &interpret::Value::Object(interpret::Object::Object(ref o)) => o.clone(),
+        _ => return interpret::throw_error(interpret::ErrorKind::TypeError, format!("property descriptor must be an object: {}", descriptor.debug_string()))
+    };
+
+    let field = |key: &str| -> Result<Option<interpret::Value>, interpret::Value> {
+        match object.borrow().find_property(key) {
+            Some(_) => Ok(Some(try!(descriptor.get_in(key, context)))),
+            None => Ok(None)
+        }
+    };
+
+    let accessor = |key: &str| -> Result<Option<interpret::Value>, interpret::Value> {
+        match try!(field(key)) {
+            Some(f) if !f.is_function() && f != interpret::Value::Undefined =>
+                interpret::throw_error(interpret::ErrorKind::TypeError, format!("property descriptor's {} field is neither undefined nor a function", key)),
+            f => Ok(f)
+        }
+    };
+
+    let result = PropertyDescriptor {
+        value: try!(field("value")),
+        writable: try!(field("writable")).map(|v| v.to_boolean()),
+        get: try!(accessor("get")),
+        set: try!(accessor("set")),
+        enumerable: try!(field("enumerable")).map(|v| v.to_boolean()),
+        configurable: try!(field("configurable")).map(|v| v.to_boolean())
+    };
+
+    if result.is_data() && result.is_accessor() {
+        return interpret::throw_error(interpret::ErrorKind::TypeError,
+            "property descriptors must not specify a value or be writable when a getter or setter has been specified".to_string());
+    }
+
+    Ok(result)
+}
+
+/// Creates the object that `Object.getOwnPropertyDescriptor` returns
+fn from_property(property: Property, global: interpret::Object) -> interpret::JSResult {
+    let descriptor = try!(interpret::new_object(global));
+    match property.slot {
+        Slot::Data {value, writable} => {
+            try!(descriptor.set("value", value));
+            try!(descriptor.set("writable", interpret::Value::Boolean(writable)));
+        },
+        Slot::Accessor {get, set} => {
+            try!(descriptor.set("get", get));
+            try!(descriptor.set("set", set));
+        }
+    }
+    try!(descriptor.set("enumerable", interpret::Value::Boolean(property.enumerable)));
+    try!(descriptor.set("configurable", interpret::Value::Boolean(property.configurable)));
+
+    Ok(interpret::Value::Object(descriptor))
+}
+
+/// Create a constructor for one kind of error, along with its prototype
+macro_rules! error_constructor {
+    ( $kind:ident, $parent:expr, $function_prototype:expr ) => {
+        {
+            let constructor = function!(
+                $kind(context; message; _args) {
+                    let message = match message {
+                        Value::Undefined => String::new(),
+                        message => try!(message.js_to_string(&context))
+                    };
+                    let error = construct_error(interpret::ErrorKind::$kind, message, context.global.clone());
+
+                    // The stack starts where the constructor was called, rather than in the constructor itself
+                    interpret::capture_stack(&error, &context.stack, 1);
+
+                    Ok(error)
+                }, $function_prototype.clone()
+            );
+            let prototype = object! {
+                $parent.clone(),
+                name => Value::String(stringify!($kind).to_string()),
+                constructor => constructor.clone()
+            };
+            constructor.set("prototype", Value::from(prototype.clone())).unwrap();
+            (constructor, prototype)
         }
     }
 }
@@ -94,10 +442,10 @@ fn create_stdlib() -> interpret::Object {
         toString(context; _args) {
             match context.this {
                 interpret::Value::Object(interpret::Object::Object(ref o)) => match o.borrow().deref() {
-                    &interpret::ActualObject {values: _, prototype: _, otype: interpret::ObjectExtension::Function(ref f)} => Ok(interpret::Value::String(f.to_string())),
-                    _ => interpret::throw_string(format!("{:?} is not a function!", context.this))
+                    &interpret::ActualObject {otype: interpret::ObjectExtension::Function(ref f), ..} => Ok(interpret::Value::String(f.to_string())),
+                    _ => interpret::throw_error(interpret::ErrorKind::TypeError, format!("{} is not a function", context.this.debug_string()))
                 },
-                _ => interpret::throw_string(format!("{:?} is not a function!", context.this))
+                _ => interpret::throw_error(interpret::ErrorKind::TypeError, format!("{} is not a function", context.this.debug_string()))
             }
         }, function_prototype.clone()
     )).unwrap();
@@ -107,6 +455,381 @@ fn create_stdlib() -> interpret::Object {
             Ok(Value::String("[object Object]".to_string()))
         }, function_prototype.clone()
     )).unwrap();
+    object_prototype.set("hasOwnProperty", function!(
+        hasOwnProperty(context; key; _args) {
+            let key = try!(key.js_to_string(&context));
+            match context.this {
+                Value::Object(ref o) => Ok(Value::Boolean(try!(o.has_own(&key)))),
+                _ => Ok(Value::Boolean(false))
+            }
+        }, function_prototype.clone()
+    )).unwrap();
+
+    let array_prototype = object! {
+        object_prototype.clone(),
+        push => function!(
+            push(context; items) {
+                let context = interpret::Context {strict: true, .. context};
+                let mut length = try!(context.this.get_in("length", &context)).to_uint32() as usize;
+
+                for item in items {
+                    try!(context.this.put(&length.to_string(), item, &context));
+                    length += 1;
+                }
+
+                try!(context.this.put("length", Value::Number(length as f64), &context));
+                Ok(Value::Number(length as f64))
+            }, function_prototype.clone()
+        ),
+        pop => function!(
+            pop(context; _args) {
+                let context = interpret::Context {strict: true, .. context};
+                let length = try!(context.this.get_in("length", &context)).to_uint32();
+
+                let last = match length {
+                    0 => Value::Undefined,
+                    _ => try!(context.this.get_in(&(length - 1).to_string(), &context))
+                };
+
+                // Shortening the array removes the last element
+                try!(context.this.put("length", Value::Number(length.saturating_sub(1) as f64), &context));
+                Ok(last)
+            }, function_prototype.clone()
+        ),
+        slice => function!(
+            slice(context; args) {
+                let elements = try!(array_elements(&context.this, &context));
+                let length = elements.len();
+
+                let start = relative_index(args.first(), length, 0);
+                let end = relative_index(args.get(1), length, length);
+                let sliced = if start < end { elements[start..end].to_vec() } else { vec![] };
+
+                Ok(Value::Object(try!(new_array(sliced, context.global))))
+            }, function_prototype.clone()
+        ),
+        splice => function!(
+            splice(context; args) {
+                let context = interpret::Context {strict: true, .. context};
+                let length = try!(context.this.get_in("length", &context)).to_uint32();
+                if length > interpret::MAX_ELEMENTS {
+                    return interpret::throw_error(interpret::ErrorKind::RangeError, format!("array of length {} is too long to splice", length));
+                }
+                let length = length as usize;
+
+                let start = relative_index(args.first(), length, 0);
+                // Unlike the start, a negative count doesn't count back from the end
+                let delete_count = match args.len() {
+                    0 => 0,
+                    1 => length - start,
+                    _ => {
+                        let count = args[1].to_number().trunc();
+                        if count > 0.0 { count.min((length - start) as f64) as usize } else { 0 }
+                    }
+                };
+                let items: Vec<Value> = args.into_iter().skip(2).collect();
+
+                let mut removed = vec![];
+                for index in start..start + delete_count {
+                    removed.push(try!(context.this.get_in(&index.to_string(), &context)));
+                }
+
+                // Only the elements after the removed ones move, towards the end they are moving away from
+                let (tail, new_length) = (start + delete_count, length - delete_count + items.len());
+                let moved: Vec<usize> = if items.len() > delete_count {
+                    (tail..length).rev().collect()
+                } else {
+                    (tail..length).collect()
+                };
+                for from in moved {
+                    let element = try!(context.this.get_in(&from.to_string(), &context));
+                    try!(context.this.put(&(from - delete_count + items.len()).to_string(), element, &context));
+                }
+
+                for (offset, item) in items.into_iter().enumerate() {
+                    try!(context.this.put(&(start + offset).to_string(), item, &context));
+                }
+
+                try!(context.this.put("length", Value::Number(new_length as f64), &context));
+                Ok(Value::Object(try!(new_array(removed, context.global))))
+            }, function_prototype.clone()
+        ),
+        indexOf => function!(
+            indexOf(context; args) {
+                let elements = try!(array_elements(&context.this, &context));
+                let search = args.first().cloned().unwrap_or(Value::Undefined);
+                let start = relative_index(args.get(1), elements.len(), 0);
+
+                Ok(Value::Number(match elements.iter().skip(start).position(|e| e.strict_equals(&search)) {
+                    Some(index) => (start + index) as f64,
+                    None => -1.0
+                }))
+            }, function_prototype.clone()
+        ),
+        join => function!(
+            join(context; args) {
+                let separator = match args.first() {
+                    Some(&Value::Undefined) | None => ",".to_string(),
+                    Some(s) => try!(s.js_to_string(&context))
+                };
+
+                let mut strings = vec![];
+                for element in try!(array_elements(&context.this, &context)) {
+                    strings.push(try!(element_to_string(&element, &context)));
+                }
+
+                Ok(Value::String(strings.join(&separator)))
+            }, function_prototype.clone()
+        ),
+        toString => function!(
+            toString(context; _args) {
+                let mut strings = vec![];
+                for element in try!(array_elements(&context.this, &context)) {
+                    strings.push(try!(element_to_string(&element, &context)));
+                }
+
+                Ok(Value::String(strings.join(",")))
+            }, function_prototype.clone()
+        ),
+        concat => function!(
+            concat(context; args) {
+                let mut elements = try!(array_elements(&context.this, &context));
+
+                for arg in args {
+                    match arg {
+                        Value::Object(ref o) if o.is_array() => elements.extend(try!(array_elements(&arg, &context))),
+                        _ => elements.push(arg.clone())
+                    }
+                }
+
+                Ok(Value::Object(try!(new_array(elements, context.global))))
+            }, function_prototype.clone()
+        ),
+        reverse => function!(
+            reverse(context; _args) {
+                let context = interpret::Context {strict: true, .. context};
+                let length = try!(context.this.get_in("length", &context)).to_uint32();
+
+                // Elements are swapped in place, working in from both ends
+                for lower in 0..length / 2 {
+                    let (lower, upper) = (lower.to_string(), (length - 1 - lower).to_string());
+                    let lower_value = try!(context.this.get_in(&lower, &context));
+                    let upper_value = try!(context.this.get_in(&upper, &context));
+
+                    try!(context.this.put(&lower, upper_value, &context));
+                    try!(context.this.put(&upper, lower_value, &context));
+                }
+
+                Ok(context.this)
+            }, function_prototype.clone()
+        ),
+        sort => function!(
+            sort(context; args) {
+                use std::cmp::Ordering;
+
+                let elements = try!(array_elements(&context.this, &context));
+                let compare = args.first().cloned().unwrap_or(Value::Undefined);
+
+                let elements = try!(merge_sort(elements, &mut |a, b| match (a, b, &compare) {
+                    (&Value::Undefined, &Value::Undefined, _) => Ok(Ordering::Equal),
+                    (&Value::Undefined, _, _) => Ok(Ordering::Greater),
+                    (_, &Value::Undefined, _) => Ok(Ordering::Less),
+                    (_, _, &Value::Undefined) => Ok(try!(a.js_to_string(&context)).cmp(&try!(b.js_to_string(&context)))),
+                    (_, _, f) => {
+                        let order = try!(f.apply(
+                            vec![a.clone(), b.clone()],
+                            interpret::Context {this: Value::Undefined, local: interpret::Scope::new(context.global.clone()), .. context.clone()}
+                        ));
+                        Ok(order.to_number().partial_cmp(&0.0).unwrap_or(Ordering::Equal))
+                    }
+                }));
+
+                try!(set_array_elements(elements, &context));
+                Ok(context.this)
+            }, function_prototype.clone()
+        ),
+        forEach => function!(
+            forEach(context; callback, this_arg; _args) {
+                try!(check_callback(&callback));
+                let length = try!(context.this.get_in("length", &context)).to_uint32();
+
+                for index in 0..length {
+                    if !has_element(index, &context) {
+                        continue;
+                    }
+                    let element = try!(context.this.get_in(&index.to_string(), &context));
+                    try!(call_element_callback(&callback, &this_arg, vec![element], index, &context));
+                }
+
+                Ok(Value::Undefined)
+            }, function_prototype.clone()
+        ),
+        map => function!(
+            map(context; callback, this_arg; _args) {
+                try!(check_callback(&callback));
+                let length = try!(context.this.get_in("length", &context)).to_uint32();
+
+                // Holes stay holes in the mapped array
+                let mapped = try!(new_array(vec![], context.global.clone()));
+                try!(mapped.set("length", Value::Number(length as f64)));
+                for index in 0..length {
+                    if !has_element(index, &context) {
+                        continue;
+                    }
+                    let element = try!(context.this.get_in(&index.to_string(), &context));
+                    let result = try!(call_element_callback(&callback, &this_arg, vec![element], index, &context));
+                    try!(mapped.define_value(&index.to_string(), result));
+                }
+
+                Ok(Value::Object(mapped))
+            }, function_prototype.clone()
+        ),
+        filter => function!(
+            filter(context; callback, this_arg; _args) {
+                try!(check_callback(&callback));
+                let length = try!(context.this.get_in("length", &context)).to_uint32();
+
+                let mut kept = vec![];
+                for index in 0..length {
+                    if !has_element(index, &context) {
+                        continue;
+                    }
+                    let element = try!(context.this.get_in(&index.to_string(), &context));
+                    if try!(call_element_callback(&callback, &this_arg, vec![element.clone()], index, &context)).to_boolean() {
+                        kept.push(element);
+                    }
+                }
+
+                Ok(Value::Object(try!(new_array(kept, context.global))))
+            }, function_prototype.clone()
+        ),
+        some => function!(
+            some(context; callback, this_arg; _args) {
+                try!(check_callback(&callback));
+                let length = try!(context.this.get_in("length", &context)).to_uint32();
+
+                for index in 0..length {
+                    if !has_element(index, &context) {
+                        continue;
+                    }
+                    let element = try!(context.this.get_in(&index.to_string(), &context));
+                    if try!(call_element_callback(&callback, &this_arg, vec![element], index, &context)).to_boolean() {
+                        return Ok(Value::Boolean(true));
+                    }
+                }
+
+                Ok(Value::Boolean(false))
+            }, function_prototype.clone()
+        ),
+        every => function!(
+            every(context; callback, this_arg; _args) {
+                try!(check_callback(&callback));
+                let length = try!(context.this.get_in("length", &context)).to_uint32();
+
+                for index in 0..length {
+                    if !has_element(index, &context) {
+                        continue;
+                    }
+                    let element = try!(context.this.get_in(&index.to_string(), &context));
+                    if !try!(call_element_callback(&callback, &this_arg, vec![element], index, &context)).to_boolean() {
+                        return Ok(Value::Boolean(false));
+                    }
+                }
+
+                Ok(Value::Boolean(true))
+            }, function_prototype.clone()
+        ),
+        find => function!(
+            find(context; callback, this_arg; _args) {
+                try!(check_callback(&callback));
+                let length = try!(context.this.get_in("length", &context)).to_uint32();
+
+                for index in 0..length {
+                    let element = try!(context.this.get_in(&index.to_string(), &context));
+                    if try!(call_element_callback(&callback, &this_arg, vec![element.clone()], index, &context)).to_boolean() {
+                        return Ok(element);
+                    }
+                }
+
+                Ok(Value::Undefined)
+            }, function_prototype.clone()
+        ),
+        reduce => function!(
+            reduce(context; callback; args) {
+                try!(check_callback(&callback));
+                let length = try!(context.this.get_in("length", &context)).to_uint32();
+
+                // Without an initial value the first element that isn't a hole is used instead
+                let (mut accumulator, start) = match args.into_iter().next() {
+                    Some(initial) => (initial, 0),
+                    None => match (0..length).find(|&index| has_element(index, &context)) {
+                        Some(first) => (try!(context.this.get_in(&first.to_string(), &context)), first + 1),
+                        None => return interpret::throw_error(interpret::ErrorKind::TypeError, "reduce of empty array with no initial value".to_string())
+                    }
+                };
+
+                for index in start..length {
+                    if !has_element(index, &context) {
+                        continue;
+                    }
+                    let element = try!(context.this.get_in(&index.to_string(), &context));
+                    accumulator = try!(call_element_callback(&callback, &Value::Undefined, vec![accumulator, element], index, &context));
+                }
+
+                Ok(accumulator)
+            }, function_prototype.clone()
+        )
+    };
+
+    let array = function!(
+        Array(context; args) {
+            // A single number is the length of a new, empty array
+            if let (1, Some(&Value::Number(n))) = (args.len(), args.first()) {
+                let array = try!(new_array(vec![], context.global));
+                try!(array.set("length", Value::Number(n)));
+                return Ok(Value::Object(array));
+            }
+
+            Ok(Value::Object(try!(new_array(args, context.global))))
+        }, function_prototype.clone()
+    );
+    array.set("prototype", Value::from(array_prototype.clone())).unwrap();
+    array.set("isArray", function!(
+        isArray(_context; args) {
+            Ok(Value::Boolean(match args.first() {
+                Some(&Value::Object(ref o)) => o.is_array(),
+                _ => false
+            }))
+        }, function_prototype.clone()
+    )).unwrap();
+    array_prototype.set("constructor", array.clone()).unwrap();
+
+    let (error, error_prototype) = error_constructor!(Error, object_prototype, function_prototype);
+    error_prototype.set("message", Value::String(String::new())).unwrap();
+    error_prototype.set("toString", function!(
+        toString(context; _args) {
+            let name = match try!(context.this.get_in("name", &context)) {
+                Value::Undefined => "Error".to_string(),
+                name => try!(name.js_to_string(&context))
+            };
+            let message = match try!(context.this.get_in("message", &context)) {
+                Value::Undefined => String::new(),
+                message => try!(message.js_to_string(&context))
+            };
+
+            Ok(Value::String(match (name.is_empty(), message.is_empty()) {
+                (_, true) => name,
+                (true, false) => message,
+                (false, false) => name + ": " + &message
+            }))
+        }, function_prototype.clone()
+    )).unwrap();
+
+    let (type_error, _) = error_constructor!(TypeError, error_prototype, function_prototype);
+    let (reference_error, _) = error_constructor!(ReferenceError, error_prototype, function_prototype);
+    let (syntax_error, _) = error_constructor!(SyntaxError, error_prototype, function_prototype);
+    let (range_error, _) = error_constructor!(RangeError, error_prototype, function_prototype);
+    let (eval_error, _) = error_constructor!(EvalError, error_prototype, function_prototype);
 
     object! {
         object_prototype.clone(),
@@ -139,6 +862,95 @@ fn create_stdlib() -> interpret::Object {
                     Ok(Value::Object(Object::create(proto)))
                 }, function_prototype.clone()
             ),
+            keys => function!(
+                keys(context; Object obj; _args) {
+                    let keys = try!(obj.keys()).into_iter().map(Value::String).collect();
+                    Ok(Value::Object(try!(new_array(keys, context.global))))
+                }, function_prototype.clone()
+            ),
+            defineProperty => function!(
+                defineProperty(context; Object obj, Value key, Value descriptor; _args) {
+                    let key = try!(key.js_to_string(&context));
+                    try!(obj.define_own_property(&key, try!(to_descriptor(&descriptor, &context))));
+                    Ok(Value::Object(obj))
+                }, function_prototype.clone()
+            ),
+            defineProperties => function!(
+                defineProperties(context; Object obj, Object properties; _args) {
+                    // Every descriptor is read before any of them are applied
+                    let mut descriptors = vec![];
+                    let holder = Value::Object(properties.clone());
+                    for key in try!(properties.keys()) {
+                        let descriptor = try!(holder.get_in(&key, &context));
+                        descriptors.push((key, try!(to_descriptor(&descriptor, &context))));
+                    }
+
+                    for (key, descriptor) in descriptors {
+                        try!(obj.define_own_property(&key, descriptor));
+                    }
+                    Ok(Value::Object(obj))
+                }, function_prototype.clone()
+            ),
+            getOwnPropertyDescriptor => function!(
+                getOwnPropertyDescriptor(context; Object obj, Value key; _args) {
+                    let key = try!(key.js_to_string(&context));
+                    match try!(obj.own_property(&key)) {
+                        Some(property) => from_property(property, context.global),
+                        None => Ok(Value::Undefined)
+                    }
+                }, function_prototype.clone()
+            ),
+            freeze => function!(
+                freeze(_context; obj; _args) {
+                    match obj {
+                        Value::Object(Object::Object(ref o)) if !o.borrow_mut().seal(true) =>
+                            throw_error(ErrorKind::TypeError, format!("can't freeze {}", obj.debug_string())),
+                        _ => Ok(obj.clone())
+                    }
+                }, function_prototype.clone()
+            ),
+            seal => function!(
+                seal(_context; obj; _args) {
+                    match obj {
+                        Value::Object(Object::Object(ref o)) if !o.borrow_mut().seal(false) =>
+                            throw_error(ErrorKind::TypeError, format!("can't seal {}", obj.debug_string())),
+                        _ => Ok(obj.clone())
+                    }
+                }, function_prototype.clone()
+            ),
+            preventExtensions => function!(
+                preventExtensions(_context; obj; _args) {
+                    match obj {
+                        Value::Object(Object::Object(ref o)) if !o.borrow_mut().prevent_extensions() =>
+                            throw_error(ErrorKind::TypeError, format!("can't prevent extensions on {}", obj.debug_string())),
+                        _ => Ok(obj.clone())
+                    }
+                }, function_prototype.clone()
+            ),
+            isFrozen => function!(
+                isFrozen(_context; obj; _args) {
+                    match obj {
+                        Value::Object(Object::Object(ref o)) => Ok(Value::Boolean(o.borrow().is_sealed(true))),
+                        _ => Ok(Value::Boolean(true))
+                    }
+                }, function_prototype.clone()
+            ),
+            isSealed => function!(
+                isSealed(_context; obj; _args) {
+                    match obj {
+                        Value::Object(Object::Object(ref o)) => Ok(Value::Boolean(o.borrow().is_sealed(false))),
+                        _ => Ok(Value::Boolean(true))
+                    }
+                }, function_prototype.clone()
+            ),
+            isExtensible => function!(
+                isExtensible(_context; obj; _args) {
+                    match obj {
+                        Value::Object(Object::Object(ref o)) => Ok(Value::Boolean(o.borrow().extensible)),
+                        _ => Ok(Value::Boolean(false))
+                    }
+                }, function_prototype.clone()
+            ),
             prototype => object_prototype.clone()
         },
         Number => object! {
@@ -148,8 +960,8 @@ fn create_stdlib() -> interpret::Object {
                 toString => function! (
                     toString(context; _args) {
                         match context.this {
-                            interpret::Value::Number(n) => Ok(interpret::Value::String(n.to_string())),
-                            _ => interpret::throw_string(format!("{:?} is not a number!", context.this))
+                            interpret::Value::Number(n) => Ok(interpret::Value::String(interpret::number_to_string(n))),
+                            _ => interpret::throw_error(interpret::ErrorKind::TypeError, format!("{} is not a number", context.this.debug_string()))
                         }
                     }, function_prototype.clone()
                 )
@@ -163,20 +975,42 @@ fn create_stdlib() -> interpret::Object {
                     toString(context; _args) {
                         match context.this {
                             interpret::Value::String(s) => Ok(interpret::Value::String(s.to_string())),
-                            _ => interpret::throw_string(format!("{:?} is not a string!", context.this))
+                            _ => interpret::throw_error(interpret::ErrorKind::TypeError, format!("{} is not a string", context.this.debug_string()))
                         }
                     }, function_prototype.clone()
                 )
             }
         },
+        JSON => object! {
+            object_prototype.clone(),
+            parse => function!(
+                parse(context; text, reviver; _args) {
+                    let text = try!(text.js_to_string(&context));
+                    json::parse(&text, &reviver, &context)
+                }, function_prototype.clone()
+            ),
+            stringify => function!(
+                stringify(context; value, replacer, space; _args) {
+                    json::stringify(value, &replacer, &space, &context)
+                }, function_prototype.clone()
+            )
+        },
         eval => function!(
             eval(context; String source; _args) {
-                context.eval(&source)
+                // Evaluated code has no file name of its own
+                context.eval(&source, None).map_err(|e| e.into_value(&Context {file: None, .. context.clone()}))
             }, function_prototype.clone()
         ),
         Function => object! {
             object_prototype.clone(),
             prototype => function_prototype
-        }
+        },
+        Array => array,
+        Error => error,
+        TypeError => type_error,
+        ReferenceError => reference_error,
+        SyntaxError => syntax_error,
+        RangeError => range_error,
+        EvalError => eval_error
     }
 }
@@ -0,0 +1,564 @@
+//! Serde support, so that any `Serialize` type can become a JavaScript value and any
+//! `Deserialize` type can be read back out of one.
+//!
+//! Values are laid out in the same way as the derived `ToValue` and `FromValue` implementations:
+//! structs and maps become objects, sequences and tuples become arrays, unit variants become
+//! their name, and other variants become an object with their name as its only property.
+
+use std::{error, fmt};
+use std::cell::RefCell;
+
+use serde::{ser, de};
+use serde::de::IntoDeserializer;
+
+use super::interpret::{self, Value, Object, Context};
+
+/// How deeply objects can be nested before reading them fails, so that the native stack can't overflow
+const MAX_DEPTH: usize = 512;
+
+/// A value that couldn't be serialized or deserialized
+#[derive(Debug, Clone, PartialEq)]
+pub enum Error {
+    /// An object contains itself, so it would never finish being read
+    Cycle,
+    /// Objects are nested more deeply than can be read
+    TooDeep,
+    /// Functions can't be turned into Rust data
+    Function(String),
+    /// A script error, thrown while reading or creating an object
+    Thrown(Value),
+    Message(String)
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &Error::Cycle => write!(fmt, "cyclic object value"),
+            &Error::TooDeep => write!(fmt, "nesting is too deep"),
+            &Error::Function(ref function) => write!(fmt, "{} is a function, which can't be deserialized", function),
+            &Error::Thrown(ref value) => write!(fmt, "uncaught exception: {}", value.debug_string()),
+            &Error::Message(ref message) => write!(fmt, "{}", message)
+        }
+    }
+}
+
+impl error::Error for Error {
+    fn description(&self) -> &str {
+        match self {
+            &Error::Cycle => "cyclic object value",
+            &Error::TooDeep => "nesting is too deep",
+            &Error::Function(_) => "function can't be deserialized",
+            &Error::Thrown(_) => "uncaught exception",
+            &Error::Message(ref message) => message
+        }
+    }
+}
+
+impl ser::Error for Error {
+    fn custom<T: fmt::Display>(message: T) -> Error {
+        Error::Message(message.to_string())
+    }
+}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(message: T) -> Error {
+        Error::Message(message.to_string())
+    }
+}
+
+impl From<Value> for Error {
+    fn from(thrown: Value) -> Error {
+        Error::Thrown(thrown)
+    }
+}
+
+/// Converts `value` into a JavaScript value, with objects getting their prototypes from `context.global`
+pub fn to_value<T: ser::Serialize + ?Sized>(value: &T, context: &Context) -> Result<Value, Error> {
+    value.serialize(Serializer { context: context })
+}
+
+/// Reads a `T` out of a JavaScript value
+pub fn from_value<T: de::DeserializeOwned>(value: Value, context: &Context) -> Result<T, Error> {
+    let ancestors = RefCell::new(vec![]);
+    T::deserialize(Deserializer { value: value, context: context, ancestors: &ancestors })
+}
+
+/// Wraps the contents of an enum variant in an object with the variant's name as its only property
+fn variant(name: &str, contents: Value, context: &Context) -> Result<Value, Error> {
+    let object = try!(interpret::new_object(context.global.clone()));
+    try!(object.set(name, contents));
+    Ok(Value::Object(object))
+}
+
+pub struct Serializer<'a> {
+    context: &'a Context
+}
+
+impl<'a> ser::Serializer for Serializer<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    type SerializeSeq = SerializeArray<'a>;
+    type SerializeTuple = SerializeArray<'a>;
+    type SerializeTupleStruct = SerializeArray<'a>;
+    type SerializeTupleVariant = SerializeArray<'a>;
+    type SerializeMap = SerializeObject<'a>;
+    type SerializeStruct = SerializeObject<'a>;
+    type SerializeStructVariant = SerializeObject<'a>;
+
+    fn serialize_bool(self, v: bool) -> Result<Value, Error> {
+        Ok(Value::Boolean(v))
+    }
+
+    fn serialize_i8(self, v: i8) -> Result<Value, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_i16(self, v: i16) -> Result<Value, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_i32(self, v: i32) -> Result<Value, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_i64(self, v: i64) -> Result<Value, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_u8(self, v: u8) -> Result<Value, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_u16(self, v: u16) -> Result<Value, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_u32(self, v: u32) -> Result<Value, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_u64(self, v: u64) -> Result<Value, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f32(self, v: f32) -> Result<Value, Error> {
+        self.serialize_f64(v as f64)
+    }
+
+    fn serialize_f64(self, v: f64) -> Result<Value, Error> {
+        Ok(Value::Number(v))
+    }
+
+    fn serialize_char(self, v: char) -> Result<Value, Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<Value, Error> {
+        Ok(Value::String(v.to_string()))
+    }
+
+    /// Bytes become an array of numbers
+    fn serialize_bytes(self, v: &[u8]) -> Result<Value, Error> {
+        let elements = v.iter().map(|&byte| Value::Number(byte as f64)).collect();
+        Ok(Value::Object(try!(interpret::new_array(elements, self.context.global.clone()))))
+    }
+
+    fn serialize_none(self) -> Result<Value, Error> {
+        Ok(Value::Undefined)
+    }
+
+    fn serialize_some<T: ser::Serialize + ?Sized>(self, value: &T) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<Value, Error> {
+        Ok(Value::Undefined)
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<Value, Error> {
+        Ok(Value::Object(Object::Null))
+    }
+
+    fn serialize_unit_variant(self, _name: &'static str, _index: u32, variant: &'static str) -> Result<Value, Error> {
+        Ok(Value::String(variant.to_string()))
+    }
+
+    fn serialize_newtype_struct<T: ser::Serialize + ?Sized>(self, _name: &'static str, value: &T) -> Result<Value, Error> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: ser::Serialize + ?Sized>(self, _name: &'static str, _index: u32, name: &'static str, value: &T) -> Result<Value, Error> {
+        let context = self.context;
+        variant(name, try!(value.serialize(self)), context)
+    }
+
+    fn serialize_seq(self, length: Option<usize>) -> Result<SerializeArray<'a>, Error> {
+        Ok(SerializeArray {
+            elements: Vec::with_capacity(length.unwrap_or(0)),
+            variant: None,
+            context: self.context
+        })
+    }
+
+    fn serialize_tuple(self, length: usize) -> Result<SerializeArray<'a>, Error> {
+        self.serialize_seq(Some(length))
+    }
+
+    fn serialize_tuple_struct(self, _name: &'static str, length: usize) -> Result<SerializeArray<'a>, Error> {
+        self.serialize_seq(Some(length))
+    }
+
+    fn serialize_tuple_variant(self, _name: &'static str, _index: u32, name: &'static str, length: usize) -> Result<SerializeArray<'a>, Error> {
+        let mut array = try!(self.serialize_seq(Some(length)));
+        array.variant = Some(name);
+        Ok(array)
+    }
+
+    fn serialize_map(self, _length: Option<usize>) -> Result<SerializeObject<'a>, Error> {
+        Ok(SerializeObject {
+            object: try!(interpret::new_object(self.context.global.clone())),
+            key: None,
+            variant: None,
+            context: self.context
+        })
+    }
+
+    fn serialize_struct(self, _name: &'static str, length: usize) -> Result<SerializeObject<'a>, Error> {
+        self.serialize_map(Some(length))
+    }
+
+    fn serialize_struct_variant(self, _name: &'static str, _index: u32, name: &'static str, length: usize) -> Result<SerializeObject<'a>, Error> {
+        let mut object = try!(self.serialize_map(Some(length)));
+        object.variant = Some(name);
+        Ok(object)
+    }
+}
+
+pub struct SerializeArray<'a> {
+    elements: Vec<Value>,
+    /// The name of the enum variant being serialized, if any
+    variant: Option<&'static str>,
+    context: &'a Context
+}
+
+impl<'a> SerializeArray<'a> {
+    fn push<T: ser::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let element = try!(value.serialize(Serializer { context: self.context }));
+        self.elements.push(element);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, Error> {
+        let array = Value::Object(try!(interpret::new_array(self.elements, self.context.global.clone())));
+
+        match self.variant {
+            Some(name) => variant(name, array, self.context),
+            None => Ok(array)
+        }
+    }
+}
+
+impl<'a> ser::SerializeSeq for SerializeArray<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ser::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeTuple for SerializeArray<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_element<T: ser::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeTupleStruct for SerializeArray<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ser::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeTupleVariant for SerializeArray<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ser::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        self.push(value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+pub struct SerializeObject<'a> {
+    object: Object,
+    /// A map key that is waiting for its value
+    key: Option<String>,
+    /// The name of the enum variant being serialized, if any
+    variant: Option<&'static str>,
+    context: &'a Context
+}
+
+impl<'a> SerializeObject<'a> {
+    fn insert<T: ser::Serialize + ?Sized>(&mut self, key: &str, value: &T) -> Result<(), Error> {
+        let value = try!(value.serialize(Serializer { context: self.context }));
+        try!(self.object.set(key, value));
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Value, Error> {
+        match self.variant {
+            Some(name) => variant(name, Value::Object(self.object), self.context),
+            None => Ok(Value::Object(self.object))
+        }
+    }
+}
+
+impl<'a> ser::SerializeMap for SerializeObject<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    /// Keys must be strings or numbers, since they become property names
+    fn serialize_key<T: ser::Serialize + ?Sized>(&mut self, key: &T) -> Result<(), Error> {
+        self.key = match try!(key.serialize(Serializer { context: self.context })) {
+            Value::String(key) => Some(key),
+            Value::Number(n) => Some(interpret::number_to_string(n)),
+            key => return Err(Error::Message(format!("{} can't be used as a property name", key.debug_string())))
+        };
+        Ok(())
+    }
+
+    fn serialize_value<T: ser::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), Error> {
+        let key = try!(self.key.take().ok_or_else(|| Error::Message("map value without a key".to_string())));
+        self.insert(&key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeStruct for SerializeObject<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ser::Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+impl<'a> ser::SerializeStructVariant for SerializeObject<'a> {
+    type Ok = Value;
+    type Error = Error;
+
+    fn serialize_field<T: ser::Serialize + ?Sized>(&mut self, key: &'static str, value: &T) -> Result<(), Error> {
+        self.insert(key, value)
+    }
+
+    fn end(self) -> Result<Value, Error> {
+        self.finish()
+    }
+}
+
+pub struct Deserializer<'a> {
+    value: Value,
+    context: &'a Context,
+    /// The objects that are being read, which would make a cycle if they were reached again
+    ancestors: &'a RefCell<Vec<Object>>
+}
+
+impl<'a> Deserializer<'a> {
+    fn child(&self, value: Value) -> Deserializer<'a> {
+        Deserializer { value: value, context: self.context, ancestors: self.ancestors }
+    }
+
+    fn get(&self, key: &str) -> Result<Value, Error> {
+        Ok(try!(self.value.get_in(key, self.context)))
+    }
+
+    /// Reads the object's properties with `visit`, failing if the object is already being read or
+    /// is nested too deeply
+    fn enter<T, F>(&self, object: &Object, visit: F) -> Result<T, Error> where F: FnOnce() -> Result<T, Error> {
+        if self.ancestors.borrow().contains(object) {
+            return Err(Error::Cycle);
+        }
+
+        if self.ancestors.borrow().len() >= MAX_DEPTH {
+            return Err(Error::TooDeep);
+        }
+
+        self.ancestors.borrow_mut().push(object.clone());
+        let result = visit();
+        self.ancestors.borrow_mut().pop();
+        result
+    }
+}
+
+impl<'de, 'a> de::Deserializer<'de> for Deserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value.clone() {
+            // Whole numbers are visited as integers, so that they can be read into integer types
+            Value::Number(n) if n.fract() == 0.0 && n.abs() < 9007199254740992.0 => visitor.visit_i64(n as i64),
+            Value::Number(n) => visitor.visit_f64(n),
+            Value::Boolean(b) => visitor.visit_bool(b),
+            Value::String(s) => visitor.visit_string(s),
+            Value::Undefined | Value::Object(Object::Null) => visitor.visit_unit(),
+            ref function if function.is_function() => Err(Error::Function(function.debug_string())),
+            Value::Object(ref array) if array.is_array() => self.enter(array, || {
+                let length = try!(self.get("length")).to_uint32();
+                visitor.visit_seq(SeqAccess { deserializer: &self, index: 0, length: length })
+            }),
+            Value::Object(ref object) => self.enter(object, || {
+                let keys = try!(object.keys());
+                visitor.visit_map(MapAccess { deserializer: &self, keys: keys.into_iter(), value: None })
+            })
+        }
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self.value {
+            Value::Undefined | Value::Object(Object::Null) => visitor.visit_none(),
+            _ => visitor.visit_some(self)
+        }
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(self, _name: &'static str, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    /// Variants are either their name, or an object with their name as its only property
+    fn deserialize_enum<V: de::Visitor<'de>>(self, name: &'static str, _variants: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+        match self.value.clone() {
+            Value::String(variant) => visitor.visit_enum(variant.into_deserializer()),
+            Value::Object(ref object) if !self.value.is_function() => {
+                let mut keys = try!(object.keys());
+                if keys.len() != 1 {
+                    return Err(Error::Message(format!("{} is not a {}", self.value.debug_string(), name)));
+                }
+
+                let variant = keys.pop().unwrap();
+                let contents = try!(self.get(&variant));
+                self.enter(object, || visitor.visit_enum(EnumAccess { deserializer: self.child(contents), variant: variant }))
+            },
+            _ => Err(Error::Message(format!("{} is not a {}", self.value.debug_string(), name)))
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+struct SeqAccess<'a, 'b: 'a> {
+    deserializer: &'a Deserializer<'b>,
+    index: u32,
+    length: u32
+}
+
+impl<'de, 'a, 'b> de::SeqAccess<'de> for SeqAccess<'a, 'b> {
+    type Error = Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(&mut self, seed: T) -> Result<Option<T::Value>, Error> {
+        if self.index >= self.length {
+            return Ok(None);
+        }
+
+        let element = try!(self.deserializer.get(&self.index.to_string()));
+        self.index += 1;
+        seed.deserialize(self.deserializer.child(element)).map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some((self.length - self.index) as usize)
+    }
+}
+
+struct MapAccess<'a, 'b: 'a> {
+    deserializer: &'a Deserializer<'b>,
+    keys: ::std::vec::IntoIter<String>,
+    /// The value for the key that was just read
+    value: Option<Value>
+}
+
+impl<'de, 'a, 'b> de::MapAccess<'de> for MapAccess<'a, 'b> {
+    type Error = Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(&mut self, seed: K) -> Result<Option<K::Value>, Error> {
+        match self.keys.next() {
+            Some(key) => {
+                self.value = Some(try!(self.deserializer.get(&key)));
+                seed.deserialize(key.into_deserializer()).map(Some)
+            },
+            None => Ok(None)
+        }
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        let value = try!(self.value.take().ok_or_else(|| Error::Message("map value without a key".to_string())));
+        seed.deserialize(self.deserializer.child(value))
+    }
+}
+
+struct EnumAccess<'a> {
+    deserializer: Deserializer<'a>,
+    variant: String
+}
+
+impl<'de, 'a> de::EnumAccess<'de> for EnumAccess<'a> {
+    type Error = Error;
+    type Variant = Deserializer<'a>;
+
+    fn variant_seed<V: de::DeserializeSeed<'de>>(self, seed: V) -> Result<(V::Value, Deserializer<'a>), Error> {
+        let variant: de::value::StringDeserializer<Error> = self.variant.into_deserializer();
+        Ok((try!(seed.deserialize(variant)), self.deserializer))
+    }
+}
+
+/// The contents of a variant that was written as an object
+impl<'de, 'a> de::VariantAccess<'de> for Deserializer<'a> {
+    type Error = Error;
+
+    fn unit_variant(self) -> Result<(), Error> {
+        de::Deserialize::deserialize(self)
+    }
+
+    fn newtype_variant_seed<T: de::DeserializeSeed<'de>>(self, seed: T) -> Result<T::Value, Error> {
+        seed.deserialize(self)
+    }
+
+    fn tuple_variant<V: de::Visitor<'de>>(self, _length: usize, visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_seq(self, visitor)
+    }
+
+    fn struct_variant<V: de::Visitor<'de>>(self, _fields: &'static [&'static str], visitor: V) -> Result<V::Value, Error> {
+        de::Deserializer::deserialize_map(self, visitor)
+    }
+}
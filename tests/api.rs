@@ -0,0 +1,297 @@
+extern crate yuk;
+
+use std::rc::Rc;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+use yuk::runtime::{Yuk, Error};
+use yuk::interpret::{Value, Object, Function, Frame, HostObject, ErrorKind, throw_error};
+
+#[test]
+fn syntax_errors_are_reported_separately() {
+    let mut yuk = Yuk::create_stdlib();
+
+    match yuk.eval("var x = 1;\nvar = 2;", Some("bad.js")) {
+        Err(Error::Syntax(e)) => {
+            assert_eq!((e.line, e.column, e.offset), (2, 5, 15));
+            assert_eq!(e.expected, vec!["identifier".to_string()]);
+            assert_eq!(e.excerpt, "var = 2;\n    ^");
+            assert_eq!(e.to_string(), "unexpected '=', expected identifier at line 2, column 5\nvar = 2;\n    ^");
+        },
+        other => panic!("expected a syntax error, got {:?}", other)
+    }
+}
+
+#[test]
+fn thrown_values_are_available() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let error = yuk.eval("throw 'oops';", None).unwrap_err();
+    match error.thrown() {
+        Some(&Value::String(ref s)) => assert_eq!(s, "oops"),
+        other => panic!("expected a thrown string, got {:?}", other)
+    }
+
+    let error = yuk.eval("var nothing;\nnothing.property;", Some("script.js")).unwrap_err();
+    assert_eq!(error.to_string(), "TypeError: undefined has no properties (script.js:2:1)");
+    match error.thrown() {
+        Some(value) => assert_eq!(value.get("name", yuk.global.clone()).unwrap().debug_string(), "TypeError"),
+        None => panic!("expected a thrown error")
+    }
+}
+
+#[test]
+fn deep_recursion_throws_a_range_error() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let source = "
+        function forever(n) { return forever(n + 1); }
+        var caught;
+        try {
+            forever(0);
+        } catch (e) {
+            caught = e instanceof RangeError;
+        }
+        caught;
+    ";
+
+    assert_eq!(yuk.eval(source, None).unwrap().debug_string(), "true");
+
+    // An overflow that the script doesn't catch is reported as a limit rather than a thrown value
+    match yuk.eval("forever(0);", None) {
+        Err(Error::ResourceLimit(message)) => assert_eq!(message, "more than 5000 nested calls"),
+        other => panic!("expected the recursion to be stopped, got {:?}", other)
+    }
+    match yuk.eval("try { forever(0); } catch (e) { throw e; }", None) {
+        Err(e @ Error::ResourceLimit(_)) => assert_eq!(e.to_string(), "evaluation aborted: more than 5000 nested calls"),
+        other => panic!("expected the rethrown overflow to be stopped, got {:?}", other)
+    }
+
+    // Scripts throwing their own RangeErrors are unaffected
+    match yuk.eval("throw new RangeError('too far');", None) {
+        Err(Error::Thrown(e)) => assert_eq!(e.debug_string(), "RangeError: too far (<anonymous>:1:7)"),
+        other => panic!("expected a thrown RangeError, got {:?}", other)
+    }
+
+    // The interpreter can still be used afterwards
+    assert_eq!(yuk.eval("function f(n) { return n; }\nf(5);", None).unwrap().debug_string(), "5");
+}
+
+#[test]
+fn unbalanced_call_stacks_are_internal_errors() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let leak = Function::native("leak", |_arguments, context| {
+        try!(context.stack.push(Frame {function: None, file: None, native: true, position: None}));
+        Ok(Value::Undefined)
+    });
+    yuk.global.set("leak", Value::from_function(leak, yuk.global.clone())).unwrap();
+
+    match yuk.eval("leak();", None) {
+        Err(e @ Error::Internal(_)) => assert_eq!(e.to_string(), "internal error: 1 call frames were left on the stack"),
+        other => panic!("expected an internal error, got {:?}", other)
+    }
+
+    // The stack is cleaned up for the next evaluation
+    assert_eq!(yuk.stack.depth(), 0);
+    assert_eq!(yuk.eval("1 + 1;", None).unwrap().debug_string(), "2");
+}
+
+#[test]
+fn errors_become_values_for_scripts() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let value = yuk.eval("var caught;\ntry { eval('var = 1;'); } catch (e) { caught = e instanceof SyntaxError; }\ncaught;", None).unwrap();
+    assert_eq!(value.debug_string(), "true");
+}
+
+#[test]
+fn native_functions_can_capture_state() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let log = Rc::new(RefCell::new(vec![]));
+    let sink = log.clone();
+    let record = Function::native("record", move |arguments, _context| {
+        sink.borrow_mut().extend(arguments.iter().map(Value::debug_string));
+        Ok(Value::Number(sink.borrow().len() as f64))
+    });
+    yuk.global.set("record", Value::from_function(record, yuk.global.clone())).unwrap();
+
+    let count = yuk.eval("record(1, 'two');\n[3].forEach(record);\nrecord();", None).unwrap();
+
+    assert_eq!(count.debug_string(), "5");
+    assert_eq!(*log.borrow(), vec!["1", "two", "3", "0", "[3]"]);
+}
+
+#[test]
+fn rust_functions_convert_their_arguments() {
+    use std::collections::HashMap;
+
+    let mut yuk = Yuk::create_stdlib();
+
+    yuk.register("repeat", |text: String, times: u32| text.repeat(times as usize)).unwrap();
+    yuk.register("sum", |numbers: Vec<f64>| numbers.iter().sum::<f64>()).unwrap();
+    yuk.register("greet", |name: Option<String>| format!("hello {}", name.unwrap_or("world".to_string()))).unwrap();
+    yuk.register("total", |prices: HashMap<String, f64>| prices.values().sum::<f64>()).unwrap();
+    yuk.register("split", |text: String| text.split(',').map(|s| s.to_string()).collect::<Vec<_>>()).unwrap();
+    yuk.register("positive", |n: f64| if n > 0.0 { Ok(true) } else { Err(format!("{} is too small", n)) }).unwrap();
+    yuk.register("unsigned", |n: u64| n.to_string()).unwrap();
+    yuk.register("signed", |n: i64| n.to_string()).unwrap();
+
+    let mut eval = |source: &str| match yuk.eval(source, None) {
+        Ok(value) => value.debug_string(),
+        Err(e) => e.to_string()
+    };
+
+    assert_eq!(eval("repeat('ab', 3);"), "ababab");
+    assert_eq!(eval("sum([1, 2, 3.5]);"), "6.5");
+    assert_eq!(eval("greet();"), "hello world");
+    assert_eq!(eval("greet(null);"), "hello world");
+    assert_eq!(eval("greet('yuk');"), "hello yuk");
+    assert_eq!(eval("total({apple: 1.5, pear: 2});"), "3.5");
+    assert_eq!(eval("split('a,b,c').join(' ');"), "a b c");
+    assert_eq!(eval("split('a,b') instanceof Array;"), "true");
+    assert_eq!(eval("positive(1);"), "true");
+
+    // Arguments of the wrong type throw TypeErrors
+    assert_eq!(eval("repeat(5, 3);"), "TypeError: 5 is not a string (<anonymous>:1:1)");
+    assert_eq!(eval("repeat('ab', -1);"), "TypeError: -1 is not an integer between 0 and 4294967295 (<anonymous>:1:1)");
+    assert_eq!(eval("repeat('ab', 1.5);"), "TypeError: 1.5 is not an integer between 0 and 4294967295 (<anonymous>:1:1)");
+    assert_eq!(eval("sum([1, 'two']);"), "TypeError: two is not a number (<anonymous>:1:1)");
+    assert_eq!(eval("sum({});"), "TypeError: {} is not an array (<anonymous>:1:1)");
+    assert_eq!(eval("repeat('ab');"), "TypeError: undefined is not a number (<anonymous>:1:1)");
+    assert_eq!(eval("unsigned(9007199254740992);"), "9007199254740992");
+    assert_eq!(eval("unsigned(18446744073709551616);"), "TypeError: 18446744073709552000 is not an integer between 0 and 18446744073709551615 (<anonymous>:1:1)");
+    assert_eq!(eval("signed(-9223372036854775808);"), "-9223372036854775808");
+    assert_eq!(eval("signed(9223372036854775808);"), "TypeError: 9223372036854776000 is not an integer between -9223372036854775808 and 9223372036854775807 (<anonymous>:1:1)");
+    assert_eq!(eval("var huge = [];\nhuge.length = 4294967295;\nsum(huge);"), "RangeError: array of length 4294967295 is too long to convert (<anonymous>:3:1)");
+
+    // Errors returned by the function are thrown
+    assert_eq!(eval("positive(-1);"), "Error: -1 is too small (<anonymous>:1:1)");
+    assert_eq!(eval("var caught;\ntry { positive(0); } catch (e) { caught = e.message; }\ncaught;"), "0 is too small");
+}
+
+#[test]
+fn registering_on_a_frozen_global_fails() {
+    let mut yuk = Yuk::create_stdlib();
+
+    yuk.eval("Object.freeze(this);", None).unwrap();
+    match yuk.register("double", |n: f64| n * 2.0) {
+        Err(Error::Thrown(e)) => assert!(e.debug_string().starts_with("TypeError: can't define property \"double\"")),
+        other => panic!("expected registering to fail, got {:?}", other)
+    }
+    assert_eq!(yuk.eval("this.hasOwnProperty('double');", None).unwrap().debug_string(), "false");
+}
+
+/// Settings that are shared with Rust, where only numbers can be stored
+struct Settings(Rc<RefCell<BTreeMap<String, f64>>>);
+
+impl HostObject for Settings {
+    fn get(&self, key: &str) -> Option<Value> {
+        self.0.borrow().get(key).map(|&n| Value::Number(n))
+    }
+
+    fn set(&mut self, key: &str, value: Value) -> Result<(), Value> {
+        match value {
+            Value::Number(n) => {
+                self.0.borrow_mut().insert(key.to_string(), n);
+                Ok(())
+            },
+            v => throw_error(ErrorKind::TypeError, format!("{} is not a number", v.debug_string()))
+        }
+    }
+
+    fn delete(&mut self, key: &str) -> bool {
+        self.0.borrow_mut().remove(key);
+        true
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.0.borrow().keys().cloned().collect()
+    }
+}
+
+#[test]
+fn host_objects_use_rust_data() {
+    let mut yuk = Yuk::create_stdlib();
+    let data = Rc::new(RefCell::new(BTreeMap::new()));
+    data.borrow_mut().insert("width".to_string(), 80.0);
+
+    let object_prototype = yuk.global.get("Object").unwrap().get("prototype", yuk.global.clone()).unwrap();
+    let prototype = match object_prototype {
+        Value::Object(o) => o,
+        _ => panic!("expected Object.prototype")
+    };
+    let settings = Object::from_host(Settings(data.clone()), prototype);
+    yuk.global.set("settings", Value::from(settings.clone())).unwrap();
+
+    let mut eval = |source: &str| match yuk.eval(source, None) {
+        Ok(value) => value.debug_string(),
+        Err(e) => e.to_string()
+    };
+
+    assert_eq!(eval("settings.width;"), "80");
+    assert_eq!(eval("settings.height;"), "undefined");
+    assert_eq!(eval("settings.height = settings.width / 2;"), "40");
+    assert_eq!(eval("settings.hasOwnProperty('height');"), "true");
+    assert_eq!(eval("settings.toString();"), "[object Object]");
+    assert_eq!(eval("Object.keys(settings).join();"), "height,width");
+    assert_eq!(eval("settings.width = 'wide';"), "TypeError: wide is not a number (<anonymous>:1:1)");
+
+    // Changes go both ways
+    assert_eq!(data.borrow().get("height"), Some(&40.0));
+    data.borrow_mut().insert("depth".to_string(), 3.0);
+    assert_eq!(eval("settings.depth;"), "3");
+
+    assert_eq!(settings.delete("depth"), Ok(true));
+    assert_eq!(settings.has_own("depth"), Ok(false));
+    assert_eq!(settings.keys(), Ok(vec!["height".to_string(), "width".to_string()]));
+}
+
+#[test]
+fn objects_print_in_order() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let value = yuk.eval("var o = {b: 1, a: {d: 2, c: 3}};\no[1] = 4;\no;", None).unwrap();
+    assert_eq!(value.debug_string(), "{\"1\": 4, \"b\": 1, \"a\": {\"d\": 2, \"c\": 3}}");
+}
+
+#[test]
+fn sparse_arrays_print_their_holes() {
+    let mut yuk = Yuk::create_stdlib();
+
+    assert_eq!(yuk.eval("[1, , 3];", None).unwrap().debug_string(), "[1, <1 empty item>, 3]");
+    let value = yuk.eval("var huge = [1, 2];
+huge.length = 4294967295;
+huge;", None).unwrap();
+    assert_eq!(value.debug_string(), "[1, 2, <4294967293 empty items>]");
+}
+
+#[test]
+fn getters_run_when_read_from_rust() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let value = yuk.eval("var o = {size: 2, get area() { return this.size * this.size; }, set area(a) {}};\no;", None).unwrap();
+    assert_eq!(value.debug_string(), "{\"size\": 2, \"area\": [Getter/Setter]}");
+    assert_eq!(value.get("area", yuk.global.clone()).unwrap(), Value::Number(4.0));
+
+    // Setters need a context to run in, which plain assignment from Rust doesn't have
+    assert!(value.set("area", Value::Number(9.0)).is_err());
+    assert_eq!(value.set("size", Value::Number(3.0)).unwrap(), Value::Number(3.0));
+    assert_eq!(value.get("area", yuk.global.clone()).unwrap(), Value::Number(9.0));
+}
+
+#[test]
+fn top_level_let_persists_between_evals() {
+    let mut yuk = Yuk::create_stdlib();
+
+    yuk.eval("let count = 1;\nconst limit = 2;", None).unwrap();
+    assert_eq!(yuk.eval("count = count + limit;\ncount;", None).unwrap(), Value::Number(3.0));
+
+    // Unlike `var`, these don't become properties of the global object
+    assert_eq!(yuk.global.get("count").unwrap(), Value::Undefined);
+    match yuk.eval("let count = 2;", None) {
+        Err(Error::Thrown(e)) => assert_eq!(e.debug_string(), "SyntaxError: redeclaration of count (<anonymous>:1:1)"),
+        other => panic!("expected a redeclaration error, got {:?}", other)
+    }
+}
@@ -0,0 +1,143 @@
+extern crate yuk;
+#[macro_use]
+extern crate yuk_derive;
+
+use std::collections::HashMap;
+
+use yuk::runtime::Yuk;
+use yuk::interpret::{Value, ToValue};
+use yuk::convert::FromValue;
+
+#[derive(Debug, Clone, PartialEq, ToValue, FromValue)]
+struct Address {
+    street: String,
+    number: u32
+}
+
+#[derive(Debug, Clone, PartialEq, ToValue, FromValue)]
+struct Person {
+    name: String,
+    age: f64,
+    nickname: Option<String>,
+    address: Address,
+    tags: Vec<String>,
+    scores: HashMap<String, i32>
+}
+
+#[derive(Debug, Clone, PartialEq, ToValue, FromValue)]
+struct Meters(f64);
+
+#[derive(Debug, Clone, PartialEq, ToValue, FromValue)]
+struct Point(i32, i32);
+
+#[derive(Debug, Clone, PartialEq, ToValue, FromValue)]
+enum Shape {
+    Empty,
+    Circle(f64),
+    Line(Point, Point),
+    Rectangle { width: f64, height: f64 }
+}
+
+#[derive(Debug, Clone, PartialEq, ToValue, FromValue)]
+struct Wrapper<T> {
+    inner: T
+}
+
+fn person() -> Person {
+    Person {
+        name: "Ann".to_string(),
+        age: 31.0,
+        nickname: None,
+        address: Address {street: "Main".to_string(), number: 5},
+        tags: vec!["a".to_string(), "b".to_string()],
+        scores: vec![("maths".to_string(), 7)].into_iter().collect()
+    }
+}
+
+fn round_trip<T: ToValue + FromValue>(yuk: &mut Yuk, value: &T, script: &str) -> Result<T, Value> {
+    let js = value.to_value(yuk).unwrap();
+    yuk.global.set("value", js).unwrap();
+    yuk.eval(script, None).unwrap();
+    let result = yuk.global.get("value").unwrap();
+    T::from_value(result, yuk)
+}
+
+#[test]
+fn structs_become_objects() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let value = person().to_value(&yuk).unwrap();
+    yuk.global.set("person", value).unwrap();
+
+    let describe = "person.name + ' ' + person.age + ' ' + person.address.street + ' ' + person.tags.join('-') + \
+                    ' ' + person.scores.maths;";
+    assert_eq!(yuk.eval(describe, None).unwrap().debug_string(), "Ann 31 Main a-b 7");
+    assert_eq!(yuk.eval("person.nickname;", None).unwrap().debug_string(), "undefined");
+}
+
+#[test]
+fn structs_round_trip_after_changes() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let changed = round_trip(&mut yuk, &person(), "
+        value.age = value.age + 1;
+        value.nickname = 'Annie';
+        value.address.number = 10;
+        value.tags.push('c');
+        value.scores.art = 9;
+    ").unwrap();
+
+    let mut expected = person();
+    expected.age = 32.0;
+    expected.nickname = Some("Annie".to_string());
+    expected.address.number = 10;
+    expected.tags.push("c".to_string());
+    expected.scores.insert("art".to_string(), 9);
+
+    assert_eq!(changed, expected);
+
+    let wrapped = round_trip(&mut yuk, &Wrapper {inner: 1u8}, "value.inner = 2;").unwrap();
+    assert_eq!(wrapped, Wrapper {inner: 2});
+}
+
+#[test]
+fn tuple_structs_and_enums() {
+    let mut yuk = Yuk::create_stdlib();
+
+    assert_eq!(Meters(2.5).to_value(&yuk).unwrap().debug_string(), "2.5");
+    assert_eq!(Point(1, 2).to_value(&yuk).unwrap().debug_string(), "[1, 2]");
+    assert_eq!(Shape::Empty.to_value(&yuk).unwrap().debug_string(), "Empty");
+
+    assert_eq!(round_trip(&mut yuk, &Meters(1.0), "value = value * 3;").unwrap(), Meters(3.0));
+    assert_eq!(round_trip(&mut yuk, &Point(1, 2), "value[1] = 5;").unwrap(), Point(1, 5));
+    assert_eq!(round_trip(&mut yuk, &Shape::Circle(1.0), "value.Circle = 4;").unwrap(), Shape::Circle(4.0));
+    assert_eq!(round_trip(&mut yuk, &Shape::Empty, "value = {Circle: 2};").unwrap(), Shape::Circle(2.0));
+    assert_eq!(
+        round_trip(&mut yuk, &Shape::Line(Point(0, 0), Point(1, 1)), "value.Line[1][0] = 3;").unwrap(),
+        Shape::Line(Point(0, 0), Point(3, 1))
+    );
+    assert_eq!(
+        round_trip(&mut yuk, &Shape::Rectangle {width: 1.0, height: 2.0}, "value.Rectangle.width = 5;").unwrap(),
+        Shape::Rectangle {width: 5.0, height: 2.0}
+    );
+    assert_eq!(round_trip(&mut yuk, &Shape::Circle(1.0), "value = 'Empty';").unwrap(), Shape::Empty);
+}
+
+#[test]
+fn mismatched_values_are_type_errors() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let message = |yuk: &mut Yuk, error: Value| error.get("message", yuk.global.clone()).unwrap().debug_string();
+
+    let error = round_trip(&mut yuk, &person(), "value.age = 'old';").unwrap_err();
+    assert_eq!(message(&mut yuk, error), "old is not a number");
+
+    let error = round_trip(&mut yuk, &person(), "value = 5;").unwrap_err();
+    assert_eq!(message(&mut yuk, error), "5 is not a Person");
+
+    let error = round_trip(&mut yuk, &Shape::Empty, "value = 'Triangle';").unwrap_err();
+    assert_eq!(message(&mut yuk, error), "Triangle is not a variant of Shape");
+
+    let error = round_trip(&mut yuk, &Shape::Empty, "value = {};").unwrap_err();
+    assert_eq!(message(&mut yuk, error), "{} is not a Shape");
+}
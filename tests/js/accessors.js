@@ -0,0 +1,152 @@
+// Getters and setters in object literals
+
+var temperature = {
+  celsius: 20,
+  get fahrenheit() {
+    return this.celsius * 9 / 5 + 32;
+  },
+  set fahrenheit(value) {
+    this.celsius = (value - 32) * 5 / 9;
+  }
+};
+
+assert_eq(temperature.fahrenheit, 68);
+temperature.fahrenheit = 212;
+assert_eq(temperature.celsius, 100);
+assert_eq(temperature["fahrenheit"], 212);
+assert_eq(Object.keys(temperature).join(), "celsius,fahrenheit");
+
+// `get` and `set` are still ordinary property names
+var names = {get: 1, set: 2, getter: 3};
+assert_eq(names.get + names.set + names.getter, 6);
+
+// Accessors found on a prototype are called with the object they were accessed through
+var counter = {
+  get double() {
+    return this.count * 2;
+  },
+  set double(value) {
+    this.count = value / 2;
+  }
+};
+
+var first = Object.create(counter);
+first.count = 1;
+var second = Object.create(first);
+second.count = 5;
+
+assert_eq(first.double, 2);
+assert_eq(second.double, 10);
+second.double = 8;
+assert_eq(second.count, 4);
+assert_eq(first.count, 1);
+assert_eq(second.hasOwnProperty("double"), false);
+
+// A getter without a setter ignores assignments outside of strict mode
+var constant = {
+  get value() {
+    return 42;
+  }
+};
+
+constant.value = 1;
+assert_eq(constant.value, 42);
+
+function strict_assign(object, key, value) {
+  "use strict";
+  try {
+    object[key] = value;
+  } catch (e) {
+    return e.name + ": " + e.message;
+  }
+  return "no error";
+}
+
+assert_eq(strict_assign(constant, "value", 1), "TypeError: setting getter-only property \"value\"");
+assert_eq(strict_assign(Object.create(constant), "value", 1), "TypeError: setting getter-only property \"value\"");
+
+// A setter without a getter reads as undefined
+var log = [];
+var sink = {
+  set value(v) {
+    log.push(v);
+  }
+};
+
+sink.value = 1;
+sink.value = 2;
+assert_eq(sink.value, undefined);
+assert_eq(log.join(), "1,2");
+
+// Accessors defined with Object.defineProperty
+
+var person = {first: "Ada", last: "Lovelace"};
+Object.defineProperty(person, "name", {
+  get: function() { return this.first + " " + this.last; },
+  set: function(parts) {
+    this.first = parts[0];
+    this.last = parts[1];
+  },
+  configurable: true
+});
+
+assert_eq(person.name, "Ada Lovelace");
+person.name = ["Grace", "Hopper"];
+assert_eq(person.last, "Hopper");
+assert_eq(Object.keys(person).join(), "first,last");
+
+var descriptor = Object.getOwnPropertyDescriptor(person, "name");
+var other = {first: "Alan", last: "Turing", name: descriptor.get};
+assert_eq(other.name(), "Alan Turing");
+assert_eq(descriptor.hasOwnProperty("value"), false);
+assert_eq(descriptor.hasOwnProperty("writable"), false);
+assert_eq(descriptor.enumerable, false);
+assert_eq(descriptor.configurable, true);
+
+// Configurable accessors can be turned back into values
+Object.defineProperty(person, "name", {value: "someone"});
+assert_eq(person.name, "someone");
+assert_eq(Object.getOwnPropertyDescriptor(person, "name").writable, false);
+
+function define_error(object, key, descriptor) {
+  try {
+    Object.defineProperty(object, key, descriptor);
+  } catch (e) {
+    return e.name + ": " + e.message;
+  }
+  return "no error";
+}
+
+assert_eq(define_error({}, "x", {get: function() {}, value: 1}),
+  "TypeError: property descriptors must not specify a value or be writable when a getter or setter has been specified");
+assert_eq(define_error({}, "x", {get: 5}), "TypeError: property descriptor's get field is neither undefined nor a function");
+
+var fixed = {};
+var getter = function() { return 1; };
+Object.defineProperty(fixed, "x", {get: getter});
+assert_eq(define_error(fixed, "x", {get: getter}), "no error");
+assert_eq(define_error(fixed, "x", {get: function() { return 2; }}), "TypeError: can't redefine non-configurable property \"x\"");
+assert_eq(define_error(fixed, "x", {value: 2}), "TypeError: can't redefine non-configurable property \"x\"");
+assert_eq(fixed.x, 1);
+
+// Freezing keeps accessors working
+var frozen = Object.freeze({
+  count: 0,
+  get next() {
+    return this.count + 1;
+  }
+});
+
+assert_eq(frozen.next, 1);
+assert_eq(Object.isFrozen(frozen), true);
+
+// Getters on the prototypes of primitives see the primitive
+Object.defineProperty(String.prototype, "shout", {
+  get: function() { return this + "!"; },
+  configurable: true
+});
+var greeting = "hello";
+assert_eq(greeting.shout, "hello!");
+
+// Getters are used by library functions
+assert_eq(JSON.stringify(temperature), '{"celsius":100,"fahrenheit":212}');
@@ -0,0 +1,135 @@
+var numbers = [1, 2, 3, 4];
+
+// forEach
+var seen = [];
+assert_eq(numbers.forEach(function(n, i, array) {
+  seen.push(n + ":" + i);
+  assert_eq(array, numbers);
+}), undefined);
+assert_eq(seen.join(), "1:0,2:1,3:2,4:3");
+
+// map
+var doubled = numbers.map(function(n) { return n * 2; });
+assert_eq(doubled.join(), "2,4,6,8");
+assert_eq(numbers.join(), "1,2,3,4");
+assert_eq(numbers.map(function(n, i) { return i; }).join(), "0,1,2,3");
+assert_eq([].map(function(n) { return n; }).length, 0);
+
+// filter
+var even = numbers.filter(function(n) { return n % 2 === 0; });
+assert_eq(even.join(), "2,4");
+assert_eq(Array.isArray(even), true);
+assert_eq(numbers.filter(function() { return 0; }).length, 0);
+
+// reduce
+assert_eq(numbers.reduce(function(sum, n) { return sum + n; }), 10);
+assert_eq(numbers.reduce(function(sum, n) { return sum + n; }, 10), 20);
+assert_eq(numbers.reduce(function(acc, n, i) { return acc + i; }, ""), "0123");
+assert_eq([].reduce(function(a, b) { return a + b; }, "initial"), "initial");
+assert_eq([5].reduce(function(a, b) { return a + b; }), 5);
+
+var empty_reduce = false;
+try {
+  [].reduce(function(a, b) { return a + b; });
+} catch (e) {
+  empty_reduce = true;
+}
+assert_eq(empty_reduce, true);
+
+// some and every
+assert_eq(numbers.some(function(n) { return n > 3; }), true);
+assert_eq(numbers.some(function(n) { return n > 4; }), false);
+assert_eq(numbers.every(function(n) { return n > 0; }), true);
+assert_eq(numbers.every(function(n) { return n > 1; }), false);
+assert_eq([].some(function() { return true; }), false);
+assert_eq([].every(function() { return false; }), true);
+
+// some and every stop early
+var calls = 0;
+numbers.some(function(n) { calls++; return n === 2; });
+assert_eq(calls, 2);
+calls = 0;
+numbers.every(function(n) { calls++; return n < 2; });
+assert_eq(calls, 2);
+
+// find
+assert_eq(numbers.find(function(n) { return n > 2; }), 3);
+assert_eq(numbers.find(function(n) { return n > 10; }), undefined);
+
+// this argument
+var scale = {factor: 10};
+assert_eq(numbers.map(function(n) { return n * this.factor; }, scale).join(), "10,20,30,40");
+
+// Chaining
+var result = [5, 1, 4, 2, 3]
+  .filter(function(n) { return n !== 4; })
+  .map(function(n) { return n * n; })
+  .sort(function(a, b) { return a - b; })
+  .join(" ");
+assert_eq(result, "1 4 9 25");
+
+var people = [{name: "Ann", age: 31}, {name: "Bob", age: 17}, {name: "Cy", age: 45}];
+var adults = people
+  .filter(function(p) { return p.age >= 18; })
+  .map(function(p) { return p.name; });
+assert_eq(adults.join(), "Ann,Cy");
+assert_eq(people.reduce(function(total, p) { return total + p.age; }, 0), 93);
+
+// Errors from callbacks propagate
+var caught;
+try {
+  numbers.map(function(n) {
+    if (n === 3) throw "three";
+    return n;
+  });
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught, "three");
+
+// A callback that isn't a function is an error
+var not_callable = false;
+try {
+  numbers.forEach(5);
+} catch (e) {
+  not_callable = true;
+}
+assert_eq(not_callable, true);
+
+// Callbacks may modify themselves and call back into arrays
+function counter(n) {
+  counter.total = (counter.total || 0) + n;
+  return [n].map(function(x) { return x + 1; })[0];
+}
+assert_eq(numbers.map(counter).join(), "2,3,4,5");
+assert_eq(counter.total, 10);
+
+// Nested callbacks
+var table = [1, 2].map(function(a) {
+  return [1, 2].map(function(b) { return a * b; }).join("-");
+});
+assert_eq(table.join(), "1-2,2-4");
+
+// Holes are skipped, except by find
+var sparse = [1, , 3];
+var visited = [];
+sparse.forEach(function(n, i) { visited.push(i); });
+assert_eq(visited.join(), "0,2");
+var squared = sparse.map(function(n) { return n * n; });
+assert_eq(squared.length, 3);
+assert_eq(squared.hasOwnProperty(1), false);
+assert_eq(squared[2], 9);
+assert_eq(sparse.filter(function(n) { return true; }).join(), "1,3");
+assert_eq(sparse.every(function(n) { return n !== undefined; }), true);
+assert_eq(sparse.some(function(n) { return n === undefined; }), false);
+assert_eq(sparse.find(function(n) { return n === undefined; }), undefined);
+assert_eq([, 2, , 4].reduce(function(total, n) { return total + n; }), 6);
+var found = [];
+sparse.find(function(n, i) { found.push(i); });
+assert_eq(found.join(), "0,1,2");
+
+// Elements with getters are read by the callbacks
+var lazy = [1, 2];
+Object.defineProperty(lazy, "1", {get: function() { return this[0] + 10; }});
+assert_eq(lazy.map(function(n) { return n * 2; }).join(), "2,22");
+assert_eq(lazy.find(function(n) { return n > 10; }), 11);
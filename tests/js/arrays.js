@@ -0,0 +1,194 @@
+var empty = [];
+assert_eq(empty.length, 0);
+
+var list = [1, "two", 3];
+assert_eq(list.length, 3);
+assert_eq(list[0], 1);
+assert_eq(list[1], "two");
+assert_eq(list["2"], 3);
+assert_eq(list[3], undefined);
+assert_eq([4, 5, 6][1], 6 - 1);
+assert_eq([1, 2, 3].length, 3);
+
+// Holes
+var holes = [1, , 3];
+assert_eq(holes.length, 3);
+assert_eq(holes[1], undefined);
+assert_eq([1, 2, ].length, 2);
+assert_eq([, ].length, 1);
+assert_eq([1, , ].length, 2);
+
+var nested = [[1, 2], [3, [4]]];
+assert_eq(nested[1][1][0], 4);
+
+// length tracks index writes
+var grow = [];
+grow[0] = "a";
+assert_eq(grow.length, 1);
+grow[4] = "e";
+assert_eq(grow.length, 5);
+assert_eq(grow[2], undefined);
+grow.name = "not an index";
+assert_eq(grow.length, 5);
+grow["01"] = "not canonical";
+assert_eq(grow.length, 5);
+
+// Writing length truncates
+grow.length = 1;
+assert_eq(grow.length, 1);
+assert_eq(grow[4], undefined);
+assert_eq(grow[0], "a");
+grow.length = 3;
+assert_eq(grow.length, 3);
+assert_eq(grow[2], undefined);
+
+var bad_length = false;
+try {
+  grow.length = -1;
+} catch (e) {
+  bad_length = true;
+}
+assert_eq(bad_length, true);
+
+// Array constructor
+assert_eq(Array(3).length, 3);
+assert_eq((new Array(3)).length, 3);
+assert_eq((new Array(1, 2)).length, 2);
+assert_eq(Array("3").length, 1);
+assert_eq(Array("3")[0], "3");
+assert_eq(Array.isArray([]), true);
+assert_eq(Array.isArray({length: 0}), false);
+assert_eq(Array.isArray(new Array()), true);
+assert_eq([].constructor === Array, true);
+
+// push and pop
+var stack = [1];
+assert_eq(stack.push(2, 3), 3);
+assert_eq(stack.length, 3);
+assert_eq(stack[2], 3);
+assert_eq(stack.pop(), 3);
+assert_eq(stack.length, 2);
+assert_eq(stack[2], undefined);
+assert_eq([].pop(), undefined);
+
+// join and toString
+assert_eq([1, 2, 3].join(), "1,2,3");
+assert_eq([1, 2, 3].join(" - "), "1 - 2 - 3");
+assert_eq([1, null, undefined, "x"].join(""), "1x");
+assert_eq([1, [2, 3]].toString(), "1,2,3");
+assert_eq([1, 2] + "", "1,2");
+assert_eq([].join(), "");
+
+// slice
+var letters = ["a", "b", "c", "d", "e"];
+assert_eq(letters.slice(1, 3).join(), "b,c");
+assert_eq(letters.slice(2).join(), "c,d,e");
+assert_eq(letters.slice(-2).join(), "d,e");
+assert_eq(letters.slice(1, -1).join(), "b,c,d");
+assert_eq(letters.slice(3, 1).length, 0);
+assert_eq(letters.slice().length, 5);
+assert_eq(letters.length, 5);
+
+// splice
+var removed = letters.splice(1, 2);
+assert_eq(removed.join(), "b,c");
+assert_eq(letters.join(), "a,d,e");
+removed = letters.splice(1, 0, "x", "y");
+assert_eq(removed.length, 0);
+assert_eq(letters.join(), "a,x,y,d,e");
+removed = letters.splice(-2);
+assert_eq(removed.join(), "d,e");
+assert_eq(letters.join(), "a,x,y");
+letters.splice(0, 1, "z");
+assert_eq(letters.join(), "z,x,y");
+
+// Elements after the removed ones move up or down to make room
+var shifting = [1, 2, 3, 4, 5];
+assert_eq(shifting.splice(1, 1, "a", "b", "c").join(), "2");
+assert_eq(shifting.join(), "1,a,b,c,3,4,5");
+assert_eq(shifting.splice(1, 3, "d").join(), "a,b,c");
+assert_eq(shifting.join(), "1,d,3,4,5");
+assert_eq(shifting.length, 5);
+
+// The number to remove is clamped to the elements after the start
+var clamped = [1, 2, 3];
+assert_eq(clamped.splice(0, -1).length, 0);
+assert_eq(clamped.join(), "1,2,3");
+assert_eq(clamped.splice(1, 10).join(), "2,3");
+assert_eq(clamped.join(), "1");
+assert_eq([1, 2, 3].splice(1, NaN).length, 0);
+assert_eq([1, 2, 3].splice(1, undefined).length, 0);
+
+// indexOf
+var numbers = [1, 2, 3, 2, 1];
+assert_eq(numbers.indexOf(2), 1);
+assert_eq(numbers.indexOf(2, 2), 3);
+assert_eq(numbers.indexOf(2, -2), 3);
+assert_eq(numbers.indexOf("2"), -1);
+assert_eq(numbers.indexOf(5), -1);
+
+// concat
+var joined = [1, 2].concat([3, 4], 5, [[6]]);
+assert_eq(joined.length, 6);
+assert_eq(joined.join(), "1,2,3,4,5,6");
+assert_eq(Array.isArray(joined[5]), true);
+
+// reverse
+var backwards = [1, 2, 3];
+assert_eq(backwards.reverse() === backwards, true);
+assert_eq(backwards.join(), "3,2,1");
+assert_eq([1, 2, 3, 4].reverse().join(), "4,3,2,1");
+assert_eq([].reverse().length, 0);
+
+// push and pop work on any object with a length
+var like = {length: 1, push: Array.prototype.push, pop: Array.prototype.pop};
+like[0] = "a";
+assert_eq(like.push("b", "c"), 3);
+assert_eq(like[2], "c");
+assert_eq(like.pop(), "c");
+assert_eq(like.length, 2);
+
+// sort
+var words = ["pear", "apple", "fig"];
+assert_eq(words.sort() === words, true);
+assert_eq(words.join(), "apple,fig,pear");
+assert_eq([10, 9, 1, 100].sort().join(), "1,10,100,9");
+assert_eq([10, 9, 1, 100].sort(function(a, b) { return a - b; }).join(), "1,9,10,100");
+assert_eq([3, undefined, 1].sort().join(), "1,3,");
+
+var sort_error;
+try {
+  [2, 1].sort(function(a, b) { throw "bad compare"; });
+} catch (e) {
+  sort_error = e;
+}
+assert_eq(sort_error, "bad compare");
+
+// Comparators that don't give a consistent order still leave every element in the array
+var shuffled = [];
+for (var k = 0; k < 50; k++) {
+  shuffled.push(k);
+}
+var flip = 1;
+shuffled.sort(function(a, b) { flip = -flip; return flip; });
+assert_eq(shuffled.length, 50);
+assert_eq(shuffled.slice().sort(function(a, b) { return a - b; }).join(), shuffled.sort(function(a, b) { return a - b; }).join());
+assert_eq(shuffled[0] + shuffled[49], 49);
+
+// Sorting is stable
+var pairs = [[1, "a"], [0, "b"], [1, "c"], [0, "d"]];
+pairs.sort(function(a, b) { return a[0] - b[0]; });
+assert_eq(pairs.join(";"), "0,b;0,d;1,a;1,c");
+
+// Arrays in loops
+var squares = [];
+for (var i = 0; i < 4; i++) {
+  squares.push(i * i);
+}
+assert_eq(squares.join(), "0,1,4,9");
+
+var sum = 0;
+for (var j = 0; j < squares.length; j++) {
+  sum += squares[j];
+}
+assert_eq(sum, 14);
@@ -0,0 +1,110 @@
+var a = 5;
+
+assert_eq(a++, 5);
+assert_eq(a, 6);
+assert_eq(++a, 7);
+assert_eq(a, 7);
+assert_eq(a--, 7);
+assert_eq(a, 6);
+assert_eq(--a, 5);
+assert_eq(a, 5);
+
+assert_eq(a++ + ++a, 12);
+assert_eq(a, 7);
+assert_eq(a+++a, 15);
+assert_eq(-a--, -8);
+assert_eq(a, 7);
+
+var s = "5";
+s++;
+assert_eq(s, 6);
+
+var word = "abc";
+word++;
+assert_eq(word !== word, true);
+
+// Compound assignment
+
+var n = 10;
+
+assert_eq(n += 5, 15);
+assert_eq(n -= 3, 12);
+assert_eq(n *= 2, 24);
+assert_eq(n /= 4, 6);
+assert_eq(n %= 4, 2);
+assert_eq(n <<= 3, 16);
+assert_eq(n >>= 1, 8);
+n = -8;
+assert_eq(n >>>= 28, 15);
+assert_eq(n &= 6, 6);
+assert_eq(n |= 9, 15);
+assert_eq(n ^= 5, 10);
+assert_eq(n, 10);
+
+var greeting = "Hello";
+greeting += ", world";
+assert_eq(greeting, "Hello, world");
+
+var total = 0;
+total += 1 + 2 * 3;
+assert_eq(total, 7);
+
+// Member targets
+
+var obj = {count: 0, nested: {value: 1}};
+
+obj.count++;
+++obj.count;
+assert_eq(obj.count, 2);
+
+obj["count"] += 10;
+assert_eq(obj.count, 12);
+
+obj.nested.value *= 3;
+assert_eq(obj.nested.value, 3);
+
+assert_eq(obj.nested["value"]--, 3);
+assert_eq(obj.nested.value, 2);
+
+// The object and key of a member target are evaluated exactly once
+
+var lookups = 0;
+function target() {
+  lookups++;
+  return obj;
+}
+
+var keys = 0;
+function key() {
+  keys++;
+  return "count";
+}
+
+target()[key()] += 1;
+assert_eq(obj.count, 13);
+assert_eq(lookups, 1);
+assert_eq(keys, 1);
+
+target()[key()]++;
+assert_eq(obj.count, 14);
+assert_eq(lookups, 2);
+assert_eq(keys, 2);
+
+--target().count;
+assert_eq(obj.count, 13);
+assert_eq(lookups, 3);
+
+// In loops
+
+var sum = 0;
+for (var i = 0; i < 5; i++) {
+  sum += i;
+}
+assert_eq(sum, 10);
+
+var j = 3;
+while (j--) {
+  sum -= 1;
+}
+assert_eq(sum, 7);
+assert_eq(j, -1);
@@ -0,0 +1,64 @@
+// Modulo keeps the sign of the dividend
+assert_eq(7 % 3, 1);
+assert_eq(-7 % 3, -1);
+assert_eq(7 % -3, 1);
+assert_eq(5.5 % 2, 1.5);
+assert_eq(2 + 7 % 3 * 2, 4);
+
+var nan = 1 % 0;
+assert_eq(nan !== nan, true);
+
+// Bitwise operators
+assert_eq(5 & 3, 1);
+assert_eq(5 | 3, 7);
+assert_eq(5 ^ 3, 6);
+assert_eq(~5, -6);
+assert_eq(~-1, 0);
+assert_eq(~~3.7, 3);
+assert_eq(~~-3.7, -3);
+
+// Flags
+var READ = 1;
+var WRITE = 2;
+var EXEC = 4;
+
+var flags = READ | EXEC;
+assert_eq(flags & READ, READ);
+assert_eq(flags & WRITE, 0);
+assert_eq((flags | WRITE) & WRITE, WRITE);
+assert_eq(flags & ~READ, EXEC);
+
+// Precedence: & binds tighter than ^, which binds tighter than |
+assert_eq(1 | 2 ^ 3 & 4, 3);
+assert_eq(1 | 6 & 3, 3);
+assert_eq(6 & 3 === 2, 0);
+assert_eq(true && 6 | 1, 7);
+assert_eq(0 || 6 & 3, 2);
+
+// Shifts
+assert_eq(1 << 4, 16);
+assert_eq(256 >> 4, 16);
+assert_eq(-16 >> 2, -4);
+assert_eq(-16 >>> 28, 15);
+assert_eq(-1 >>> 0, 4294967295);
+assert_eq(1 << 32, 1);
+assert_eq(1 << 31, -2147483648);
+assert_eq(1 << 2 + 1, 8);
+assert_eq(1 << 2 < 5, true);
+assert_eq(16 >> 2 >= 4, true);
+
+// ToInt32 and ToUint32 conversions
+assert_eq(4294967296 | 0, 0);
+assert_eq(4294967297 | 0, 1);
+assert_eq(2147483648 | 0, -2147483648);
+assert_eq(-2147483649 | 0, 2147483647);
+assert_eq(3.9 | 0, 3);
+assert_eq(-3.9 | 0, -3);
+assert_eq("12" | 0, 12);
+assert_eq("abc" | 0, 0);
+assert_eq(nan | 0, 0);
+assert_eq(true | 0, 1);
+assert_eq(null | 0, 0);
+assert_eq(undefined | 0, 0);
+assert_eq(-1 >>> 1, 2147483647);
+assert_eq(4294967295.5 >>> 0, 4294967295);
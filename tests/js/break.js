@@ -0,0 +1,120 @@
+var i = 0;
+
+while (true) {
+  i = i + 1;
+  if (i === 5) break;
+}
+
+assert_eq(i, 5);
+
+var sum = 0;
+
+for (var k = 0; k - 6; k = k + 1) {
+  if ((k === 2) || (k === 4)) {
+    continue;
+  }
+  sum = sum + k;
+}
+
+assert_eq(sum, 9);
+
+var runs = 0;
+
+do {
+  runs = runs + 1;
+  continue;
+} while (runs - 3);
+
+assert_eq(runs, 3);
+
+// break only leaves the innermost loop
+var pairs = "";
+
+for (var a = 0; a - 3; a = a + 1) {
+  for (var b = 0; b - 3; b = b + 1) {
+    if (b === 2) break;
+    pairs = pairs + a + b + " ";
+  }
+}
+
+assert_eq(pairs, "00 01 10 11 20 21 ");
+
+// Labelled loops
+
+var found = "";
+
+outer: for (var x = 0; x - 3; x = x + 1) {
+  for (var y = 0; y - 3; y = y + 1) {
+    if ((x === 1) && (y === 2)) {
+      found = found + x + y;
+      break outer;
+    }
+  }
+}
+
+assert_eq(found, "12");
+assert_eq(x, 1);
+
+var skipped = "";
+
+rows: for (var r = 0; r - 3; r = r + 1) {
+  var c = 0;
+  while (true) {
+    c = c + 1;
+    if (c === 2) continue rows;
+    skipped = skipped + r + c + " ";
+  }
+}
+
+assert_eq(skipped, "01 11 21 ");
+assert_eq(r, 3);
+
+// Several labels on one loop
+
+var hits = 0;
+
+first: second: for (var z = 0; z - 5; z = z + 1) {
+  hits = hits + 1;
+  if (z === 1) continue first;
+  if (z === 2) break second;
+}
+
+assert_eq(hits, 3);
+
+// Breaking out of a plain block
+
+var reached = "start";
+
+block: {
+  reached = "inside";
+  if (true) break block;
+  reached = "end";
+}
+
+assert_eq(reached, "inside");
+
+// break and continue inside functions
+
+function first_even(a, b, c) {
+  var i = 0;
+  while (true) {
+    i = i + 1;
+    if (i === 1) {
+      if (a / 2 === 1) return a;
+      continue;
+    }
+    if (i === 2) {
+      if (b / 2 === 1) return b;
+      continue;
+    }
+    break;
+  }
+  return c;
+}
+
+assert_eq(first_even(2, 3, 4), 2);
+assert_eq(first_even(3, 2, 4), 2);
+assert_eq(first_even(3, 3, 4), 4);
+
+var breakfast = "eggs";
+assert_eq(breakfast, "eggs");
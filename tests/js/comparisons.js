@@ -0,0 +1,95 @@
+assert_eq(1 < 2, true);
+assert_eq(2 < 1, false);
+assert_eq(2 > 1, true);
+assert_eq(1 > 1, false);
+assert_eq(1 <= 1, true);
+assert_eq(2 <= 1, false);
+assert_eq(1 >= 1, true);
+assert_eq(0 >= 1, false);
+
+// Strings are compared lexicographically
+assert_eq("a" < "b", true);
+assert_eq("abc" < "abd", true);
+assert_eq("b" > "abc", true);
+assert_eq("10" < "9", true);
+
+// Anything else is compared numerically
+assert_eq("10" < 9, false);
+assert_eq(true > false, true);
+assert_eq(null < 1, true);
+
+// NaN is unordered
+var nan = +"not a number";
+assert_eq(nan < 1, false);
+assert_eq(nan > 1, false);
+assert_eq(nan <= nan, false);
+assert_eq(nan >= 1, false);
+
+// Strict equality
+assert_eq(1 !== 1, false);
+assert_eq(1 !== "1", true);
+assert_eq(nan !== nan, true);
+
+// Abstract equality
+assert_eq(1 == 1, true);
+assert_eq(1 == "1", true);
+assert_eq("1.0" == 1, true);
+assert_eq(0 == "", true);
+assert_eq(0 == false, true);
+assert_eq("1" == true, true);
+assert_eq(2 == true, false);
+assert_eq(null == undefined, true);
+assert_eq(undefined == null, true);
+assert_eq(null == 0, false);
+assert_eq(undefined == 0, false);
+assert_eq(nan == nan, false);
+assert_eq("a" != "b", true);
+assert_eq(1 != "1", false);
+
+var obj = {};
+assert_eq(obj == obj, true);
+assert_eq(obj == {}, false);
+assert_eq(obj == "[object Object]", true);
+assert_eq("[object Object]" != obj, false);
+
+// Objects are converted with valueOf before toString
+var five = {};
+five.valueOf = function() { return 5; };
+assert_eq(five == 5, true);
+assert_eq(five == "5", true);
+assert_eq(five < 6, true);
+assert_eq(five > 6, false);
+assert_eq(five == "[object Object]", false);
+
+var boxed = {};
+boxed.valueOf = function() { return {}; };
+boxed.toString = function() { return "7"; };
+assert_eq(boxed == 7, true);
+assert_eq(boxed < 8, true);
+
+var stubborn = {};
+stubborn.valueOf = function() { return {}; };
+stubborn.toString = function() { return {}; };
+var converted = "not thrown";
+try {
+  stubborn < 1;
+} catch (e) {
+  converted = e instanceof TypeError;
+}
+assert_eq(converted, true);
+
+// Precedence
+
+assert_eq(1 + 1 < 3, true);
+assert_eq(1 < 2 === true, true);
+assert_eq(1 === 1 && 2 === 2, true);
+assert_eq(1 === 2 || 2 === 2, true);
+assert_eq(1 < 2 && 3 > 2 ? "yes" : "no", "yes");
+assert_eq(false && false || true, true);
+assert_eq(true || false && false, true);
+
+var order = "";
+for (var i = 0; i < 3; i = i + 1) {
+  order = order + i;
+}
+assert_eq(order, "012");
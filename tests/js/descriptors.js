@@ -0,0 +1,141 @@
+// Defining properties
+
+var point = {};
+Object.defineProperty(point, "x", {value: 1});
+assert_eq(point.x, 1);
+
+var x = Object.getOwnPropertyDescriptor(point, "x");
+assert_eq(x.value, 1);
+assert_eq(x.writable, false);
+assert_eq(x.enumerable, false);
+assert_eq(x.configurable, false);
+assert_eq(Object.getOwnPropertyDescriptor(point, "y"), undefined);
+
+// Assigned properties have every attribute set
+point.y = 2;
+var y = Object.getOwnPropertyDescriptor(point, "y");
+assert_eq(y.writable && y.enumerable && y.configurable, true);
+
+// Non-enumerable properties are left out of listings
+assert_eq(Object.keys(point).join(), "y");
+assert_eq(JSON.stringify(point), '{"y":2}');
+assert_eq(point.hasOwnProperty("x"), true);
+
+// Writes to read-only properties are ignored outside of strict mode
+point.x = 10;
+assert_eq(point.x, 1);
+
+// So are writes that would shadow an inherited read-only property
+var child = Object.create(point);
+child.x = 10;
+assert_eq(child.x, 1);
+assert_eq(child.hasOwnProperty("x"), false);
+
+// Properties that aren't configurable can't be changed back
+
+function define_error(object, key, descriptor) {
+  try {
+    Object.defineProperty(object, key, descriptor);
+  } catch (e) {
+    return e.name + ": " + e.message;
+  }
+  return "no error";
+}
+
+assert_eq(define_error(point, "x", {value: 2}), "TypeError: can't redefine non-configurable property \"x\"");
+assert_eq(define_error(point, "x", {writable: true}), "TypeError: can't redefine non-configurable property \"x\"");
+assert_eq(define_error(point, "x", {enumerable: true}), "TypeError: can't redefine non-configurable property \"x\"");
+assert_eq(define_error(point, "x", {value: 1}), "no error");
+assert_eq(define_error(point, "x", 5), "TypeError: property descriptor must be an object: 5");
+
+var open = {};
+Object.defineProperty(open, "z", {value: 1, writable: true, configurable: true});
+Object.defineProperty(open, "z", {value: 2, enumerable: true});
+assert_eq(open.z, 2);
+assert_eq(Object.keys(open).join(), "z");
+Object.defineProperty(open, "z", {writable: false});
+open.z = 3;
+assert_eq(open.z, 2);
+
+// Defining several properties at once
+
+var defined = Object.defineProperties({}, {
+  a: {value: "a", enumerable: true},
+  b: {value: "b", writable: true}
+});
+assert_eq(defined.a + defined.b, "ab");
+assert_eq(Object.keys(defined).join(), "a");
+
+// Descriptors can come from getters
+var computed = Object.defineProperties({}, {
+  get c() { return {value: "c", enumerable: true}; }
+});
+assert_eq(computed.c, "c");
+
+// Preventing extensions
+
+var fixed = {a: 1};
+assert_eq(Object.isExtensible(fixed), true);
+assert_eq(Object.preventExtensions(fixed), fixed);
+assert_eq(Object.isExtensible(fixed), false);
+fixed.b = 2;
+assert_eq(fixed.b, undefined);
+fixed.a = 3;
+assert_eq(fixed.a, 3);
+assert_eq(define_error(fixed, "c", {value: 1}), "TypeError: can't define property \"c\": {\"a\": 3} is not extensible");
+
+// Sealing and freezing
+
+var sealed = Object.seal({a: 1});
+assert_eq(Object.isSealed(sealed), true);
+assert_eq(Object.isFrozen(sealed), false);
+sealed.a = 2;
+assert_eq(sealed.a, 2);
+assert_eq(Object.getOwnPropertyDescriptor(sealed, "a").configurable, false);
+
+var frozen = Object.freeze({a: 1, inner: {b: 1}});
+assert_eq(Object.isFrozen(frozen), true);
+assert_eq(Object.isSealed(frozen), true);
+frozen.a = 2;
+frozen.c = 3;
+assert_eq(frozen.a, 1);
+assert_eq(frozen.c, undefined);
+
+// Freezing is shallow
+frozen.inner.b = 2;
+assert_eq(frozen.inner.b, 2);
+
+assert_eq(Object.isFrozen({}), false);
+assert_eq(Object.isFrozen(Object.preventExtensions({})), true);
+assert_eq(Object.isFrozen(1), true);
+assert_eq(Object.freeze(1), 1);
+
+// Frozen arrays can't gain or lose elements
+var list = Object.freeze([1, 2]);
+list[0] = 5;
+list[2] = 3;
+assert_eq(list.join(), "1,2");
+assert_eq(list.length, 2);
+
+// Array lengths can be defined, but not made read-only
+var sized = [1, 2, 3];
+Object.defineProperty(sized, "length", {value: 1});
+assert_eq(sized.join(), "1");
+assert_eq(define_error(sized, "length", {writable: false}), "TypeError: can't redefine non-configurable property \"length\"");
+assert_eq(Object.getOwnPropertyDescriptor(sized, "length").enumerable, false);
+
+// Functions can opt into strict mode, where failed writes throw
+
+function strict_write() {
+  "use strict";
+  frozen.a = 2;
+}
+
+var caught;
+try {
+  strict_write();
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught instanceof TypeError, true);
+assert_eq(caught.message, "\"a\" is read-only");
@@ -0,0 +1,85 @@
+// Constructing errors
+
+var error = new Error("something broke");
+assert_eq(error.message, "something broke");
+assert_eq(error.name, "Error");
+assert_eq(error.toString(), "Error: something broke");
+assert_eq(error.stack, "Error: something broke\n    at tests/js/errors.js:3:13");
+assert_eq(error instanceof Error, true);
+
+var plain = Error("no new");
+assert_eq(plain.message, "no new");
+assert_eq(plain instanceof Error, true);
+
+assert_eq((new Error()).message, "");
+assert_eq((new Error()).toString(), "Error");
+
+var type_error = new TypeError("bad type");
+assert_eq(type_error.name, "TypeError");
+assert_eq(type_error.toString(), "TypeError: bad type");
+assert_eq(type_error instanceof TypeError, true);
+assert_eq(type_error instanceof Error, true);
+assert_eq(type_error instanceof RangeError, false);
+assert_eq(type_error.constructor, TypeError);
+
+assert_eq((new RangeError("x")).name, "RangeError");
+assert_eq((new ReferenceError("x")).name, "ReferenceError");
+assert_eq((new SyntaxError("x")).name, "SyntaxError");
+assert_eq((new EvalError("x")).name, "EvalError");
+
+// instanceof on other objects
+
+function Point() {}
+var point = new Point();
+assert_eq(point instanceof Point, true);
+assert_eq(point instanceof Error, false);
+assert_eq(5 instanceof Point, false);
+assert_eq([] instanceof Array, true);
+
+var not_callable;
+try {
+  point instanceof point;
+} catch (e) {
+  not_callable = e;
+}
+assert_eq(not_callable instanceof TypeError, true);
+
+// Errors thrown by the interpreter
+
+function kind_of(thrower) {
+  try {
+    thrower();
+  } catch (e) {
+    return e.name;
+  }
+  return "nothing thrown";
+}
+
+assert_eq(kind_of(function() { var nothing; nothing.property; }), "TypeError");
+assert_eq(kind_of(function() { var number = 5; number(); }), "TypeError");
+assert_eq(kind_of(function() { [].length = -1; }), "RangeError");
+assert_eq(kind_of(function() { var huge = []; huge.length = 4294967295; huge.join(""); }), "RangeError");
+assert_eq(kind_of(function() { var huge = []; huge.length = 4294967295; huge.splice(0); }), "RangeError");
+assert_eq(kind_of(function() { var huge = []; huge.length = 4294967295; JSON.stringify(huge); }), "RangeError");
+assert_eq(kind_of(function() { [].reduce(function(a, b) { return a; }); }), "TypeError");
+assert_eq(kind_of(function() { eval("var = ;"); }), "SyntaxError");
+assert_eq(kind_of(function() { throw new EvalError("thrown"); }), "EvalError");
+
+var caught;
+try {
+  var nothing;
+  nothing.property;
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught instanceof TypeError, true);
+assert_eq(caught instanceof Error, true);
+assert_eq(caught.message, "undefined has no properties");
+assert_eq(caught.toString(), "TypeError: undefined has no properties");
+
+// Error properties can be changed
+
+var custom = new Error("original");
+custom.message = "changed";
+custom.name = "CustomError";
+assert_eq(custom.toString(), "CustomError: changed");
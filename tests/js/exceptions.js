@@ -0,0 +1,168 @@
+var caught;
+
+try {
+  throw "oops";
+} catch (e) {
+  caught = e;
+}
+
+assert_eq(caught, "oops");
+
+var order = "";
+
+try {
+  order = order + "try ";
+} catch (e) {
+  order = order + "catch ";
+} finally {
+  order = order + "finally";
+}
+
+assert_eq(order, "try finally");
+
+// Errors thrown by the interpreter can be caught too
+
+var nothing;
+
+try {
+  nothing.property;
+} catch (e) {
+  caught = "reference";
+}
+
+assert_eq(caught, "reference");
+
+// The catch parameter has its own scope
+
+var e = "outer";
+
+try {
+  throw "inner";
+} catch (e) {
+  caught = e;
+}
+
+assert_eq(caught, "inner");
+assert_eq(e, "outer");
+
+// Errors propagate through finally when there is no catch
+
+function rethrow() {
+  try {
+    throw "first";
+  } finally {
+    order = "cleaned up";
+  }
+}
+
+try {
+  rethrow();
+} catch (err) {
+  caught = err;
+}
+
+assert_eq(caught, "first");
+assert_eq(order, "cleaned up");
+
+// Errors thrown in a catch block replace the original
+
+try {
+  try {
+    throw "original";
+  } catch (err) {
+    throw "replacement";
+  }
+} catch (err) {
+  caught = err;
+}
+
+assert_eq(caught, "replacement");
+
+// finally can override the completion of try and catch
+
+function returns_from_try() {
+  try {
+    return "try";
+  } finally {
+    order = "finally ran";
+  }
+}
+
+assert_eq(returns_from_try(), "try");
+assert_eq(order, "finally ran");
+
+function returns_from_finally() {
+  try {
+    return "try";
+  } finally {
+    return "finally";
+  }
+}
+
+assert_eq(returns_from_finally(), "finally");
+
+function swallows_error() {
+  try {
+    throw "lost";
+  } finally {
+    return "swallowed";
+  }
+}
+
+assert_eq(swallows_error(), "swallowed");
+
+function throws_from_finally() {
+  try {
+    return "try";
+  } finally {
+    throw "finally";
+  }
+}
+
+try {
+  throws_from_finally();
+} catch (err) {
+  caught = err;
+}
+
+assert_eq(caught, "finally");
+
+function returns_from_catch() {
+  try {
+    throw "error";
+  } catch (err) {
+    return "caught " + err;
+  } finally {
+    order = "after catch";
+  }
+}
+
+assert_eq(returns_from_catch(), "caught error");
+assert_eq(order, "after catch");
+
+// Loops inside try
+
+var count = 0;
+
+while (true) {
+  try {
+    count = count + 1;
+    if (count === 3) break;
+    continue;
+  } finally {
+    order = "finally " + count;
+  }
+}
+
+assert_eq(count, 3);
+assert_eq(order, "finally 3");
+
+for (var i = 0; i - 3; i = i + 1) {
+  try {
+    throw i;
+  } finally {
+    continue;
+  }
+}
+
+assert_eq(i, 3);
@@ -27,10 +27,7 @@ assert_eq(add(7, 4), 11);
 function counter() {
   var count = 0;
   return function() {
-    // TODO: ++
-    var old_count = count;
-    count = count + 1;
-    return old_count;
+    return count++;
   };
 }
 
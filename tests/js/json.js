@@ -0,0 +1,159 @@
+// Parsing
+
+var parsed = JSON.parse('{"name": "yuk", "tags": ["a", "b"], "nested": {"ok": true}, "none": null}');
+assert_eq(parsed.name, "yuk");
+assert_eq(parsed.tags.length, 2);
+assert_eq(parsed.tags[1], "b");
+assert_eq(parsed.nested.ok, true);
+assert_eq(parsed.none, null);
+assert_eq(parsed.tags instanceof Array, true);
+
+assert_eq(JSON.parse(" 12.5e1 "), 125);
+assert_eq(JSON.parse("-0.25"), -0.25);
+assert_eq(JSON.parse('"tab\\there \\"quoted\\" \\u0041\\ud83d\\ude00"'), "tab\there \"quoted\" A😀");
+assert_eq(JSON.parse("[]").length, 0);
+assert_eq(JSON.parse("false"), false);
+
+function parse_error(text) {
+  try {
+    JSON.parse(text);
+  } catch (e) {
+    return e.name + ": " + e.message;
+  }
+  return "no error";
+}
+
+assert_eq(parse_error("{'a': 1}"), "SyntaxError: JSON.parse: unexpected character ''' at position 1");
+assert_eq(parse_error("[1, 2"), "SyntaxError: JSON.parse: unexpected end of data");
+assert_eq(parse_error("[1,]"), "SyntaxError: JSON.parse: unexpected character ']' at position 3");
+assert_eq(parse_error("01"), "SyntaxError: JSON.parse: unexpected character '1' at position 1");
+assert_eq(parse_error("tru"), "SyntaxError: JSON.parse: unexpected end of data");
+assert_eq(parse_error("1 2"), "SyntaxError: JSON.parse: unexpected character '2' at position 2");
+
+// The reviver sees every value from the inside out, and drops values it returns undefined for
+
+var seen = [];
+var revived = JSON.parse('{"a": [1, 2], "b": {"c": 3}}', function(key, value) {
+  seen.push(key);
+  if (key === "c") {
+    return undefined;
+  }
+  if (key === "0" || key === "1") {
+    return value * 10;
+  }
+  return value;
+});
+assert_eq(revived.a[1], 20);
+assert_eq(revived.b.c, undefined);
+assert_eq(revived.b.hasOwnProperty("c"), false);
+assert_eq(seen[0], "0");
+assert_eq(seen[1], "1");
+assert_eq(seen[2], "a");
+assert_eq(seen[seen.length - 1], "");
+
+// Stringifying
+
+assert_eq(JSON.stringify({name: "yuk"}), '{"name":"yuk"}');
+assert_eq(JSON.stringify([1, "two", true, null]), '[1,"two",true,null]');
+assert_eq(JSON.stringify("line\nbreak \"quoted\" \\"), '"line\\nbreak \\"quoted\\" \\\\"');
+assert_eq(JSON.stringify(0 / 0), "null");
+assert_eq(JSON.stringify(-0), "0");
+assert_eq(JSON.stringify(undefined), undefined);
+assert_eq(JSON.stringify(function() {}), undefined);
+assert_eq(JSON.stringify([undefined, function() {}]), "[null,null]");
+assert_eq(JSON.stringify({skipped: undefined}), "{}");
+assert_eq(JSON.stringify({}), "{}");
+assert_eq(JSON.stringify([]), "[]");
+
+// Indentation
+
+assert_eq(JSON.stringify({list: [1, 2]}, null, 2), '{\n  "list": [\n    1,\n    2\n  ]\n}');
+assert_eq(JSON.stringify([[]], null, "\t"), "[\n\t[]\n]");
+assert_eq(JSON.stringify([1], null, 20), "[\n          1\n]");
+
+// toJSON and replacers
+
+var dated = {when: {toJSON: function(key) { return "at " + key; }}};
+assert_eq(JSON.stringify(dated), '{"when":"at when"}');
+
+assert_eq(JSON.stringify({a: 1}, function(key, value) {
+  if (key === "") {
+    return value;
+  }
+  return value + 1;
+}), '{"a":2}');
+assert_eq(JSON.stringify({a: 1, b: 2, c: 3}, ["b", "b", 5]), '{"b":2}');
+
+// Cycles are TypeErrors
+
+var cycle = {};
+cycle.self = [cycle];
+
+var caught;
+try {
+  JSON.stringify(cycle);
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught instanceof TypeError, true);
+assert_eq(caught.message, "cyclic object value");
+
+// The same object can appear twice if it doesn't contain itself
+var shared = {x: 1};
+assert_eq(JSON.stringify([shared, shared]), '[{"x":1},{"x":1}]');
+
+// Properties come out in the same order as Object.keys
+var mixed = {z: 1, a: 2};
+mixed[3] = 3;
+mixed.m = {y: 1, b: 2};
+assert_eq(JSON.stringify(mixed), '{"3":3,"z":1,"a":2,"m":{"y":1,"b":2}}');
+assert_eq(JSON.stringify(JSON.parse('{"b": 1, "a": 2, "0": 3}')), '{"0":3,"b":1,"a":2}');
+
+// Nesting is limited, rather than recursing until the stack runs out
+
+function nested(depth) {
+  var text = "";
+  for (var i = 0; i < depth; i++) {
+    text = "[" + text + "]";
+  }
+  return text;
+}
+
+assert_eq(JSON.stringify(JSON.parse(nested(512))), nested(512));
+assert_eq(JSON.parse(nested(512), function(key, value) { return value; }).length, 1);
+assert_eq(parse_error(nested(513)), "SyntaxError: JSON.parse: nesting is too deep at position 512");
+
+var deep = [];
+for (var d = 0; d < 600; d++) {
+  deep = [deep];
+}
+
+try {
+  JSON.stringify(deep);
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught instanceof RangeError, true);
+assert_eq(caught.message, "JSON.stringify: nesting is too deep");
+
+// Numbers are written the same way as when they are converted to strings
+assert_eq(JSON.stringify(1e21), "1e+21");
+assert_eq(JSON.stringify(1e20), "100000000000000000000");
+assert_eq(JSON.stringify(1e-7), "1e-7");
+assert_eq(JSON.stringify(0.000001), "0.000001");
+assert_eq(JSON.stringify([-0, 2.5, -1e-10]), "[0,2.5,-1e-10]");
+assert_eq(JSON.stringify({a: 1}, [1e21, "a"]), '{"a":1}');
+
+// Parsed and revived values are own properties, even when a prototype has a setter for them
+var intercepted = [];
+Object.defineProperty(Object.prototype, "trap", {
+  set: function(value) { intercepted.push(value); },
+  configurable: true
+});
+var trapped = JSON.parse('{"trap": 1, "inner": {"trap": 2}}');
+assert_eq(trapped.hasOwnProperty("trap"), true);
+assert_eq(trapped.inner.trap, 2);
+var revived = JSON.parse('{"trap": 1}', function(key, value) { return key === "trap" ? value + 1 : value; });
+assert_eq(revived.trap, 2);
+assert_eq(JSON.stringify({trap: 3}), '{"trap":3}');
+assert_eq(intercepted.length, 0);
@@ -0,0 +1,176 @@
+// `let` and `const` variables only exist in their block
+
+var outer = 1;
+let shadowed = "outer";
+{
+  let shadowed = "inner";
+  let only_inner = 2;
+  const fixed = 3;
+  assert_eq(shadowed, "inner");
+  assert_eq(only_inner + fixed, 5);
+}
+assert_eq(shadowed, "outer");
+
+function error_of(f) {
+  try {
+    f();
+  } catch (e) {
+    return e.name + ": " + e.message;
+  }
+  return "no error";
+}
+
+assert_eq(error_of(function() { return only_inner; }), "ReferenceError: only_inner is not defined");
+
+if (true) {
+  let in_if = 1;
+  var hoisted = in_if;
+}
+assert_eq(hoisted, 1);
+assert_eq(error_of(function() { return in_if; }), "ReferenceError: in_if is not defined");
+
+function blocks() {
+  let result = [];
+  let x = 1;
+  if (true) {
+    let x = 2;
+    result.push(x);
+  }
+  result.push(x);
+  return result.join();
+}
+assert_eq(blocks(), "2,1");
+
+// Top-level `let` doesn't create a property of the global object
+assert_eq(this.hasOwnProperty("shadowed"), false);
+assert_eq(this.hasOwnProperty("outer"), true);
+
+// Variables can't be used before their declaration runs
+
+assert_eq(error_of(function() {
+  let before = later;
+  let later = 1;
+}), "ReferenceError: can't access lexical declaration 'later' before initialization");
+
+assert_eq(error_of(function() {
+  early = 1;
+  let early;
+}), "ReferenceError: can't access lexical declaration 'early' before initialization");
+
+// The inner declaration hides the outer one for the whole block
+assert_eq(error_of(function() {
+  let hidden = 1;
+  {
+    hidden = 2;
+    let hidden = 3;
+  }
+}), "ReferenceError: can't access lexical declaration 'hidden' before initialization");
+
+// Functions can refer to variables declared after them, as long as they run later
+function read_later() {
+  return declared_later;
+}
+let declared_later = "ready";
+assert_eq(read_later(), "ready");
+
+let no_value;
+assert_eq(no_value, undefined);
+
+// Constants can't be assigned to, even outside strict mode
+
+const answer = 42;
+assert_eq(error_of(function() { answer = 1; }), "TypeError: invalid assignment to const 'answer'");
+assert_eq(error_of(function() { answer += 1; }), "TypeError: invalid assignment to const 'answer'");
+assert_eq(error_of(function() { answer++; }), "TypeError: invalid assignment to const 'answer'");
+assert_eq(answer, 42);
+
+// Constants can still hold objects that change
+const list = [];
+list.push(1);
+assert_eq(list.length, 1);
+
+assert_eq(error_of(function() {
+  let twice = 1;
+  let twice = 2;
+}), "SyntaxError: redeclaration of twice");
+
+// `var` and function declarations can't share a name with `let` or `const` in the same scope
+assert_eq(error_of(function() {
+  let mixed;
+  var mixed;
+}), "SyntaxError: redeclaration of mixed");
+assert_eq(error_of(function() {
+  var mixed;
+  const mixed = 1;
+}), "SyntaxError: redeclaration of mixed");
+assert_eq(error_of(function() {
+  let mixed;
+  function mixed() {}
+}), "SyntaxError: redeclaration of mixed");
+assert_eq(error_of(function() {
+  let mixed;
+  {
+    var mixed;
+  }
+}), "SyntaxError: redeclaration of mixed");
+assert_eq(error_of(function() { eval("var top_mixed; let top_mixed;"); }), "SyntaxError: redeclaration of top_mixed");
+
+// A `let` in an inner block can still shadow a `var`, and parameters can be redeclared with `var`
+assert_eq(error_of(function() {
+  var shadowed = 1;
+  {
+    let shadowed = 2;
+  }
+}), "no error");
+assert_eq(error_of(function(param = 1) {
+  var param;
+}), "no error");
+
+// Each loop iteration gets its own copy of the loop's variables
+
+var functions = [];
+for (let i = 0; i < 3; i++) {
+  functions.push(function() { return i; });
+}
+assert_eq(functions[0]() + "," + functions[1]() + "," + functions[2](), "0,1,2");
+assert_eq(error_of(function() { return i; }), "ReferenceError: i is not defined");
+
+var shared = [];
+for (var j = 0; j < 3; j++) {
+  shared.push(function() { return j; });
+}
+assert_eq(shared[0](), 3);
+
+// Changes made by the body carry over to the next iteration
+var seen = [];
+for (let k = 0; k < 6; k++) {
+  seen.push(k);
+  k++;
+}
+assert_eq(seen.join(), "0,2,4");
+
+// Bodies get a fresh scope each time around
+var bodies = [];
+var n = 0;
+while (n < 2) {
+  let current = n;
+  bodies.push(function() { return current; });
+  n++;
+}
+assert_eq(bodies[0]() + bodies[1](), 1);
+
+// `var` in a catch block belongs to the function, while the parameter doesn't
+
+try {
+  throw 1;
+} catch (caught) {
+  var from_catch = caught;
+}
+assert_eq(from_catch, 1);
+assert_eq(this.hasOwnProperty("caught"), false);
+
+// Variables are only looked up in scopes, not in Object.prototype
+
+assert_eq(error_of(function() { return toString; }), "ReferenceError: toString is not defined");
+assert_eq(error_of(function() { return hasOwnProperty; }), "ReferenceError: hasOwnProperty is not defined");
+assert_eq(this.toString(), "[object Object]");
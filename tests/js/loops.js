@@ -0,0 +1,70 @@
+var count = 0;
+
+while (count - 5) {
+  count = count + 1;
+}
+
+assert_eq(count, 5);
+
+while (false) count = 100;
+
+assert_eq(count, 5);
+
+var runs = 0;
+
+do {
+  runs = runs + 1;
+} while (false);
+
+assert_eq(runs, 1);
+
+do runs = runs + 1; while (runs - 10)
+
+assert_eq(runs, 10);
+
+var total = 0;
+
+for (var i = 0; i - 4; i = i + 1) {
+  total = total + i;
+}
+
+assert_eq(total, 6);
+assert_eq(i, 4);
+
+var j = 3;
+for (; j; j = j - 1) total = total + j;
+
+assert_eq(total, 12);
+assert_eq(j, 0);
+
+function find_first(n) {
+  for (var k = 1; true; k = k * 2) {
+    if (k === n) {
+      return k;
+    }
+  }
+}
+
+assert_eq(find_first(8), 8);
+
+function count_down(n) {
+  while (true) {
+    n = n - 1;
+    if (n === 0) return "done";
+  }
+}
+
+assert_eq(count_down(3), "done");
+
+var nested = "";
+
+for (var a = 0; a - 2; a = a + 1) {
+  for (var b = 0; b - 2; b = b + 1) {
+    nested = nested + a + b;
+  }
+}
+
+assert_eq(nested, "00011011");
+
+var done = 0;
+assert_eq(done, 0);
@@ -1,10 +1,11 @@
 var a = 100;
 var s = "hey";
 
-//function isNaN(n) {
-//  return n !== n;
-//}
+function isNaN(n) {
+  return n !== n;
+}
 
-//assert(isNaN(+s));
-//assert(isNaN(s * 10));
-//assert(isNaN(s - a));
+assert_eq(isNaN(+s), true);
+assert_eq(isNaN(s * 10), true);
+assert_eq(isNaN(s - a), true);
+assert_eq(isNaN(a), false);
@@ -14,3 +14,19 @@ assert_eq("3.5" * 5 * 10, 175);
 
 assert_eq(-true, -1);
 assert_eq(+!true, 0);
+
+// Converting to strings
+assert_eq(12 + "", "12");
+assert_eq(-1.5 + "", "-1.5");
+assert_eq(0.1 + 0.2 + "", "0.30000000000000004");
+assert_eq(-0 + "", "0");
+assert_eq(1 / 0 + "", "Infinity");
+assert_eq(-1 / 0 + "", "-Infinity");
+assert_eq(0 / 0 + "", "NaN");
+assert_eq(123456789012345680000 + "", "123456789012345680000");
+assert_eq(1e21 + "", "1e+21");
+assert_eq(1.5e300 + "", "1.5e+300");
+assert_eq(0.000001 + "", "0.000001");
+assert_eq(1e-7 + "", "1e-7");
+assert_eq(-1.25e-10 + "", "-1.25e-10");
+assert_eq((255).toString(), "255");
@@ -38,3 +38,25 @@ assert_eq(child.andyet.key, false);
 assert_eq(object.andyet.key, false);
 
 assert_eq(Object.create(null).toString, undefined);
+
+assert_eq(object.hasOwnProperty("bleh"), true);
+assert_eq(object.hasOwnProperty("toString"), false);
+assert_eq(child.hasOwnProperty("bleh"), true);
+assert_eq(child.hasOwnProperty("andyet"), false);
+assert_eq(Object.keys(child).join(), "bleh");
+assert_eq(Object.keys({}).length, 0);
+
+// Properties are listed with array indices first, then in the order they were added
+
+var ordered = {b: 1, a: 2};
+ordered[10] = 3;
+ordered.z = 5;
+ordered[2] = 4;
+ordered[1] = 6;
+ordered.b = 7;
+assert_eq(Object.keys(ordered).join(), "1,2,10,b,a,z");
+var keys = {};
+keys["01"] = 1;
+keys[1] = 2;
+keys["-1"] = 3;
+assert_eq(Object.keys(keys).join(), "1,01,-1");
@@ -0,0 +1,130 @@
+// Every argument can be read from `arguments`, including ones without a parameter
+
+function sum() {
+  var total = 0;
+  for (var i = 0; i < arguments.length; i++) {
+    total += arguments[i];
+  }
+  return total;
+}
+
+assert_eq(sum(), 0);
+assert_eq(sum(1, 2, 3, 4), 10);
+
+function first(a) {
+  return [arguments[0], arguments.length];
+}
+assert_eq(first()[0], undefined);
+assert_eq(first()[1], 0);
+assert_eq(first(1, 2).join(), "1,2");
+
+function keys() {
+  return Object.keys(arguments).join();
+}
+assert_eq(keys("a", "b"), "0,1");
+
+// Each call gets its own `arguments`, and a parameter with the same name replaces it
+function outer() {
+  var inner = function() {
+    return arguments.length;
+  };
+  return inner() + "," + arguments.length;
+}
+assert_eq(outer(1, 2), "0,2");
+
+function named(arguments) {
+  return arguments;
+}
+assert_eq(named(5), 5);
+
+// Default values are used for missing and undefined arguments
+
+function greet(name = "world", greeting = "hello") {
+  return greeting + ", " + name;
+}
+
+assert_eq(greet(), "hello, world");
+assert_eq(greet("you"), "hello, you");
+assert_eq(greet(undefined, "hi"), "hi, world");
+assert_eq(greet("", "hi"), "hi, ");
+
+// Defaults are evaluated on each call, and can use the parameters before them
+function box(value, list = [value]) {
+  return list;
+}
+assert_eq(box(1) === box(1), false);
+assert_eq(box(2)[0], 2);
+
+var calls = 0;
+function counted(x = ++calls) {
+  return x;
+}
+counted(1);
+assert_eq(calls, 0);
+assert_eq(counted(), 1);
+assert_eq(calls, 1);
+
+// Later parameters can't be used before they have their values, even if an outer variable has the same name
+var later = 1;
+function too_early(a = later, later = 2) {
+  return a;
+}
+
+var early_error;
+try {
+  too_early();
+} catch (e) {
+  early_error = e.name + ": " + e.message;
+}
+assert_eq(early_error, "ReferenceError: can't access lexical declaration 'later' before initialization");
+assert_eq(too_early(5), 5);
+
+function self_default(a = a) {
+  return a;
+}
+try {
+  self_default();
+  early_error = "no error";
+} catch (e) {
+  early_error = e.name;
+}
+assert_eq(early_error, "ReferenceError");
+
+function named_default(arguments = 3) {
+  return arguments;
+}
+assert_eq(named_default(), 3);
+
+// Defaults don't change `arguments`
+function defaults(a = 1) {
+  return arguments.length;
+}
+assert_eq(defaults(), 0);
+
+// Rest parameters collect the remaining arguments into an array
+
+function tail(head, ...rest) {
+  return rest;
+}
+
+assert_eq(Array.isArray(tail(1, 2, 3)), true);
+assert_eq(tail(1, 2, 3).join(), "2,3");
+assert_eq(tail(1).length, 0);
+assert_eq(tail().length, 0);
+
+function all(...items) {
+  return items.length + ":" + items.join();
+}
+assert_eq(all(), "0:");
+assert_eq(all("a", "b"), "2:a,b");
+
+function mixed(a, b = 2, ...others) {
+  return a + b + others.length;
+}
+assert_eq(mixed(1), 3);
+assert_eq(mixed(1, 1, 5, 5, 5), 5);
+
+var anonymous = function(...values) {
+  return values.reduce(function(a, b) { return a + b; }, 0);
+};
+assert_eq(anonymous(1, 2, 3), 6);
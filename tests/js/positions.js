@@ -0,0 +1,82 @@
+// Errors record where they were thrown
+
+var caught;
+try {
+  var nothing;
+  nothing.property;
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught.lineNumber, 6);
+assert_eq(caught.columnNumber, 3);
+assert_eq(caught.fileName, "tests/js/positions.js");
+assert_eq(caught.stack, "TypeError: undefined has no properties\n    at tests/js/positions.js:6:3");
+
+function fails() {
+  var value = 5;
+  return 1 +
+    value();
+}
+
+try {
+  fails();
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught.message, "5 is not a function");
+assert_eq(caught.lineNumber, 18);
+assert_eq(caught.columnNumber, 5);
+
+// Thrown errors are located at the throw statement
+
+try {
+  throw new RangeError("too far");
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught.lineNumber, 33);
+assert_eq(caught.columnNumber, 9);
+
+// Rethrowing keeps the original location
+
+try {
+  try {
+    [].length = -1;
+  } catch (e) {
+    throw e;
+  }
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught.lineNumber, 44);
+
+// Thrown values that aren't errors are left alone
+
+try {
+  throw "plain";
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught, "plain");
+
+// Code run by eval has no file name
+
+try {
+  eval("\n  [].length = -1;");
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught.fileName, "<anonymous>");
+assert_eq(caught.lineNumber, 2);
+assert_eq(caught.columnNumber, 3);
+
+// Errors from declarations are located at the statement
+
+try {
+  eval("var fine;\n  let twice;\n    let twice;");
+} catch (e) {
+  caught = e;
+}
+assert_eq(caught.message, "redeclaration of twice");
+assert_eq(caught.lineNumber, 3);
+assert_eq(caught.columnNumber, 5);
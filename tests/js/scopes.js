@@ -0,0 +1,47 @@
+// Assigning to an undeclared variable creates a global, rather than a property that every object inherits
+function leak() {
+  leaked = 1;
+}
+leak();
+
+assert_eq(leaked, 1);
+assert_eq(this.hasOwnProperty("leaked"), true);
+assert_eq(Object.prototype.hasOwnProperty("leaked"), false);
+var plain = {};
+assert_eq(plain.leaked, undefined);
+
+// Assignments find the closest declaration
+var counter = 0;
+function increment() {
+  var step = 1;
+  return function() {
+    counter = counter + step;
+    return counter;
+  };
+}
+increment()();
+assert_eq(counter, 1);
+assert_eq(this.hasOwnProperty("step"), false);
+
+try {
+  throw 1;
+} catch (caught) {
+  caught = 2;
+}
+assert_eq(this.hasOwnProperty("caught"), false);
+
+// Member assignments shadow inherited properties on the receiver, even through global variables
+Object.prototype.shared = 1;
+shared = 2;
+
+assert_eq(shared, 2);
+assert_eq(this.hasOwnProperty("shared"), true);
+assert_eq(Object.prototype.shared, 1);
+assert_eq(plain.shared, 1);
+
+var parent = {value: 1};
+var child = Object.create(parent);
+child.value = 2;
+
+assert_eq(parent.value, 1);
+assert_eq(child.hasOwnProperty("value"), true);
@@ -0,0 +1,182 @@
+// Errors carry the calls that led to them
+
+function inner() {
+  var nothing;
+  return nothing.property;
+}
+
+function middle() {
+  return inner();
+}
+
+function outer() {
+  return middle();
+}
+
+var caught;
+try {
+  outer();
+} catch (e) {
+  caught = e;
+}
+
+assert_eq(caught.stack, [
+  "TypeError: undefined has no properties",
+  "    at inner (tests/js/stack.js:5:10)",
+  "    at middle (tests/js/stack.js:9:10)",
+  "    at outer (tests/js/stack.js:13:10)",
+  "    at tests/js/stack.js:18:3"
+].join("\n"));
+
+// Native functions appear in the stack
+
+try {
+  [1].map(function(n) {
+    return [].reduce(function(a, b) { return a; });
+  });
+} catch (e) {
+  caught = e;
+}
+
+assert_eq(caught.stack, [
+  "TypeError: reduce of empty array with no initial value",
+  "    at reduce (native)",
+  "    at tests/js/stack.js:35:12",
+  "    at map (native)",
+  "    at tests/js/stack.js:34:3"
+].join("\n"));
+
+// The stack of a constructed error is where it was created, not where it was thrown
+
+function create() {
+  return new Error("created");
+}
+
+function rethrow(error) {
+  throw error;
+}
+
+try {
+  rethrow(create());
+} catch (e) {
+  caught = e;
+}
+
+assert_eq(caught.stack, [
+  "Error: created",
+  "    at create (tests/js/stack.js:52:10)",
+  "    at tests/js/stack.js:60:11"
+].join("\n"));
+
+// Frames are removed when functions return, even by throwing
+
+function thrower() {
+  throw new RangeError("thrown");
+}
+
+try {
+  thrower();
+} catch (e) {}
+
+var after = new Error("after");
+assert_eq(after.stack, "Error: after\n    at tests/js/stack.js:81:13");
+
+// Ordinary recursion is allowed to go deep
+
+function depth(n) {
+  return n === 0 ? 0 : 1 + depth(n - 1);
+}
+
+assert_eq(depth(99), 99);
+assert_eq(depth(2000), 2000);
+
+// Recursing without end throws a RangeError that can be caught, and finally blocks still run
+
+function forever(n) {
+  return forever(n + 1);
+}
+
+var finished = false;
+try {
+  try {
+    forever(0);
+  } finally {
+    finished = true;
+  }
+} catch (e) {
+  caught = e;
+}
+
+assert_eq(finished, true);
+assert_eq(caught instanceof RangeError, true);
+assert_eq(caught.message, "maximum call stack size exceeded");
+
+// The stack is usable again after an overflow
+assert_eq(depth(100), 100);
+
+// Conversions and getters run on the same stack as the code that uses them
+
+var looping = {
+  toString: function() {
+    return "" + looping;
+  }
+};
+
+try {
+  "" + looping;
+} catch (e) {
+  caught = e;
+}
+
+assert_eq(caught instanceof RangeError, true);
+
+var getting = {
+  get self() {
+    return this.self;
+  }
+};
+
+try {
+  getting.self;
+} catch (e) {
+  caught = e;
+}
+
+assert_eq(caught instanceof RangeError, true);
+
+// Callbacks of array methods are called on the stack of the method
+
+function summing() {
+  return [1, 2].reduce(function(sum, n) {
+    throw new Error("in callback");
+  });
+}
+
+try {
+  summing();
+} catch (e) {
+  caught = e;
+}
+
+assert_eq(caught.stack, [
+  "Error: in callback",
+  "    at tests/js/stack.js:151:11",
+  "    at reduce (native)",
+  "    at summing (tests/js/stack.js:150:10)",
+  "    at tests/js/stack.js:156:3"
+].join("\n"));
+
+try {
+  [2, 1].sort(function(a, b) {
+    throw new Error("in comparator");
+  });
+} catch (e) {
+  caught = e;
+}
+
+assert_eq(caught.stack, [
+  "Error: in comparator",
+  "    at tests/js/stack.js:171:11",
+  "    at sort (native)",
+  "    at tests/js/stack.js:170:3"
+].join("\n"));
@@ -0,0 +1,37 @@
+"use strict";
+
+function write_error(write) {
+  try {
+    write();
+  } catch (e) {
+    return e.name + ": " + e.message;
+  }
+  return "no error";
+}
+
+var frozen = Object.freeze({a: 1});
+
+assert_eq(write_error(function() { frozen.a = 2; }), "TypeError: \"a\" is read-only");
+assert_eq(write_error(function() { frozen.b = 2; }), "TypeError: can't define property \"b\": {\"a\": 1} is not extensible");
+assert_eq(write_error(function() { Object.create(frozen).a = 2; }), "TypeError: \"a\" is read-only");
+assert_eq(frozen.a, 1);
+
+// Writes that succeed aren't affected
+var open = {a: 1};
+assert_eq(write_error(function() { open.a = 2; }), "no error");
+assert_eq(open.a, 2);
+
+// Frozen arrays can't be pushed to or popped from
+var list = Object.freeze([1]);
+assert_eq(write_error(function() { list.push(2); }), "TypeError: can't define property \"1\": [1] is not extensible");
+assert_eq(write_error(function() { list.pop(); }), "TypeError: can't delete non-configurable array element");
+assert_eq(list.length, 1);
+
+// Assigning to an undeclared variable doesn't create a global
+var undeclared_error;
+try {
+  undeclared = 1;
+} catch (e) {
+  undeclared_error = e.name + ": " + e.message;
+}
+assert_eq(undeclared_error, "ReferenceError: assignment to undeclared variable undeclared");
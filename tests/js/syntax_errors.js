@@ -0,0 +1,31 @@
+// Syntax errors can be caught as SyntaxError objects
+
+function syntax_error(source) {
+  try {
+    eval(source);
+  } catch (e) {
+    return e;
+  }
+}
+
+var error = syntax_error("var x = 1;\nvar = 2;");
+assert_eq(error instanceof SyntaxError, true);
+assert_eq(error instanceof Error, true);
+assert_eq(error.name, "SyntaxError");
+assert_eq(error.message, "unexpected '=', expected identifier");
+assert_eq(error.lineNumber, 2);
+assert_eq(error.columnNumber, 5);
+assert_eq(error.fileName, "<anonymous>");
+
+error = syntax_error("if (true) {");
+assert_eq(error.lineNumber, 1);
+assert_eq(error.columnNumber, 12);
+
+error = syntax_error("1 +\n\n  ]");
+assert_eq(error.lineNumber, 3);
+assert_eq(error.columnNumber, 3);
+
+
+error = syntax_error("var ok = 1;\nvar 5 = ok;");
+assert_eq(error.message, "unexpected '5', expected identifier");
+assert_eq(error.lineNumber, 2);
@@ -3,22 +3,16 @@ extern crate yuk;
 use std::fs;
 use std::io::Read;
 use std::path::Path;
+use std::rc::Rc;
+use std::cell::Cell;
 
 use yuk::runtime::Yuk;
-use yuk::interpret::{Value, Function, JSResult, Context, throw_string};
+use yuk::interpret::{Value, Function, JSResult, ErrorKind, throw_error};
 
 const DIRECTORY: &'static str = "tests/js/";
-const COUNT: &'static str = "__assert_eq_call_count";
-
-// Use strict equals, keeping track of the number of calls
-fn assert_eq(arguments: Vec<Value>, context: Context) -> JSResult {
-    // increment the call count
-    let calls = match context.global.get(COUNT) {
-        Ok(Value::Number(n)) => n,
-        _ => 0.0
-    };
-    context.global.set(COUNT, Value::Number(calls + 1.0)).unwrap_or(Value::Undefined);
 
+// Use strict equals
+fn assert_eq(arguments: Vec<Value>) -> JSResult {
     let undefined = Value::Undefined;
 
     let (a, b) = (arguments.get(0).unwrap_or(&undefined), arguments.get(1).unwrap_or(&undefined));
@@ -26,7 +20,7 @@ fn assert_eq(arguments: Vec<Value>, context: Context) -> JSResult {
     if a.strict_equals(b) {
         Ok(Value::Undefined)
     } else {
-        throw_string(format!("{} !== {}", a.debug_string(), b.debug_string()))
+        throw_error(ErrorKind::Error, format!("{} !== {}", a.debug_string(), b.debug_string()))
     }
 }
 
@@ -40,14 +34,21 @@ fn run_tests() {
         file.read_to_string(&mut s).ok().expect(&format!("Could not read {}", path.to_string_lossy()));
 
         let mut yuk = Yuk::create_stdlib();
-        yuk.global.set("assert_eq", Value::from_function(Function::Native("assert_eq".to_string(), assert_eq), yuk.global.clone())).unwrap();
 
-        let result = yuk.eval(&s);
-        println!("{} - {} assertions called", path.to_string_lossy(), match yuk.global.get(COUNT) {Ok(Value::Number(n)) => n, _ => 0.0});
+        // Keep track of the number of calls
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        yuk.global.set("assert_eq", Value::from_function(Function::native("assert_eq", move |arguments, _context| {
+            counter.set(counter.get() + 1);
+            assert_eq(arguments)
+        }), yuk.global.clone())).unwrap();
+
+        let result = yuk.eval(&s, Some(&path.to_string_lossy()));
+        println!("{} - {} assertions called", path.to_string_lossy(), calls.get());
 
         match result {
             Ok(_) => (),
-            Err(e) => panic!("{} failed with error \"{}\"", path.to_string_lossy(), e.debug_string())
+            Err(e) => panic!("{} failed with error \"{}\"", path.to_string_lossy(), e)
         }
     }
 }
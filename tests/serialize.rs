@@ -0,0 +1,153 @@
+#![cfg(feature = "serde")]
+
+extern crate yuk;
+#[macro_use]
+extern crate serde_derive;
+
+use std::collections::BTreeMap;
+
+use yuk::runtime::Yuk;
+use yuk::interpret::Value;
+use yuk::serialize::{self, Error};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Config {
+    name: String,
+    retries: u8,
+    ratio: f64,
+    verbose: bool,
+    proxy: Option<String>,
+    hosts: Vec<String>,
+    limits: BTreeMap<String, i64>,
+    mode: Mode
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+enum Mode {
+    Off,
+    Fixed(u32),
+    Range(u32, u32),
+    Custom { label: String }
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct Node {
+    name: String,
+    next: Option<Box<Node>>
+}
+
+#[derive(Debug, Deserialize)]
+#[allow(dead_code)]
+struct Tree(Vec<Tree>);
+
+fn config() -> Config {
+    let mut limits = BTreeMap::new();
+    limits.insert("memory".to_string(), 512);
+
+    Config {
+        name: "server".to_string(),
+        retries: 3,
+        ratio: 0.5,
+        verbose: false,
+        proxy: None,
+        hosts: vec!["a".to_string(), "b".to_string()],
+        limits: limits,
+        mode: Mode::Range(1, 5)
+    }
+}
+
+fn eval(yuk: &mut Yuk, source: &str) -> Value {
+    yuk.eval(source, None).unwrap()
+}
+
+#[test]
+fn serialized_data_becomes_objects() {
+    let mut yuk = Yuk::create_stdlib();
+    let value = serialize::to_value(&config(), &yuk).unwrap();
+    yuk.global.set("config", value).unwrap();
+
+    assert_eq!(eval(&mut yuk, "config.name + ' ' + config.retries + ' ' + config.ratio;").debug_string(), "server 3 0.5");
+    assert_eq!(eval(&mut yuk, "config.proxy;"), Value::Undefined);
+    assert_eq!(eval(&mut yuk, "config.hosts instanceof Array;"), Value::Boolean(true));
+    assert_eq!(eval(&mut yuk, "config.hosts.join('-') + ' ' + config.limits.memory;").debug_string(), "a-b 512");
+    assert_eq!(eval(&mut yuk, "config.mode.Range[1];"), Value::Number(5.0));
+}
+
+#[test]
+fn data_round_trips_after_changes() {
+    let mut yuk = Yuk::create_stdlib();
+    let value = serialize::to_value(&config(), &yuk).unwrap();
+    yuk.global.set("config", value).unwrap();
+
+    eval(&mut yuk, "config.retries = 4;\nconfig.proxy = 'proxy:8080';\nconfig.hosts.push('c');\nconfig.limits.disk = -1;\nconfig.mode = {Custom: {label: 'fast'}};");
+
+    let mut expected = config();
+    expected.retries = 4;
+    expected.proxy = Some("proxy:8080".to_string());
+    expected.hosts.push("c".to_string());
+    expected.limits.insert("disk".to_string(), -1);
+    expected.mode = Mode::Custom { label: "fast".to_string() };
+
+    let config = eval(&mut yuk, "config;");
+    assert_eq!(serialize::from_value::<Config>(config, &yuk), Ok(expected));
+
+    for (source, mode) in vec![("'Off';", Mode::Off), ("({Fixed: 2});", Mode::Fixed(2)), ("({Range: [2, 3]});", Mode::Range(2, 3))] {
+        let value = eval(&mut yuk, source);
+        assert_eq!(serialize::from_value::<Mode>(value, &yuk), Ok(mode));
+    }
+}
+
+#[test]
+fn mismatched_values_are_errors() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let value = eval(&mut yuk, "({name: 'a', next: {name: 5}});");
+    match serialize::from_value::<Node>(value, &yuk) {
+        Err(Error::Message(message)) => assert_eq!(message, "invalid type: integer `5`, expected a string"),
+        other => panic!("expected an error, got {:?}", other)
+    }
+
+    let value = eval(&mut yuk, "({});");
+    assert_eq!(serialize::from_value::<Mode>(value, &yuk).unwrap_err().to_string(), "{} is not a Mode");
+
+    let value = eval(&mut yuk, "2.5;");
+    assert!(serialize::from_value::<u8>(value, &yuk).is_err());
+}
+
+#[test]
+fn cycles_and_functions_are_errors() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let value = eval(&mut yuk, "var node = {name: 'a'};\nnode.next = {name: 'b', next: node};\nnode;");
+    assert_eq!(serialize::from_value::<Node>(value, &yuk).unwrap_err(), Error::Cycle);
+
+    let value = eval(&mut yuk, "var list = [];\nlist.push(list);\nlist;");
+    let error = serialize::from_value::<Vec<Vec<u8>>>(value, &yuk).unwrap_err();
+    assert_eq!(error.to_string(), "cyclic object value");
+
+    // The same object can appear more than once as long as it doesn't contain itself
+    let value = eval(&mut yuk, "var shared = {name: 'c'};\n[shared, shared];");
+    assert_eq!(serialize::from_value::<Vec<BTreeMap<String, String>>>(value, &yuk).unwrap().len(), 2);
+
+    let value = eval(&mut yuk, "({name: function named() {}});");
+    match serialize::from_value::<Node>(value, &yuk) {
+        Err(Error::Function(_)) => (),
+        other => panic!("expected a function error, got {:?}", other)
+    }
+}
+
+#[test]
+fn deep_nesting_is_an_error() {
+    let mut yuk = Yuk::create_stdlib();
+
+    let value = eval(&mut yuk, "var node = {name: 'leaf'};\nfor (var i = 0; i < 1000; i++) { node = {name: 'branch', next: node}; }\nnode;");
+    assert_eq!(serialize::from_value::<Node>(value, &yuk).unwrap_err(), Error::TooDeep);
+
+    let value = eval(&mut yuk, "var list = [];\nfor (var i = 0; i < 1000; i++) { list = [list]; }\nlist;");
+    assert_eq!(serialize::from_value::<Tree>(value, &yuk).unwrap_err().to_string(), "nesting is too deep");
+
+    // Nesting within the limit is still fine
+    let value = eval(&mut yuk, "var list = [];\nfor (var i = 0; i < 100; i++) { list = [list]; }\nlist;");
+    assert!(serialize::from_value::<Tree>(value, &yuk).is_ok());
+}
@@ -0,0 +1,269 @@
+//! Derives `ToValue` and `FromValue` for structs and enums, so that they can be passed to and from scripts.
+//!
+//! Structs with named fields become objects with a property for each field, newtypes become the
+//! value inside them, other tuple structs become arrays, and unit structs become `null`. Enum
+//! variants without fields become their name as a string, and other variants become an object
+//! with their name as its only property.
+
+extern crate proc_macro;
+extern crate proc_macro2;
+extern crate syn;
+#[macro_use]
+extern crate quote;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as Tokens;
+use syn::{Data, DeriveInput, Fields, Ident, Index};
+
+#[proc_macro_derive(ToValue)]
+pub fn derive_to_value(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).unwrap();
+    let name = &input.ident;
+
+    let body = match input.data {
+        Data::Struct(ref data) => fields_to_value(&data.fields, |field| {
+            let member = field.member();
+            quote!(self.#member)
+        }),
+        Data::Enum(ref data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                let key = variant_name.to_string();
+
+                match variant.fields {
+                    Fields::Unit => quote! {
+                        #name::#variant_name => Ok(::yuk::interpret::Value::String(#key.to_string()))
+                    },
+                    ref fields => {
+                        let bindings = bindings(fields);
+                        let pattern = match *fields {
+                            Fields::Named(_) => quote!(#name::#variant_name { #(ref #bindings),* }),
+                            _ => quote!(#name::#variant_name(#(ref #bindings),*))
+                        };
+                        let contents = fields_to_value(fields, |field| {
+                            let binding = binding(field);
+                            quote!(*#binding)
+                        });
+
+                        quote! {
+                            #pattern => {
+                                let variant = ::yuk::interpret::new_object(context.global.clone())?;
+                                variant.set(#key, (|| -> ::yuk::interpret::JSResult { #contents })()?)?;
+                                Ok(::yuk::interpret::Value::Object(variant))
+                            }
+                        }
+                    }
+                }
+            });
+
+            quote! {
+                match *self {
+                    #(#arms),*
+                }
+            }
+        },
+        Data::Union(_) => panic!("ToValue can't be derived for unions")
+    };
+
+    let generics = add_bounds(&input.generics, quote!(::yuk::interpret::ToValue));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics ::yuk::interpret::ToValue for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn to_value(&self, context: &::yuk::interpret::Context) -> ::yuk::interpret::JSResult {
+                #body
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+#[proc_macro_derive(FromValue)]
+pub fn derive_from_value(input: TokenStream) -> TokenStream {
+    let input: DeriveInput = syn::parse(input).unwrap();
+    let name = &input.ident;
+    let type_name = name.to_string();
+
+    let body = match input.data {
+        Data::Struct(ref data) => {
+            let construct = fields_from_value(quote!(#name), &data.fields, quote!(value));
+            let check = match data.fields {
+                Fields::Unit => quote!(),
+                Fields::Unnamed(ref unnamed) if unnamed.unnamed.len() == 1 => quote!(),
+                _ => quote!(::yuk::convert::expect_object(&value, #type_name)?;)
+            };
+
+            quote! {
+                #check
+                Ok(#construct)
+            }
+        },
+        Data::Enum(ref data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let variant_name = &variant.ident;
+                let key = variant_name.to_string();
+
+                match variant.fields {
+                    Fields::Unit => quote! {
+                        (#key, None) => Ok(#name::#variant_name)
+                    },
+                    ref fields => {
+                        let construct = fields_from_value(quote!(#name::#variant_name), fields, quote!(contents));
+
+                        quote! {
+                            (#key, Some(contents)) => Ok(#construct)
+                        }
+                    }
+                }
+            });
+
+            quote! {
+                let (variant, contents) = ::yuk::convert::variant(value, #type_name, context)?;
+
+                match (&variant[..], contents) {
+                    #(#arms,)*
+                    _ => ::yuk::convert::unknown_variant(&variant, #type_name)
+                }
+            }
+        },
+        Data::Union(_) => panic!("FromValue can't be derived for unions")
+    };
+
+    let generics = add_bounds(&input.generics, quote!(::yuk::convert::FromValue));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let expanded = quote! {
+        impl #impl_generics ::yuk::convert::FromValue for #name #ty_generics #where_clause {
+            #[allow(unused_variables)]
+            fn from_value(value: ::yuk::interpret::Value, context: &::yuk::interpret::Context) -> Result<Self, ::yuk::interpret::Value> {
+                #body
+            }
+        }
+    };
+
+    expanded.into()
+}
+
+/// A field of a struct or variant, which is either named or numbered
+enum Field<'a> {
+    Named(&'a Ident),
+    Unnamed(usize)
+}
+
+impl<'a> Field<'a> {
+    fn all(fields: &'a Fields) -> Vec<Field<'a>> {
+        fields.iter().enumerate().map(|(index, field)| match field.ident {
+            Some(ref ident) => Field::Named(ident),
+            None => Field::Unnamed(index)
+        }).collect()
+    }
+
+    fn member(&self) -> Tokens {
+        match *self {
+            Field::Named(ident) => quote!(#ident),
+            Field::Unnamed(index) => {
+                let index = Index::from(index);
+                quote!(#index)
+            }
+        }
+    }
+}
+
+/// The variable that a field of an enum variant is bound to
+fn binding(field: &Field) -> Ident {
+    match *field {
+        Field::Named(ident) => ident.clone(),
+        Field::Unnamed(index) => Ident::new(&format!("field_{}", index), proc_macro2::Span::call_site())
+    }
+}
+
+fn bindings(fields: &Fields) -> Vec<Ident> {
+    Field::all(fields).iter().map(binding).collect()
+}
+
+/// Converts fields to an object, an array, or `null`, reading each field with `access`
+fn fields_to_value<F>(fields: &Fields, access: F) -> Tokens where F: Fn(&Field) -> Tokens {
+    let all = Field::all(fields);
+
+    match *fields {
+        Fields::Named(_) => {
+            let sets = all.iter().map(|field| {
+                let key = match *field {
+                    Field::Named(ident) => ident.to_string(),
+                    Field::Unnamed(_) => unreachable!()
+                };
+                let value = access(field);
+
+                quote! {
+                    object.set(#key, ::yuk::interpret::ToValue::to_value(&#value, context)?)?;
+                }
+            });
+
+            quote! {
+                let object = ::yuk::interpret::new_object(context.global.clone())?;
+                #(#sets)*
+                Ok(::yuk::interpret::Value::Object(object))
+            }
+        },
+        // Newtypes are the same as the value inside them
+        Fields::Unnamed(ref unnamed) if unnamed.unnamed.len() == 1 => {
+            let value = access(&all[0]);
+            quote!(::yuk::interpret::ToValue::to_value(&#value, context))
+        },
+        Fields::Unnamed(_) => {
+            let values = all.iter().map(|field| {
+                let value = access(field);
+                quote!(::yuk::interpret::ToValue::to_value(&#value, context)?)
+            });
+
+            quote! {
+                Ok(::yuk::interpret::Value::Object(::yuk::interpret::new_array(vec![#(#values),*], context.global.clone())?))
+            }
+        },
+        Fields::Unit => quote!(Ok(::yuk::interpret::Value::Object(::yuk::interpret::Object::Null)))
+    }
+}
+
+/// Builds `path` from the properties of `source`
+fn fields_from_value(path: Tokens, fields: &Fields, source: Tokens) -> Tokens {
+    let all = Field::all(fields);
+
+    match *fields {
+        Fields::Named(_) => {
+            let members = all.iter().map(|field| {
+                let member = field.member();
+                let key = member.to_string();
+                quote!(#member: ::yuk::convert::property(&#source, #key, context)?)
+            });
+
+            quote!(#path { #(#members),* })
+        },
+        Fields::Unnamed(ref unnamed) if unnamed.unnamed.len() == 1 => {
+            quote!(#path(::yuk::convert::FromValue::from_value(#source, context)?))
+        },
+        Fields::Unnamed(_) => {
+            let members = all.iter().map(|field| {
+                let key = field.member().to_string();
+                quote!(::yuk::convert::property(&#source, #key, context)?)
+            });
+
+            quote!(#path(#(#members),*))
+        },
+        Fields::Unit => quote!(#path)
+    }
+}
+
+/// Requires every type parameter to implement `bound`
+fn add_bounds(generics: &syn::Generics, bound: Tokens) -> syn::Generics {
+    let mut generics = generics.clone();
+
+    for param in generics.params.iter_mut() {
+        if let syn::GenericParam::Type(ref mut param) = *param {
+            param.bounds.push(syn::parse2(bound.clone()).unwrap());
+        }
+    }
+
+    generics
+}